@@ -0,0 +1,219 @@
+//! Budget command implementation
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+
+use crate::config::Config;
+use crate::plan;
+use crate::skill::{self, tokens::estimate_tokens, Skill};
+
+/// Estimated context cost of one skill
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkillCost {
+    pub name: String,
+    /// Name and description, loaded whenever the skill is enabled
+    pub description_tokens: usize,
+    /// Full SKILL.md, loaded when the skill is invoked
+    pub body_tokens: usize,
+    pub skill_file: PathBuf,
+}
+
+/// Estimated context cost of everything installed into one target
+#[derive(Debug)]
+pub struct TargetCost {
+    pub target: PathBuf,
+    pub skills: Vec<SkillCost>,
+}
+
+impl TargetCost {
+    /// Tokens loaded up front: every skill's name and description
+    pub fn description_tokens(&self) -> usize {
+        self.skills.iter().map(|s| s.description_tokens).sum()
+    }
+
+    /// Tokens loaded if every skill in the target is invoked
+    pub fn body_tokens(&self) -> usize {
+        self.skills.iter().map(|s| s.body_tokens).sum()
+    }
+}
+
+/// Estimate the token cost of each target in the install plan
+///
+/// Skills that can't be found are skipped; `check` reports them separately.
+pub fn estimate(config: &Config, all_skills: &[Skill]) -> Result<Vec<TargetCost>> {
+    let skills_by_name: HashMap<&str, &Skill> =
+        all_skills.iter().map(|s| (s.name.as_str(), s)).collect();
+    let mut costs: HashMap<PathBuf, SkillCost> = HashMap::new();
+    let mut targets = Vec::new();
+
+    for target_plan in plan::build(config, all_skills, &[])?.targets {
+        let mut skills = Vec::new();
+        for name in &target_plan.skills {
+            let Some(skill) = target_plan
+                .local_skills
+                .get(name)
+                .or_else(|| skills_by_name.get(name.as_str()).copied())
+            else {
+                continue;
+            };
+            if !costs.contains_key(&skill.skill_file) {
+                costs.insert(skill.skill_file.clone(), skill_cost(skill)?);
+            }
+            skills.push(costs[&skill.skill_file].clone());
+        }
+
+        if !skills.is_empty() {
+            targets.push(TargetCost {
+                target: target_plan.target,
+                skills,
+            });
+        }
+    }
+
+    Ok(targets)
+}
+
+fn skill_cost(skill: &Skill) -> Result<SkillCost> {
+    let content = fs::read_to_string(&skill.skill_file).context(format!(
+        "Failed to read SKILL.md: {}",
+        skill.skill_file.display()
+    ))?;
+
+    Ok(SkillCost {
+        name: skill.name.clone(),
+        description_tokens: estimate_tokens(&skill.frontmatter.name)
+            + estimate_tokens(&skill.frontmatter.description),
+        body_tokens: estimate_tokens(&content),
+        skill_file: skill.skill_file.clone(),
+    })
+}
+
+/// Report the estimated token cost of each target
+pub fn budget(config: &Config) -> Result<()> {
+    let all_skills = skill::discover_all(&config.sources.skills)?;
+    let targets = estimate(config, &all_skills)?;
+    let limits = &config.budget;
+
+    println!("{}", "--- Token budget (approximate) ---".cyan().bold());
+    println!(
+        "{}",
+        "Descriptions load with every session; bodies load when a skill is invoked.".dimmed()
+    );
+
+    if targets.is_empty() {
+        println!("\nNo skills are enabled in any target");
+        return Ok(());
+    }
+
+    for target in &targets {
+        println!("\nTarget: {}", target.target.display());
+
+        let width = target
+            .skills
+            .iter()
+            .map(|s| s.name.len())
+            .max()
+            .unwrap_or(0);
+        for cost in &target.skills {
+            let body = format!("{:>6} body", cost.body_tokens);
+            let body = match limits.max_skill_tokens {
+                Some(limit) if cost.body_tokens > limit => body.red().to_string(),
+                _ => body,
+            };
+            println!(
+                "  {:<width$}  {:>5} description  {}",
+                cost.name, cost.description_tokens, body
+            );
+        }
+
+        let always = target.description_tokens();
+        let always_text = format!("{} tokens always loaded", always);
+        let always_text = match limits.max_target_tokens {
+            Some(limit) if always > limit => {
+                format!("{} (limit {})", always_text, limit).red().bold()
+            }
+            Some(limit) => format!("{} (limit {})", always_text, limit).green(),
+            None => always_text.normal(),
+        };
+        println!(
+            "  Total: {}, {} more if every skill is invoked",
+            always_text,
+            target.body_tokens()
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{default_target_aliases, Global, Sources, TargetAliasPaths};
+    use tempfile::TempDir;
+
+    #[test]
+    fn should_estimate_description_and_body_tokens_per_target() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let skills_dir = temp.path().join("skills");
+        for (name, description) in [("voice", "Write in our voice."), ("deploy", "Ship it")] {
+            let dir = skills_dir.join(name);
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(
+                dir.join("SKILL.md"),
+                format!("---\nname: {name}\ndescription: {description}\n---\n"),
+            )
+            .unwrap();
+        }
+
+        let mut target_aliases = default_target_aliases();
+        target_aliases.insert(
+            "test_runner".to_string(),
+            TargetAliasPaths {
+                global: temp.path().join("global"),
+                project: PathBuf::from(".test-runner/skills"),
+            },
+        );
+        let config = Config {
+            sources: Sources {
+                skills: vec![skills_dir],
+                templates: vec![],
+            },
+            global: Global {
+                targets: vec!["test_runner".to_string()],
+                skills: vec![
+                    "voice".to_string(),
+                    "deploy".to_string(),
+                    "gone".to_string(),
+                ],
+                exclude: vec![],
+                allow_drafts: false,
+            },
+            target_aliases,
+            projects: HashMap::new(),
+            check: Default::default(),
+            crossref: Default::default(),
+            budget: Default::default(),
+            profile: None,
+            profiles: Default::default(),
+        };
+        let all_skills = skill::discover_all(&config.sources.skills).unwrap();
+
+        // When
+        let targets = estimate(&config, &all_skills).unwrap();
+
+        // Then
+        assert_eq!(targets.len(), 1);
+        assert_eq!(targets[0].target, temp.path().join("global"));
+        let names: Vec<&str> = targets[0].skills.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["deploy", "voice"]);
+        // "voice" + "Write in our voice."
+        assert_eq!(targets[0].skills[1].description_tokens, 9);
+        assert_eq!(targets[0].description_tokens(), 9 + 4);
+        assert!(targets[0].skills[1].body_tokens > 9);
+    }
+}
@@ -1,17 +1,26 @@
 use anyhow::Result;
 use colored::Colorize;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use crate::config::Config;
+use crate::commands::budget;
+use crate::config::{BudgetSeverity, Config};
+use crate::linker;
 use crate::paths;
-use crate::skill::{self, Skill};
+use crate::plan;
+use crate::skill::{self, markdown, suggest, text::TfIdf, Skill, SkillStatus};
 
 const MARKER_FILE: &str = ".managed-by-loadout";
 
+/// Shared terms listed in an overlap finding
+const MAX_SHARED_TERMS: usize = 5;
+
 const PLACEHOLDER_DESCRIPTIONS: &[&str] = &["Description here", "TODO", "TBD", "FIXME"];
 
+/// Descriptions shorter than this are reported as stubs
+const MIN_DESCRIPTION_CHARS: usize = 10;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Severity {
     Info,
@@ -113,6 +122,7 @@ pub fn check(
     config: &Config,
     filter_severity: Option<Severity>,
     verbose: bool,
+    min_confidence: Option<f32>,
 ) -> Result<Vec<Finding>> {
     let mut findings = Vec::new();
 
@@ -127,16 +137,7 @@ pub fn check(
     let known_skills: HashSet<String> = all_skills.iter().map(|s| s.name.clone()).collect();
 
     // Extract cross-references from all skills
-    let mut crossrefs: HashMap<String, Vec<skill::CrossRef>> = HashMap::new();
-    for skill in &all_skills {
-        let skill_md = skill.path.join("SKILL.md");
-        let content = fs::read_to_string(&skill_md)?;
-        let refs =
-            skill::extract_references_with_filter(&content, &skill.name, Some(&known_skills));
-        if !refs.is_empty() {
-            crossrefs.insert(skill.name.clone(), refs);
-        }
-    }
+    let crossrefs = collect_crossrefs(config, &all_skills, &known_skills, min_confidence)?;
 
     // Check 1: Dangling references
     findings.extend(check_dangling_references(&crossrefs, &skill_map));
@@ -144,6 +145,9 @@ pub fn check(
     // Check 2: Orphaned skills
     findings.extend(check_orphaned_skills(config, &all_skills));
 
+    // Checks 1 and 2 for skills in project sources, within their project
+    findings.extend(check_project_sources(config, &all_skills, min_confidence)?);
+
     // Check 3: Name/directory mismatches
     findings.extend(check_name_directory_mismatch(&all_skills));
 
@@ -165,6 +169,27 @@ pub fn check(
     // Check 9: Untagged/unpipelined skills
     findings.extend(check_missing_metadata(&all_skills));
 
+    // Check 10: Relative links and supporting files
+    findings.extend(check_supporting_files(&all_skills)?);
+
+    // Check 11: Lifecycle status (drafts, deprecations, replacements)
+    findings.extend(check_lifecycle(config, &all_skills, &crossrefs, &skill_map));
+
+    // Check 12: Content changed since install without a version bump
+    findings.extend(check_version_drift(config, &all_skills, &skill_map)?);
+
+    // Check 13: Token budget limits
+    findings.extend(check_budget(config, &all_skills)?);
+
+    // Check 14: Skills sharing a target with near-identical descriptions
+    findings.extend(check_trigger_overlap(config, &all_skills)?);
+
+    // Check 15: Description quality (triggers, phrasing, length)
+    findings.extend(check_description_quality(config, &all_skills)?);
+
+    // Check 16: pipeline:/tag: selectors in skill lists that match nothing
+    findings.extend(check_unmatched_selectors(config, &all_skills)?);
+
     // Sort by severity (errors first)
     findings.sort_by_key(|f| f.severity);
     findings.reverse(); // Reverse to get errors first
@@ -190,6 +215,97 @@ pub fn check(
     Ok(findings)
 }
 
+/// A dangling reference rewritten by `check --fix`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppliedFix {
+    /// The SKILL.md file that was edited
+    pub path: PathBuf,
+    /// Line of the rewritten reference (1-indexed)
+    pub line: usize,
+    /// Character column of the rewritten reference (1-indexed)
+    pub column: usize,
+    /// The unknown name that was replaced
+    pub from: String,
+    /// The known skill name it was replaced with
+    pub to: String,
+}
+
+/// Rewrite dangling references that have exactly one strong candidate
+///
+/// References with no candidate, or several similar ones, are left for
+/// `check` to report.
+pub fn fix_dangling_references(
+    config: &Config,
+    min_confidence: Option<f32>,
+) -> Result<Vec<AppliedFix>> {
+    let all_skills = skill::discover_all(&config.sources.skills)?;
+    let known_skills: HashSet<String> = all_skills.iter().map(|s| s.name.clone()).collect();
+    let crossrefs = collect_crossrefs(config, &all_skills, &known_skills, min_confidence)?;
+
+    let mut applied = Vec::new();
+    for skill in &all_skills {
+        let Some(refs) = crossrefs.get(&skill.name) else {
+            continue;
+        };
+
+        let mut edits: Vec<(&skill::CrossRef, String)> = refs
+            .iter()
+            .filter(|r| !known_skills.contains(&r.target))
+            .filter_map(|r| {
+                suggest::strong_candidate(&r.target, known_skills.iter().map(String::as_str))
+                    .map(|to| (r, to))
+            })
+            .collect();
+        if edits.is_empty() {
+            continue;
+        }
+
+        // Apply from the end of the file so earlier positions stay valid
+        edits.sort_by_key(|(r, _)| std::cmp::Reverse((r.line, r.column)));
+
+        let mut content = fs::read_to_string(&skill.skill_file)?;
+        for (r, to) in edits {
+            if let Some(updated) = markdown::replace_at(&content, r.line, r.column, &r.target, &to)
+            {
+                content = updated;
+                applied.push(AppliedFix {
+                    path: skill.skill_file.clone(),
+                    line: r.line,
+                    column: r.column,
+                    from: r.target.clone(),
+                    to,
+                });
+            }
+        }
+        fs::write(&skill.skill_file, content)?;
+    }
+
+    applied.sort_by(|a, b| (&a.path, a.line, a.column).cmp(&(&b.path, b.line, b.column)));
+    Ok(applied)
+}
+
+fn collect_crossrefs(
+    config: &Config,
+    all_skills: &[Skill],
+    known_skills: &HashSet<String>,
+    min_confidence: Option<f32>,
+) -> Result<HashMap<String, Vec<skill::CrossRef>>> {
+    let mut options = skill::ExtractOptions::from_config(&config.crossref)?;
+    if let Some(min) = min_confidence {
+        options.min_confidence = min;
+    }
+
+    let mut crossrefs = HashMap::new();
+    for skill in all_skills {
+        let refs = skill::references_for_skill(skill, Some(known_skills), &options)?;
+        if !refs.is_empty() {
+            crossrefs.insert(skill.name.clone(), refs);
+        }
+    }
+
+    Ok(crossrefs)
+}
+
 fn check_dangling_references(
     crossrefs: &HashMap<String, Vec<skill::CrossRef>>,
     skill_map: &HashMap<String, &Skill>,
@@ -199,15 +315,34 @@ fn check_dangling_references(
     for (source_skill, refs) in crossrefs {
         for crossref in refs {
             if !skill_map.contains_key(&crossref.target) {
+                let known = || skill_map.keys().map(String::as_str);
+                let suggestions = suggest::suggestions(&crossref.target, known());
+                let fix = if suggestions.is_empty() {
+                    format!(
+                        "Create the skill with `loadout new {}`, or remove the reference at line {}",
+                        crossref.target, crossref.line
+                    )
+                } else {
+                    let rewrite = if suggest::strong_candidate(&crossref.target, known()).is_some()
+                    {
+                        " (`loadout check --fix` rewrites it)"
+                    } else {
+                        ""
+                    };
+                    format!(
+                        "{} Fix the reference at line {}{}, or create the skill with `loadout new {}`",
+                        suggest::did_you_mean(&suggestions).trim_start_matches(". "),
+                        crossref.line,
+                        rewrite,
+                        crossref.target
+                    )
+                };
                 findings.push(Finding::error(
                     format!(
                         "Skill '{}' references non-existent skill '{}' (line {})",
                         source_skill, crossref.target, crossref.line
                     ),
-                    format!(
-                        "Create the skill with `loadout new {}`, or remove the reference at line {}",
-                        crossref.target, crossref.line
-                    ),
+                    fix,
                     format!("dangling:{}:{}", source_skill, crossref.target),
                 ));
             }
@@ -222,10 +357,15 @@ fn check_orphaned_skills(config: &Config, all_skills: &[Skill]) -> Vec<Finding>
 
     // Collect all skill names mentioned in config
     let mut mentioned_skills: HashSet<String> = HashSet::new();
-    mentioned_skills.extend(config.global.skills.iter().cloned());
+    // Excluded skills were left out on purpose
+    let global = plan::global_selection(config, all_skills);
+    mentioned_skills.extend(global.names().map(String::from));
+    mentioned_skills.extend(global.excluded.iter().map(|e| e.name.clone()));
 
     for project in config.projects.values() {
-        mentioned_skills.extend(project.skills.iter().cloned());
+        let selection = plan::project_selection(config, project, all_skills);
+        mentioned_skills.extend(selection.names().map(String::from));
+        mentioned_skills.extend(selection.excluded.iter().map(|e| e.name.clone()));
     }
 
     // Check for skills in sources but not in config
@@ -246,6 +386,58 @@ fn check_orphaned_skills(config: &Config, all_skills: &[Skill]) -> Vec<Finding>
     findings
 }
 
+/// Dangling references and orphans among skills in project sources
+///
+/// A project's own skills can reference each other and global skills, and
+/// only that project's skill list enables them.
+fn check_project_sources(
+    config: &Config,
+    all_skills: &[Skill],
+    min_confidence: Option<f32>,
+) -> Result<Vec<Finding>> {
+    let mut findings = Vec::new();
+    let mut projects: Vec<_> = config
+        .projects
+        .iter()
+        .filter(|(_, project)| !project.sources.is_empty())
+        .collect();
+    projects.sort_by_key(|(path, _)| *path);
+
+    for (path, project) in projects {
+        let local = skill::discover_all(&project.sources)?;
+        let pool = plan::project_skills(project, all_skills)?;
+        let skill_map: HashMap<String, &Skill> = pool.iter().map(|s| (s.name.clone(), s)).collect();
+        let known: HashSet<String> = skill_map.keys().cloned().collect();
+
+        let crossrefs = collect_crossrefs(config, &local, &known, min_confidence)?;
+        findings.extend(check_dangling_references(&crossrefs, &skill_map));
+
+        let selection = plan::project_selection(config, project, &pool);
+        for skill in &local {
+            let mentioned = selection.names().any(|name| name == skill.name)
+                || selection.excluded.iter().any(|e| e.name == skill.name);
+            if !mentioned {
+                findings.push(Finding::warning_with_path(
+                    format!(
+                        "Skill '{}' exists in the sources of project {} but isn't enabled there",
+                        skill.name,
+                        path.display()
+                    ),
+                    format!(
+                        "Add '{}' to [projects.\"{}\"].skills in loadout.toml",
+                        skill.name,
+                        path.display()
+                    ),
+                    format!("orphaned:{}:{}", path.display(), skill.name),
+                    skill.path.clone(),
+                ));
+            }
+        }
+    }
+
+    Ok(findings)
+}
+
 fn check_name_directory_mismatch(all_skills: &[Skill]) -> Vec<Finding> {
     let mut findings = Vec::new();
 
@@ -375,7 +567,7 @@ fn check_placeholder_descriptions(all_skills: &[Skill]) -> Vec<Finding> {
     for skill in all_skills {
         let desc = &skill.frontmatter.description;
 
-        if PLACEHOLDER_DESCRIPTIONS.iter().any(|p| desc.contains(p)) {
+        if is_placeholder(desc) {
             findings.push(Finding::warning_with_path(
                 format!(
                     "Skill '{}' has placeholder description: '{}'",
@@ -389,7 +581,7 @@ fn check_placeholder_descriptions(all_skills: &[Skill]) -> Vec<Finding> {
                 format!("placeholder:{}", skill.name),
                 skill.path.clone(),
             ));
-        } else if desc.len() < 10 {
+        } else if desc.len() < MIN_DESCRIPTION_CHARS {
             findings.push(Finding::warning_with_path(
                 format!(
                     "Skill '{}' has very short description ({} chars): '{}'",
@@ -410,8 +602,62 @@ fn check_placeholder_descriptions(all_skills: &[Skill]) -> Vec<Finding> {
     findings
 }
 
+fn is_placeholder(description: &str) -> bool {
+    PLACEHOLDER_DESCRIPTIONS
+        .iter()
+        .any(|p| description.contains(p))
+}
+
+/// Lint descriptions that aren't already flagged as placeholders or stubs
+fn check_description_quality(config: &Config, all_skills: &[Skill]) -> Result<Vec<Finding>> {
+    let options = skill::description::LintOptions::from_config(&config.check.description)?;
+    let mut findings = Vec::new();
+
+    for skill in all_skills {
+        let desc = &skill.frontmatter.description;
+        if is_placeholder(desc) || desc.len() < MIN_DESCRIPTION_CHARS {
+            continue;
+        }
+
+        for issue in skill::description::lint(&skill.name, desc, &options) {
+            findings.push(Finding::warning_with_path(
+                format!("Skill '{}' description {}", skill.name, issue.message),
+                format!("{} in {}", issue.fix, skill.skill_file.display()),
+                format!("description:{}:{}", skill.name, issue.rule),
+                skill.skill_file.clone(),
+            ));
+        }
+    }
+
+    Ok(findings)
+}
+
+fn check_unmatched_selectors(config: &Config, all_skills: &[Skill]) -> Result<Vec<Finding>> {
+    let findings = plan::build(config, all_skills, &[])?
+        .unmatched
+        .into_iter()
+        .map(|unmatched| {
+            Finding::warning(
+                format!(
+                    "Selector '{}' in {} skills matches no skill",
+                    unmatched.selector, unmatched.scope
+                ),
+                "Check the pipeline or tag name, or remove the selector from loadout.toml",
+                format!(
+                    "unmatched-selector:{}:{}",
+                    unmatched.scope, unmatched.selector
+                ),
+            )
+        })
+        .collect();
+
+    Ok(findings)
+}
+
 fn check_pipeline_integrity(all_skills: &[Skill], known_skills: &HashSet<String>) -> Vec<Finding> {
     let mut findings = Vec::new();
+    let skills_by_name: HashMap<&str, &Skill> =
+        all_skills.iter().map(|s| (s.name.as_str(), s)).collect();
 
     // Build a map of pipeline declarations: pipeline_name -> skill_name -> PipelineStage
     let mut pipeline_map: HashMap<String, HashMap<String, &skill::PipelineStage>> = HashMap::new();
@@ -429,76 +675,193 @@ fn check_pipeline_integrity(all_skills: &[Skill], known_skills: &HashSet<String>
     // Check each pipeline for integrity
     for (pipeline_name, stages) in &pipeline_map {
         for (skill_name, stage) in stages {
-            // Check after references exist
-            if let Some(after) = &stage.after {
-                for dep in after {
+            for (list, entries) in [("after", &stage.after), ("before", &stage.before)] {
+                for entry in entries.iter().flatten() {
+                    let requirement = match skill::SkillRequirement::parse(entry) {
+                        Ok(requirement) => requirement,
+                        Err(reason) => {
+                            findings.push(Finding::error_with_path(
+                                format!(
+                                    "Pipeline '{}': skill '{}' has invalid {} entry '{}': {}",
+                                    pipeline_name, skill_name, list, entry, reason
+                                ),
+                                "Use a skill name, optionally followed by @ and a version constraint such as `story-spine@^2`",
+                                format!("pipeline-entry:{}:{}:{}", pipeline_name, skill_name, entry),
+                                skills_by_name[skill_name.as_str()].skill_file.clone(),
+                            ));
+                            continue;
+                        }
+                    };
+                    let dep = &requirement.name;
+
+                    // Check referenced skills exist
                     if !known_skills.contains(dep) {
                         findings.push(Finding::error(
                             format!(
-                                "Pipeline '{}': skill '{}' declares after: ['{}'] but skill doesn't exist",
-                                pipeline_name, skill_name, dep
+                                "Pipeline '{}': skill '{}' declares {}: ['{}'] but skill doesn't exist",
+                                pipeline_name, skill_name, list, dep
                             ),
                             format!(
-                                "Create the skill with `loadout new {}`, or remove it from the after list",
-                                dep
+                                "Create the skill with `loadout new {}`, or remove it from the {} list",
+                                dep, list
                             ),
                             format!("pipeline-missing:{}:{}:{}", pipeline_name, skill_name, dep),
                         ));
+                        continue;
+                    }
+
+                    // Check version constraints are satisfied
+                    if let (Some(constraint), Some(dep_skill)) =
+                        (&requirement.constraint, skills_by_name.get(dep.as_str()))
+                    {
+                        findings.extend(check_version_constraint(
+                            pipeline_name,
+                            skill_name,
+                            entry,
+                            constraint,
+                            dep_skill,
+                        ));
                     }
                 }
             }
 
-            // Check before references exist
-            if let Some(before) = &stage.before {
-                for dep in before {
-                    if !known_skills.contains(dep) {
-                        findings.push(Finding::error(
+            // Check for asymmetric after/before declarations
+            for dep in stage.after_skills() {
+                if let Some(dep_stage) = stages.get(dep) {
+                    // dep should have before: [skill_name]
+                    let has_reciprocal = dep_stage.before_skills().any(|b| b == skill_name);
+                    if !has_reciprocal {
+                        findings.push(Finding::warning(
                             format!(
-                                "Pipeline '{}': skill '{}' declares before: ['{}'] but skill doesn't exist",
-                                pipeline_name, skill_name, dep
+                                "Pipeline '{}': '{}' declares after: ['{}'] but '{}' doesn't declare before: ['{}']",
+                                pipeline_name, skill_name, dep, dep, skill_name
                             ),
                             format!(
-                                "Create the skill with `loadout new {}`, or remove it from the before list",
-                                dep
+                                "Add before: ['{}'] to skill '{}' in pipeline '{}'",
+                                skill_name, dep, pipeline_name
                             ),
-                            format!("pipeline-missing:{}:{}:{}", pipeline_name, skill_name, dep),
+                            format!("pipeline-gap:{}:{}:{}", pipeline_name, skill_name, dep),
                         ));
                     }
                 }
             }
-
-            // Check for asymmetric after/before declarations
-            if let Some(after) = &stage.after {
-                for dep in after {
-                    if let Some(dep_stage) = stages.get(dep) {
-                        // dep should have before: [skill_name]
-                        let has_reciprocal = dep_stage
-                            .before
-                            .as_ref()
-                            .map(|b| b.contains(skill_name))
-                            .unwrap_or(false);
-                        if !has_reciprocal {
-                            findings.push(Finding::warning(
-                                format!(
-                                    "Pipeline '{}': '{}' declares after: ['{}'] but '{}' doesn't declare before: ['{}']",
-                                    pipeline_name, skill_name, dep, dep, skill_name
-                                ),
-                                format!(
-                                    "Add before: ['{}'] to skill '{}' in pipeline '{}'",
-                                    skill_name, dep, pipeline_name
-                                ),
-                                format!("pipeline-gap:{}:{}:{}", pipeline_name, skill_name, dep),
-                            ));
-                        }
-                    }
-                }
-            }
         }
+
+        let analysis =
+            skill::pipeline::analyze(stages.iter().map(|(name, stage)| (name.as_str(), *stage)));
+        findings.extend(pipeline_order_findings(pipeline_name, &analysis));
+    }
+
+    findings
+}
+
+/// Cycles, order/edge contradictions, and unreachable stages in a pipeline
+fn pipeline_order_findings(
+    pipeline_name: &str,
+    analysis: &skill::pipeline::PipelineAnalysis,
+) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    for cycle in &analysis.cycles {
+        findings.push(Finding::error(
+            format!(
+                "Pipeline '{}': after/before entries form a cycle: {}",
+                pipeline_name,
+                cycle.join(" → ")
+            ),
+            "Remove one of the after/before entries in the loop".to_string(),
+            format!("pipeline-cycle:{}:{}", pipeline_name, cycle[0]),
+        ));
+    }
+
+    for contradiction in &analysis.contradictions {
+        findings.push(Finding::error(
+            format!(
+                "Pipeline '{}': '{}' runs before '{}' but has order {} (vs {})",
+                pipeline_name,
+                contradiction.first,
+                contradiction.then,
+                contradiction.first_order,
+                contradiction.then_order
+            ),
+            format!(
+                "Give '{}' an order below {}, or drop the after/before entry between them",
+                contradiction.first, contradiction.then_order
+            ),
+            format!(
+                "pipeline-order:{}:{}:{}",
+                pipeline_name, contradiction.first, contradiction.then
+            ),
+        ));
+    }
+
+    for stage in &analysis.unreachable {
+        findings.push(Finding::warning(
+            format!(
+                "Pipeline '{}': no after/before entry leads to '{}' from the first stage",
+                pipeline_name, stage
+            ),
+            format!(
+                "Add an after: entry to '{}' naming the stage it follows",
+                stage
+            ),
+            format!("pipeline-unreachable:{}:{}", pipeline_name, stage),
+        ));
     }
 
     findings
 }
 
+/// Verify a pipeline entry's `@constraint` against the discovered skill
+fn check_version_constraint(
+    pipeline_name: &str,
+    skill_name: &str,
+    entry: &str,
+    constraint: &semver::VersionReq,
+    dep: &Skill,
+) -> Option<Finding> {
+    let key = format!(
+        "pipeline-version:{}:{}:{}",
+        pipeline_name, skill_name, dep.name
+    );
+
+    let Some(version) = dep.frontmatter.version.as_deref() else {
+        return Some(Finding::warning_with_path(
+            format!(
+                "Pipeline '{}': '{}' requires '{}' but '{}' has no version",
+                pipeline_name, skill_name, entry, dep.name
+            ),
+            format!("Add `version:` to {}", dep.skill_file.display()),
+            key,
+            dep.skill_file.clone(),
+        ));
+    };
+
+    // Versions are validated during discovery
+    let parsed = semver::Version::parse(version).ok()?;
+    if constraint.matches(&parsed) {
+        return None;
+    }
+
+    Some(Finding::error_with_path(
+        format!(
+            "Pipeline '{}': '{}' requires '{}' but '{}' is {} (from {})",
+            pipeline_name,
+            skill_name,
+            entry,
+            dep.name,
+            version,
+            dep.skill_file.display()
+        ),
+        format!(
+            "Update '{}' for {} {}, or relax the constraint",
+            skill_name, dep.name, version
+        ),
+        key,
+        dep.skill_file.clone(),
+    ))
+}
+
 fn check_missing_metadata(all_skills: &[Skill]) -> Vec<Finding> {
     // Only check when the library is partially annotated — at least one skill
     // has tags or pipeline. This avoids noise for users who haven't adopted
@@ -545,6 +908,443 @@ fn check_missing_metadata(all_skills: &[Skill]) -> Vec<Finding> {
     findings
 }
 
+fn check_supporting_files(all_skills: &[Skill]) -> Result<Vec<Finding>> {
+    let mut findings = Vec::new();
+
+    for skill in all_skills {
+        let content = fs::read_to_string(&skill.skill_file)?;
+        let mut referenced: Vec<PathBuf> = Vec::new();
+
+        for link in skill::extract_links(&content) {
+            match skill::links::classify(&link.target) {
+                skill::LinkTarget::External | skill::LinkTarget::Anchor => {}
+                skill::LinkTarget::Local(relative) => {
+                    if !skill.path.join(&relative).exists() {
+                        findings.push(Finding::error_with_path(
+                            format!(
+                                "Skill '{}' links to missing file '{}' (line {})",
+                                skill.name, link.target, link.line
+                            ),
+                            format!(
+                                "Create {}, or fix the link at line {}",
+                                skill.path.join(&relative).display(),
+                                link.line
+                            ),
+                            format!("broken-link:{}:{}", skill.name, link.target),
+                            skill.skill_file.clone(),
+                        ));
+                    }
+                    // `./` is the skill directory itself, not any file in it
+                    if !relative.as_os_str().is_empty() {
+                        referenced.push(relative);
+                    }
+                }
+                skill::LinkTarget::Escaping(path) => {
+                    findings.extend(check_escaping_link(skill, &link, &path, all_skills));
+                }
+            }
+        }
+
+        for file in skill.supporting_files()? {
+            let mentioned = referenced.iter().any(|r| file.starts_with(r))
+                || content.contains(file.to_string_lossy().replace('\\', "/").as_str());
+            if !mentioned {
+                findings.push(Finding::info(
+                    format!(
+                        "Skill '{}' ships '{}' but SKILL.md never references it",
+                        skill.name,
+                        file.display()
+                    ),
+                    format!(
+                        "Link to it from {}, or delete it",
+                        skill.skill_file.display()
+                    ),
+                    format!("unreferenced-file:{}:{}", skill.name, file.display()),
+                ));
+            }
+        }
+    }
+
+    Ok(findings)
+}
+
+fn check_lifecycle(
+    config: &Config,
+    all_skills: &[Skill],
+    crossrefs: &HashMap<String, Vec<skill::CrossRef>>,
+    skill_map: &HashMap<String, &Skill>,
+) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    let replace_hint = |skill: &Skill, action: &str| match &skill.frontmatter.replaced_by {
+        Some(replacement) => format!("{} '{}' instead", action, replacement),
+        None => "Remove it; the skill has no replacement".to_string(),
+    };
+
+    // Enabled skills, by scope, with selectors expanded
+    let mut scopes: Vec<(String, plan::Selection)> = vec![(
+        "global".to_string(),
+        plan::global_selection(config, all_skills),
+    )];
+    let mut projects: Vec<_> = config.projects.iter().collect();
+    projects.sort_by_key(|(path, _)| *path);
+    for (path, project) in projects {
+        // Inherited skills are reported once, under global
+        let mut selection = plan::project_selection(config, project, all_skills);
+        selection.skills.retain(|selected| !selected.inherited);
+        scopes.push((path.display().to_string(), selection));
+    }
+
+    for (scope, selection) in &scopes {
+        for name in selection.names() {
+            let Some(skill) = skill_map.get(name) else {
+                continue;
+            };
+            match skill.frontmatter.status() {
+                SkillStatus::Deprecated => findings.push(Finding::warning(
+                    format!("Skill '{}' is enabled in {} but deprecated", name, scope),
+                    replace_hint(skill, "Enable"),
+                    format!("deprecated-enabled:{}:{}", scope, name),
+                )),
+                SkillStatus::Draft if scope == "global" && !config.global.allow_drafts => {
+                    findings.push(Finding::error(
+                        format!("Draft skill '{}' is enabled in global scope", name),
+                        "Enable it for a project instead, or set allow_drafts = true under [global]",
+                        format!("draft-global:{}", name),
+                    ))
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // References to deprecated skills from skills that are still current
+    let mut sources: Vec<_> = crossrefs.iter().collect();
+    sources.sort_by_key(|(name, _)| *name);
+    for (source, refs) in sources {
+        let source_deprecated = skill_map
+            .get(source)
+            .is_some_and(|s| s.frontmatter.status() == SkillStatus::Deprecated);
+        if source_deprecated {
+            continue;
+        }
+        for crossref in refs {
+            let Some(target) = skill_map.get(&crossref.target) else {
+                continue;
+            };
+            if target.frontmatter.status() == SkillStatus::Deprecated {
+                findings.push(Finding::warning(
+                    format!(
+                        "Skill '{}' references deprecated skill '{}' (line {})",
+                        source, crossref.target, crossref.line
+                    ),
+                    replace_hint(target, "Reference"),
+                    format!("deprecated-ref:{}:{}", source, crossref.target),
+                ));
+            }
+        }
+    }
+
+    // Replacements must exist
+    let mut skills: Vec<_> = skill_map.values().collect();
+    skills.sort_by_key(|s| &s.name);
+    for skill in skills {
+        if let Some(replacement) = &skill.frontmatter.replaced_by {
+            if !skill_map.contains_key(replacement) {
+                findings.push(Finding::warning(
+                    format!(
+                        "Skill '{}' is replaced by '{}', which doesn't exist",
+                        skill.name, replacement
+                    ),
+                    format!(
+                        "Fix replaced-by in {}, or create the skill with `loadout new {}`",
+                        skill.skill_file.display(),
+                        replacement
+                    ),
+                    format!("missing-replacement:{}:{}", skill.name, replacement),
+                ));
+            }
+        }
+    }
+
+    findings
+}
+
+fn check_version_drift(
+    config: &Config,
+    all_skills: &[Skill],
+    skill_map: &HashMap<String, &Skill>,
+) -> Result<Vec<Finding>> {
+    // Compare each target against the skill `install` linked there, which is
+    // a project's own copy when its sources shadow a global skill
+    let install_plan = plan::build(config, all_skills, &[])?;
+
+    // Skill file -> the skill and targets where it drifted from the recorded snapshot
+    let mut drifted: BTreeMap<&Path, (&Skill, Vec<PathBuf>)> = BTreeMap::new();
+    let mut hashes: HashMap<&Path, String> = HashMap::new();
+
+    for target in &install_plan.targets {
+        let state = linker::state::read(&target.target)?;
+        for (name, installed) in &state.skills {
+            let Some(skill) = target
+                .local_skills
+                .get(name)
+                .or_else(|| skill_map.get(name).copied())
+            else {
+                continue;
+            };
+            let Some(version) = skill.frontmatter.version.as_deref() else {
+                continue;
+            };
+            if installed.version.as_deref() != Some(version) {
+                continue;
+            }
+
+            if !hashes.contains_key(skill.skill_file.as_path()) {
+                let hash = linker::state::content_hash(&skill.path)?;
+                hashes.insert(&skill.skill_file, hash);
+            }
+            if hashes[skill.skill_file.as_path()] != installed.hash {
+                drifted
+                    .entry(&skill.skill_file)
+                    .or_insert_with(|| (skill, Vec::new()))
+                    .1
+                    .push(target.target.clone());
+            }
+        }
+    }
+
+    let findings = drifted
+        .into_values()
+        .map(|(skill, targets)| {
+            let name = &skill.name;
+            Finding::warning_with_path(
+                format!(
+                    "Skill '{}' changed since it was installed without a version bump (still {}, {} target{})",
+                    name,
+                    skill.frontmatter.version.as_deref().unwrap_or_default(),
+                    targets.len(),
+                    if targets.len() == 1 { "" } else { "s" }
+                ),
+                "Bump `version` in its frontmatter, then run `loadout install`",
+                format!("unversioned-change:{}", name),
+                skill.skill_file.clone(),
+            )
+        })
+        .collect();
+
+    Ok(findings)
+}
+
+fn check_budget(config: &Config, all_skills: &[Skill]) -> Result<Vec<Finding>> {
+    let limits = &config.budget;
+    if limits.max_target_tokens.is_none() && limits.max_skill_tokens.is_none() {
+        return Ok(Vec::new());
+    }
+
+    let finding = |message: String, fix: String, key: String| match limits.severity {
+        BudgetSeverity::Warning => Finding::warning(message, fix, key),
+        BudgetSeverity::Error => Finding::error(message, fix, key),
+    };
+
+    let mut findings = Vec::new();
+    let mut oversized = BTreeMap::new();
+    for target in budget::estimate(config, all_skills)? {
+        if let Some(limit) = limits.max_target_tokens {
+            let total = target.description_tokens();
+            if total > limit {
+                findings.push(finding(
+                    format!(
+                        "Target {} loads ~{} tokens of skill descriptions (limit {})",
+                        target.target.display(),
+                        total,
+                        limit
+                    ),
+                    "Shorten descriptions, or enable fewer skills in this target (see `loadout budget`)"
+                        .to_string(),
+                    format!("budget-target:{}", target.target.display()),
+                ));
+            }
+        }
+        if let Some(limit) = limits.max_skill_tokens {
+            for cost in target.skills {
+                if cost.body_tokens > limit {
+                    oversized.insert(cost.name.clone(), cost);
+                }
+            }
+        }
+    }
+
+    for (name, cost) in oversized {
+        findings.push(finding(
+            format!(
+                "Skill '{}' is ~{} tokens when invoked (limit {})",
+                name,
+                cost.body_tokens,
+                limits.max_skill_tokens.unwrap_or_default()
+            ),
+            format!(
+                "Move detail from {} into supporting files",
+                cost.skill_file.display()
+            ),
+            format!("budget-skill:{}", name),
+        ));
+    }
+
+    Ok(findings)
+}
+
+/// Flag skills in the same target whose descriptions would trigger alike
+///
+/// Term weights come from every discovered skill, so words common across
+/// the library don't make two descriptions look alike.
+fn check_trigger_overlap(config: &Config, all_skills: &[Skill]) -> Result<Vec<Finding>> {
+    let index: HashMap<&str, usize> = all_skills
+        .iter()
+        .enumerate()
+        .map(|(i, s)| (s.name.as_str(), i))
+        .collect();
+    let tfidf = TfIdf::new(
+        all_skills
+            .iter()
+            .map(|s| s.frontmatter.description.as_str()),
+    );
+    let threshold = f64::from(config.check.overlap_threshold);
+
+    let mut pairs = BTreeSet::new();
+    for target in plan::build(config, all_skills, &[])?.targets {
+        let members: Vec<usize> = target
+            .skills
+            .iter()
+            .filter_map(|name| index.get(name.as_str()).copied())
+            .collect();
+        for (i, &a) in members.iter().enumerate() {
+            for &b in &members[i + 1..] {
+                let (a, b) = if all_skills[a].name <= all_skills[b].name {
+                    (a, b)
+                } else {
+                    (b, a)
+                };
+                pairs.insert((
+                    all_skills[a].name.as_str(),
+                    all_skills[b].name.as_str(),
+                    a,
+                    b,
+                ));
+            }
+        }
+    }
+
+    let mut findings = Vec::new();
+    for (name_a, name_b, a, b) in pairs {
+        let similarity = tfidf.cosine(a, b);
+        if similarity < threshold {
+            continue;
+        }
+
+        let shared = tfidf.shared_terms(a, b);
+        let shared: Vec<String> = shared
+            .iter()
+            .take(MAX_SHARED_TERMS)
+            .map(|t| format!("'{}'", t))
+            .collect();
+        findings.push(Finding::warning(
+            format!(
+                "Skills '{}' and '{}' have overlapping descriptions (similarity {:.2}; shared terms: {})",
+                name_a,
+                name_b,
+                similarity,
+                shared.join(", ")
+            ),
+            "Reword one description so it says when to use that skill instead of the other",
+            format!("overlap:{}:{}", name_a, name_b),
+        ));
+    }
+
+    Ok(findings)
+}
+
+/// Classify a link that leaves the skill directory
+///
+/// Links into another skill are fine as long as they use the sibling form
+/// `../<name>/...`, which is how skills sit next to each other in a target.
+fn check_escaping_link(
+    skill: &Skill,
+    link: &skill::Link,
+    path: &Path,
+    all_skills: &[Skill],
+) -> Option<Finding> {
+    if let Some((name, rest)) = skill::links::sibling_skill(path) {
+        if let Some(other) = all_skills.iter().find(|s| s.name == name) {
+            if other.path.join(&rest).exists() {
+                return None;
+            }
+            return Some(Finding::error_with_path(
+                format!(
+                    "Skill '{}' links to missing file '{}' in skill '{}' (line {})",
+                    skill.name, link.target, other.name, link.line
+                ),
+                format!(
+                    "Create {}, or fix the link at line {}",
+                    other.path.join(&rest).display(),
+                    link.line
+                ),
+                format!("broken-link:{}:{}", skill.name, link.target),
+                skill.skill_file.clone(),
+            ));
+        }
+    }
+
+    let resolved = skill::links::normalize(&skill.path.join(path));
+    if let Some(other) = all_skills
+        .iter()
+        .find(|s| s.name != skill.name && resolved.starts_with(skill::links::normalize(&s.path)))
+    {
+        let rest = resolved
+            .strip_prefix(skill::links::normalize(&other.path))
+            .unwrap_or(Path::new(""));
+        return Some(Finding::warning_with_path(
+            format!(
+                "Skill '{}' links to '{}' via '{}', which won't resolve once symlinked into a target (line {})",
+                skill.name, other.name, link.target, link.line
+            ),
+            format!(
+                "Use '{}' so the link works from any target directory",
+                Path::new("..").join(&other.name).join(rest).display()
+            ),
+            format!("link-portability:{}:{}", skill.name, link.target),
+            skill.skill_file.clone(),
+        ));
+    }
+
+    Some(Finding::warning_with_path(
+        format!(
+            "Skill '{}' links outside its directory: '{}' (line {})",
+            skill.name, link.target, link.line
+        ),
+        format!(
+            "Move the file into {} so the link survives symlinking",
+            skill.path.display()
+        ),
+        format!("escaping-link:{}:{}", skill.name, link.target),
+        skill.skill_file.clone(),
+    ))
+}
+
+pub fn print_fixes(fixes: &[AppliedFix]) {
+    for fix in fixes {
+        println!(
+            "{} {}:{}:{} '{}' → '{}'",
+            "Fixed".green().bold(),
+            fix.path.display(),
+            fix.line,
+            fix.column,
+            fix.from,
+            fix.to
+        );
+    }
+}
+
 pub fn print_findings(findings: &[Finding]) {
     if findings.is_empty() {
         println!("{}", "No issues found.".green());
@@ -607,6 +1407,7 @@ pub fn exit_code(findings: &[Finding]) -> i32 {
 mod tests {
     use super::*;
     use crate::config::{default_target_aliases, TargetAliasPaths};
+    use crate::skill::testing::write_skill;
     use std::fs;
     use std::path::Path;
     use tempfile::TempDir;
@@ -644,6 +1445,10 @@ mod tests {
                 metadata: None,
                 tags: None,
                 pipeline: None,
+                crossref: None,
+                status: None,
+                replaced_by: None,
+                version: None,
             },
         }
     }
@@ -663,20 +1468,31 @@ mod tests {
             temp.path().join("project"),
             crate::config::Project {
                 skills: vec![],
+                exclude: vec![],
+                sources: vec![],
                 inherit: true,
                 targets: None,
             },
         );
 
         Config {
-            sources: crate::config::Sources { skills: vec![] },
+            sources: crate::config::Sources {
+                skills: vec![],
+                templates: vec![],
+            },
             global: crate::config::Global {
                 targets: vec!["test_runner".to_string()],
                 skills: vec![],
+                exclude: vec![],
+                allow_drafts: false,
             },
             target_aliases,
             projects,
             check: Default::default(),
+            crossref: Default::default(),
+            budget: Default::default(),
+            profile: None,
+            profiles: Default::default(),
         }
     }
 
@@ -689,7 +1505,10 @@ mod tests {
             vec![skill::CrossRef {
                 target: "nonexistent".to_string(),
                 line: 10,
+                column: 1,
+                heading: None,
                 method: skill::DetectionMethod::XmlCrossref,
+                confidence: 1.0,
             }],
         );
 
@@ -705,20 +1524,94 @@ mod tests {
         assert!(findings[0].fix.contains("loadout new nonexistent"));
     }
 
+    #[test]
+    fn should_suggest_similar_skill_for_dangling_reference() {
+        // Given
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert(
+            "skill-a".to_string(),
+            vec![skill::CrossRef {
+                target: "skill-reviw".to_string(),
+                line: 3,
+                column: 1,
+                heading: None,
+                method: skill::DetectionMethod::XmlCrossref,
+                confidence: 1.0,
+            }],
+        );
+        let review = test_skill("skill-review", "Reviews skills");
+        let skill_map: HashMap<String, &Skill> =
+            HashMap::from([("skill-review".to_string(), &review)]);
+
+        // When
+        let findings = check_dangling_references(&crossrefs, &skill_map);
+
+        // Then
+        assert_eq!(findings.len(), 1);
+        assert!(findings[0]
+            .fix
+            .starts_with("Did you mean 'skill-review'? Fix the reference at line 3"));
+        assert!(findings[0].fix.contains("`loadout check --fix`"));
+    }
+
+    #[test]
+    fn should_fix_dangling_references_with_one_strong_candidate() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let skills_dir = temp.path().join("skills");
+        for name in ["writer", "voice", "deploy"] {
+            fs::create_dir_all(skills_dir.join(name)).unwrap();
+            fs::write(
+                skills_dir.join(name).join("SKILL.md"),
+                format!("---\nname: {}\ndescription: Test skill\n---\n", name),
+            )
+            .unwrap();
+        }
+        fs::write(
+            skills_dir.join("writer/SKILL.md"),
+            "---\nname: writer\ndescription: Test skill\n---\n\
+             <see ref=\"voise\">tone</see> <see ref=\"depoy\">ship</see>\n\
+             <see ref=\"kubernetes\">infra</see>\n",
+        )
+        .unwrap();
+        let mut config = test_config_with_project(&temp);
+        config.sources.skills = vec![skills_dir.clone()];
+
+        // When
+        let fixes = fix_dangling_references(&config, None).unwrap();
+
+        // Then
+        let renamed: Vec<(&str, &str)> = fixes
+            .iter()
+            .map(|f| (f.from.as_str(), f.to.as_str()))
+            .collect();
+        assert_eq!(renamed, vec![("voise", "voice"), ("depoy", "deploy")]);
+        let content = fs::read_to_string(skills_dir.join("writer/SKILL.md")).unwrap();
+        assert!(content.contains("<see ref=\"voice\">tone</see> <see ref=\"deploy\">ship</see>"));
+        assert!(content.contains("<see ref=\"kubernetes\">"));
+    }
+
     #[test]
     fn should_detect_orphaned_skills() {
         // Given
         let config = Config {
             sources: crate::config::Sources {
                 skills: vec![PathBuf::from("/test/skills")],
+                templates: vec![],
             },
             global: crate::config::Global {
                 targets: vec![],
                 skills: vec!["skill-a".to_string()],
+                exclude: vec![],
+                allow_drafts: false,
             },
             target_aliases: default_target_aliases(),
             projects: HashMap::new(),
             check: Default::default(),
+            crossref: Default::default(),
+            budget: Default::default(),
+            profile: None,
+            profiles: Default::default(),
         };
 
         let skills = vec![
@@ -736,6 +1629,45 @@ mod tests {
         assert!(findings[0].fix.contains("loadout.toml"));
     }
 
+    #[test]
+    fn should_check_project_sources_for_orphans_and_dangling_references() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let global_source = temp.path().join("skills");
+        let project = temp.path().join("project");
+        let local_source = project.join(".skills");
+        write_skill(&global_source, "writer", "");
+        write_skill(&local_source, "deploy", "");
+        write_skill(&local_source, "scratch", "");
+        fs::write(
+            local_source.join("deploy/SKILL.md"),
+            "---\nname: deploy\ndescription: Deploys\n---\n\
+             See <see ref=\"writer\">style</see> and <see ref=\"rollbak\">undo</see>.\n",
+        )
+        .unwrap();
+        let mut config = test_config_with_project(&temp);
+        config.sources.skills = vec![global_source.clone()];
+        let entry = config.projects.get_mut(&project).unwrap();
+        entry.skills = vec!["deploy".to_string()];
+        entry.sources = vec![local_source];
+        let all_skills = skill::discover_all(&config.sources.skills).unwrap();
+
+        // When
+        let findings = check_project_sources(&config, &all_skills, None).unwrap();
+
+        // Then
+        let keys: Vec<&str> = findings.iter().map(|f| f.suppress_key.as_str()).collect();
+        let orphan_key = format!("orphaned:{}:scratch", project.display());
+        assert_eq!(keys, vec!["dangling:deploy:rollbak", orphan_key.as_str()]);
+        assert_eq!(findings[1].severity, Severity::Warning);
+        assert!(findings[1]
+            .message
+            .contains(&format!("sources of project {}", project.display())));
+        assert!(check_orphaned_skills(&config, &all_skills)
+            .iter()
+            .all(|f| f.suppress_key != orphan_key));
+    }
+
     #[test]
     fn should_detect_broken_symlink_in_project_target_directory() {
         // Given
@@ -801,6 +1733,43 @@ mod tests {
         assert!(findings.iter().any(|f| f.message.contains("skill-b")));
     }
 
+    #[test]
+    fn should_lint_description_quality() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let mut config = test_config_with_project(&temp);
+        let skills = vec![
+            test_skill("skill-a", "TODO: write description"),
+            test_skill("code-review", "Code review skill for our team"),
+            test_skill(
+                "deploy",
+                "Deploys the staging environment. Use when a release branch is cut.",
+            ),
+        ];
+
+        // When
+        let findings = check_description_quality(&config, &skills).unwrap();
+
+        // Then
+        let keys: Vec<&str> = findings.iter().map(|f| f.suppress_key.as_str()).collect();
+        assert_eq!(
+            keys,
+            vec![
+                "description:code-review:when-clause",
+                "description:code-review:first-person",
+            ]
+        );
+        assert!(findings[0].fix.starts_with("Add a trigger"));
+
+        config
+            .check
+            .description
+            .rules
+            .insert("first-person".to_string(), false);
+        let findings = check_description_quality(&config, &skills).unwrap();
+        assert_eq!(findings.len(), 1);
+    }
+
     #[test]
     fn should_detect_pipeline_integrity_issues() {
         // Given: skill-a declares after: [skill-b] but skill-b doesn't declare before: [skill-a]
@@ -838,6 +1807,10 @@ mod tests {
                         );
                         m
                     }),
+                    crossref: None,
+                    status: None,
+                    replaced_by: None,
+                    version: None,
                 },
             },
             Skill {
@@ -871,6 +1844,10 @@ mod tests {
                         );
                         m
                     }),
+                    crossref: None,
+                    status: None,
+                    replaced_by: None,
+                    version: None,
                 },
             },
         ];
@@ -886,6 +1863,148 @@ mod tests {
         }));
     }
 
+    #[test]
+    fn should_detect_unsatisfied_pipeline_version_constraints() {
+        // Given
+        use crate::skill::frontmatter::PipelineStage;
+
+        let stage = |after: &[&str]| {
+            let mut m = HashMap::new();
+            m.insert(
+                "blog".to_string(),
+                PipelineStage {
+                    stage: "draft".to_string(),
+                    order: 2,
+                    after: Some(after.iter().map(|s| s.to_string()).collect()),
+                    before: None,
+                },
+            );
+            Some(m)
+        };
+        let mut draft = test_skill("blog-draft", "Drafts");
+        draft.frontmatter.pipeline = stage(&["story-spine@^2", "outline@>=1.0"]);
+        let mut spine = test_skill("story-spine", "Spine");
+        spine.frontmatter.version = Some("1.4.0".to_string());
+        let mut editor = test_skill("blog-edit", "Edits");
+        editor.frontmatter.pipeline = stage(&["story-spine@^1.2"]);
+        let outline = test_skill("outline", "Outlines");
+        let skills = vec![draft, spine, editor, outline];
+        let known_skills: HashSet<String> = skills.iter().map(|s| s.name.clone()).collect();
+
+        // When
+        let findings = check_pipeline_integrity(&skills, &known_skills);
+
+        // Then
+        let versioned: Vec<&Finding> = findings
+            .iter()
+            .filter(|f| f.suppress_key.starts_with("pipeline-version:"))
+            .collect();
+        assert_eq!(versioned.len(), 2);
+        let mismatch = versioned
+            .iter()
+            .find(|f| f.suppress_key == "pipeline-version:blog:blog-draft:story-spine")
+            .unwrap();
+        assert_eq!(mismatch.severity, Severity::Error);
+        assert!(mismatch.message.contains(
+            "requires 'story-spine@^2' but 'story-spine' is 1.4.0 (from /test/skills/story-spine/SKILL.md)"
+        ));
+        let unversioned = versioned
+            .iter()
+            .find(|f| f.suppress_key == "pipeline-version:blog:blog-draft:outline")
+            .unwrap();
+        assert_eq!(unversioned.severity, Severity::Warning);
+        assert!(!findings.iter().any(|f| f.message.contains("doesn't exist")));
+    }
+
+    #[test]
+    fn should_report_malformed_pipeline_entries() {
+        // Given
+        use crate::skill::frontmatter::PipelineStage;
+
+        let mut draft = test_skill("blog-draft", "Drafts");
+        draft.frontmatter.pipeline = Some(HashMap::from([(
+            "blog".to_string(),
+            PipelineStage {
+                stage: "draft".to_string(),
+                order: 2,
+                after: Some(vec!["story-spine@two".to_string(), "outline".to_string()]),
+                before: Some(vec!["Blog_Edit".to_string()]),
+            },
+        )]));
+        let skills = vec![draft, test_skill("story-spine", "Spine")];
+        let known_skills: HashSet<String> = skills.iter().map(|s| s.name.clone()).collect();
+
+        // When
+        let findings = check_pipeline_integrity(&skills, &known_skills);
+
+        // Then
+        let mut keys: Vec<&str> = findings.iter().map(|f| f.suppress_key.as_str()).collect();
+        keys.sort();
+        assert_eq!(
+            keys,
+            vec![
+                "pipeline-entry:blog:blog-draft:Blog_Edit",
+                "pipeline-entry:blog:blog-draft:story-spine@two",
+                "pipeline-missing:blog:blog-draft:outline",
+            ]
+        );
+        let malformed = findings
+            .iter()
+            .find(|f| f.suppress_key == "pipeline-entry:blog:blog-draft:story-spine@two")
+            .unwrap();
+        assert_eq!(malformed.severity, Severity::Error);
+        assert!(malformed
+            .message
+            .contains("invalid after entry 'story-spine@two'"));
+    }
+
+    #[test]
+    fn should_detect_pipeline_cycles_and_order_contradictions() {
+        // Given
+        use crate::skill::frontmatter::PipelineStage;
+
+        let skill = |name: &str, order: u32, after: &[&str], before: &[&str]| {
+            let mut skill = test_skill(name, "Stage");
+            let list = |names: &[&str]| {
+                (!names.is_empty()).then(|| names.iter().map(|n| n.to_string()).collect())
+            };
+            skill.frontmatter.pipeline = Some(HashMap::from([(
+                "blog".to_string(),
+                PipelineStage {
+                    stage: name.to_string(),
+                    order,
+                    after: list(after),
+                    before: list(before),
+                },
+            )]));
+            skill
+        };
+        let skills = vec![
+            skill("outline", 1, &[], &["draft"]),
+            skill("draft", 2, &["outline", "polish"], &["edit"]),
+            skill("edit", 3, &["draft"], &["polish"]),
+            skill("polish", 4, &["edit"], &["draft"]),
+            skill("archive", 5, &[], &[]),
+        ];
+        let known_skills: HashSet<String> = skills.iter().map(|s| s.name.clone()).collect();
+
+        // When
+        let findings = check_pipeline_integrity(&skills, &known_skills);
+
+        // Then
+        let keys: Vec<&str> = findings.iter().map(|f| f.suppress_key.as_str()).collect();
+        assert!(keys.contains(&"pipeline-cycle:blog:draft"));
+        assert!(keys.contains(&"pipeline-order:blog:polish:draft"));
+        assert!(keys.contains(&"pipeline-unreachable:blog:archive"));
+        assert_eq!(findings.len(), 3, "{keys:?}");
+        let cycle = findings
+            .iter()
+            .find(|f| f.suppress_key == "pipeline-cycle:blog:draft")
+            .unwrap();
+        assert_eq!(cycle.severity, Severity::Error);
+        assert!(cycle.message.contains("draft → edit → polish → draft"));
+    }
+
     #[test]
     fn should_detect_missing_metadata_when_library_is_partially_annotated() {
         // Given: one tagged skill and one with no metadata
@@ -910,6 +2029,10 @@ mod tests {
                 metadata: None,
                 tags: Some(vec!["example".to_string()]),
                 pipeline: None,
+                crossref: None,
+                status: None,
+                replaced_by: None,
+                version: None,
             },
         };
 
@@ -942,6 +2065,327 @@ mod tests {
         assert!(findings.is_empty());
     }
 
+    #[test]
+    fn should_check_relative_links_and_supporting_files() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let skill_dir = temp.path().join("my-skill");
+        fs::create_dir_all(skill_dir.join("scripts")).unwrap();
+        fs::create_dir_all(skill_dir.join("assets")).unwrap();
+        fs::write(
+            skill_dir.join("SKILL.md"),
+            "---\nname: my-skill\ndescription: Test skill\n---\n\n\
+             Run [setup](scripts/setup.sh) then read [notes](references/notes.md).\n\
+             See also [shared](../../shared/guide.md).\n",
+        )
+        .unwrap();
+        fs::write(skill_dir.join("scripts/setup.sh"), "echo").unwrap();
+        fs::write(skill_dir.join("assets/unused.png"), "").unwrap();
+        let skills = vec![Skill::from_directory(&skill_dir).unwrap()];
+
+        // When
+        let findings = check_supporting_files(&skills).unwrap();
+
+        // Then
+        assert_eq!(findings.len(), 3);
+        assert!(findings.iter().any(|f| f.severity == Severity::Error
+            && f.suppress_key == "broken-link:my-skill:references/notes.md"));
+        assert!(findings.iter().any(|f| f.severity == Severity::Warning
+            && f.suppress_key == "escaping-link:my-skill:../../shared/guide.md"));
+        assert!(findings.iter().any(|f| f.severity == Severity::Info
+            && f.suppress_key == "unreferenced-file:my-skill:assets/unused.png"));
+    }
+
+    #[test]
+    fn should_not_count_link_to_skill_directory_as_referencing_files() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let skill_dir = temp.path().join("my-skill");
+        fs::create_dir_all(skill_dir.join("assets")).unwrap();
+        fs::write(
+            skill_dir.join("SKILL.md"),
+            "---\nname: my-skill\ndescription: Test skill\n---\n\n\
+             Browse [this skill](./) or [here](.) or [docs](docs/..).\n",
+        )
+        .unwrap();
+        fs::write(skill_dir.join("assets/unused.png"), "").unwrap();
+        let skills = vec![Skill::from_directory(&skill_dir).unwrap()];
+
+        // When
+        let findings = check_supporting_files(&skills).unwrap();
+
+        // Then
+        let keys: Vec<&str> = findings.iter().map(|f| f.suppress_key.as_str()).collect();
+        assert_eq!(keys, vec!["unreferenced-file:my-skill:assets/unused.png"]);
+    }
+
+    #[test]
+    fn should_check_portability_of_links_between_skills() {
+        // Given: sources nest skills in category directories
+        let temp = TempDir::new().unwrap();
+        let writer_dir = temp.path().join("writing/writer");
+        let voice_dir = temp.path().join("writing/voice");
+        let review_dir = temp.path().join("quality/review");
+        for dir in [&writer_dir, &voice_dir, &review_dir] {
+            fs::create_dir_all(dir).unwrap();
+        }
+        fs::write(
+            writer_dir.join("SKILL.md"),
+            "---\nname: writer\ndescription: Test skill\n---\n\n\
+             Load [voice](../voice/SKILL.md) and [its guide](../voice/guide.md).\n\
+             Then [review](../../quality/review/SKILL.md).\n",
+        )
+        .unwrap();
+        for (dir, name) in [(&voice_dir, "voice"), (&review_dir, "review")] {
+            fs::write(
+                dir.join("SKILL.md"),
+                format!("---\nname: {}\ndescription: Test skill\n---\n", name),
+            )
+            .unwrap();
+        }
+        let skills: Vec<Skill> = [&writer_dir, &voice_dir, &review_dir]
+            .iter()
+            .map(|d| Skill::from_directory(d).unwrap())
+            .collect();
+
+        // When
+        let findings = check_supporting_files(&skills).unwrap();
+
+        // Then
+        assert_eq!(findings.len(), 2);
+        assert!(findings.iter().any(|f| f.severity == Severity::Error
+            && f.suppress_key == "broken-link:writer:../voice/guide.md"));
+        assert!(findings.iter().any(|f| f.severity == Severity::Warning
+            && f.suppress_key == "link-portability:writer:../../quality/review/SKILL.md"
+            && f.fix == "Use '../review/SKILL.md' so the link works from any target directory"));
+    }
+
+    #[test]
+    fn should_check_lifecycle_status() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let mut config = test_config_with_project(&temp);
+        config.global.skills = vec!["old-voice".to_string(), "sketch".to_string()];
+
+        let mut old_voice = test_skill("old-voice", "Old voice");
+        old_voice.frontmatter.status = Some(SkillStatus::Deprecated);
+        old_voice.frontmatter.replaced_by = Some("voice".to_string());
+        let mut sketch = test_skill("sketch", "Draft");
+        sketch.frontmatter.status = Some(SkillStatus::Draft);
+        let mut legacy = test_skill("legacy", "Gone");
+        legacy.frontmatter.status = Some(SkillStatus::Deprecated);
+        legacy.frontmatter.replaced_by = Some("nowhere".to_string());
+        let writer = test_skill("writer", "Writes");
+        let voice = test_skill("voice", "Voice");
+        let skills = vec![old_voice, sketch, legacy, writer, voice];
+        let skill_map: HashMap<String, &Skill> =
+            skills.iter().map(|s| (s.name.clone(), s)).collect();
+
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert(
+            "writer".to_string(),
+            vec![skill::CrossRef {
+                target: "old-voice".to_string(),
+                line: 7,
+                column: 1,
+                heading: None,
+                method: skill::DetectionMethod::XmlCrossref,
+                confidence: 1.0,
+            }],
+        );
+
+        // When
+        let findings = check_lifecycle(&config, &skills, &crossrefs, &skill_map);
+
+        // Then
+        let keys: Vec<&str> = findings.iter().map(|f| f.suppress_key.as_str()).collect();
+        assert_eq!(
+            keys,
+            vec![
+                "deprecated-enabled:global:old-voice",
+                "draft-global:sketch",
+                "deprecated-ref:writer:old-voice",
+                "missing-replacement:legacy:nowhere",
+            ]
+        );
+        assert_eq!(findings[0].fix, "Enable 'voice' instead");
+        assert_eq!(findings[1].severity, Severity::Error);
+        assert_eq!(findings[2].fix, "Reference 'voice' instead");
+
+        // Allowing drafts silences the global draft error
+        config.global.allow_drafts = true;
+        let findings = check_lifecycle(&config, &skills, &crossrefs, &skill_map);
+        assert!(findings
+            .iter()
+            .all(|f| f.suppress_key != "draft-global:sketch"));
+    }
+
+    #[test]
+    fn should_detect_content_changed_without_version_bump() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let config = test_config_with_project(&temp);
+        let target = temp.path().join("global-target");
+        fs::create_dir_all(&target).unwrap();
+
+        let mut skills = Vec::new();
+        for name in ["voice", "writer", "sketch"] {
+            let dir = temp.path().join("skills").join(name);
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(dir.join("SKILL.md"), "original").unwrap();
+            let mut skill = test_skill(name, "A skill");
+            skill.path = dir;
+            skill.frontmatter.version = Some("1.0.0".to_string());
+            skills.push(skill);
+        }
+        skills[2].frontmatter.version = None;
+
+        let mut state = linker::state::TargetState::default();
+        for skill in &skills {
+            state.skills.insert(
+                skill.name.clone(),
+                linker::state::InstalledSkill::snapshot(
+                    &skill.path,
+                    skill.frontmatter.version.as_deref(),
+                )
+                .unwrap(),
+            );
+        }
+        linker::state::write(&target, &state).unwrap();
+
+        // voice changes in place; writer changes with a bump; sketch is unversioned
+        for skill in &skills {
+            fs::write(skill.path.join("SKILL.md"), "edited").unwrap();
+        }
+        skills[1].frontmatter.version = Some("1.1.0".to_string());
+        let skill_map: HashMap<String, &Skill> =
+            skills.iter().map(|s| (s.name.clone(), s)).collect();
+
+        // When
+        let findings = check_version_drift(&config, &skills, &skill_map).unwrap();
+
+        // Then
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].suppress_key, "unversioned-change:voice");
+        assert!(findings[0].message.contains("still 1.0.0, 1 target"));
+    }
+
+    #[test]
+    fn should_compare_project_targets_against_shadowing_project_skill() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let global_source = temp.path().join("skills");
+        let project = temp.path().join("project");
+        let local_source = project.join(".skills");
+        write_skill(&global_source, "voice", "version: 1.0.0\n");
+        write_skill(&local_source, "voice", "version: 1.0.0\ntags: [local]\n");
+        let mut config = test_config_with_project(&temp);
+        config.sources.skills = vec![global_source];
+        config.global.skills = vec!["voice".to_string()];
+        let entry = config.projects.get_mut(&project).unwrap();
+        entry.skills = vec!["voice".to_string()];
+        entry.sources = vec![local_source.clone()];
+        crate::commands::install(&config, false).unwrap();
+        let all_skills = skill::discover_all(&config.sources.skills).unwrap();
+        let skill_map: HashMap<String, &Skill> =
+            all_skills.iter().map(|s| (s.name.clone(), s)).collect();
+
+        // When
+        let before_edit = check_version_drift(&config, &all_skills, &skill_map).unwrap();
+        fs::write(local_source.join("voice/notes.md"), "edited").unwrap();
+        let after_edit = check_version_drift(&config, &all_skills, &skill_map).unwrap();
+
+        // Then
+        assert!(before_edit.is_empty());
+        assert_eq!(after_edit.len(), 1);
+        assert_eq!(after_edit[0].suppress_key, "unversioned-change:voice");
+        assert!(after_edit[0].message.contains("still 1.0.0, 1 target)"));
+        assert_eq!(
+            after_edit[0].path,
+            Some(local_source.join("voice/SKILL.md"))
+        );
+    }
+
+    #[test]
+    fn should_enforce_token_budget_limits() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let mut config = test_config_with_project(&temp);
+        let skills_dir = temp.path().join("skills");
+        for (name, body) in [("voice", "Short."), ("manual", &"word ".repeat(200))] {
+            let dir = skills_dir.join(name);
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(
+                dir.join("SKILL.md"),
+                format!("---\nname: {name}\ndescription: A skill\n---\n{body}\n"),
+            )
+            .unwrap();
+        }
+        config.sources.skills = vec![skills_dir];
+        config.global.skills = vec!["voice".to_string(), "manual".to_string()];
+        config.budget.max_target_tokens = Some(5);
+        config.budget.max_skill_tokens = Some(100);
+        let all_skills = skill::discover_all(&config.sources.skills).unwrap();
+
+        // When
+        let findings = check_budget(&config, &all_skills).unwrap();
+
+        // Then
+        let keys: Vec<&str> = findings.iter().map(|f| f.suppress_key.as_str()).collect();
+        let global_target = temp.path().join("global-target");
+        let project_target = temp.path().join("project/.test-runner/skills");
+        assert_eq!(
+            keys,
+            vec![
+                format!("budget-target:{}", global_target.display()),
+                format!("budget-target:{}", project_target.display()),
+                "budget-skill:manual".to_string(),
+            ]
+        );
+        assert!(findings.iter().all(|f| f.severity == Severity::Warning));
+
+        config.budget.severity = BudgetSeverity::Error;
+        let findings = check_budget(&config, &all_skills).unwrap();
+        assert!(findings.iter().all(|f| f.severity == Severity::Error));
+    }
+
+    #[test]
+    fn should_flag_overlapping_descriptions_in_shared_target() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let mut config = test_config_with_project(&temp);
+        config.global.skills = vec!["code-review".to_string(), "pr-review".to_string()];
+        config.projects.values_mut().next().unwrap().inherit = false;
+        config.projects.values_mut().next().unwrap().skills = vec!["style-check".to_string()];
+        let skills = vec![
+            test_skill(
+                "code-review",
+                "Review pull requests for correctness and style",
+            ),
+            test_skill(
+                "pr-review",
+                "Review pull requests for style and correctness issues",
+            ),
+            test_skill(
+                "style-check",
+                "Review pull requests for style and correctness",
+            ),
+            test_skill("deploy", "Deploy the staging environment"),
+        ];
+
+        // When
+        let findings = check_trigger_overlap(&config, &skills).unwrap();
+
+        // Then
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].suppress_key, "overlap:code-review:pr-review");
+        assert!(findings[0].message.contains("shared terms: "));
+        assert!(findings[0].message.contains("'style'"));
+
+        config.check.overlap_threshold = 1.0;
+        assert!(check_trigger_overlap(&config, &skills).unwrap().is_empty());
+    }
+
     #[test]
     fn should_include_fix_suggestions_in_all_findings() {
         // Given
@@ -951,7 +2395,10 @@ mod tests {
             vec![skill::CrossRef {
                 target: "missing".to_string(),
                 line: 5,
+                column: 1,
+                heading: None,
                 method: skill::DetectionMethod::XmlCrossref,
+                confidence: 1.0,
             }],
         );
 
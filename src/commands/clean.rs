@@ -1,5 +1,7 @@
 //! Clean command implementation
 
+use std::path::{Path, PathBuf};
+
 use anyhow::Result;
 use colored::Colorize;
 
@@ -9,6 +11,11 @@ use crate::paths;
 
 /// Remove all managed symlinks from target directories
 pub fn clean(config: &Config, dry_run: bool) -> Result<()> {
+    clean_scoped(config, &[], dry_run)
+}
+
+/// Clean, leaving the targets in `out_of_scope` untouched
+pub fn clean_scoped(config: &Config, out_of_scope: &[PathBuf], dry_run: bool) -> Result<()> {
     if dry_run {
         println!("{}", "[DRY RUN MODE]".yellow().bold());
         println!();
@@ -19,6 +26,9 @@ pub fn clean(config: &Config, dry_run: bool) -> Result<()> {
     // Clean global targets
     println!("{}", "--- Global scope ---".cyan().bold());
     for target in paths::global_targets(config)? {
+        if is_out_of_scope(out_of_scope, &target) {
+            continue;
+        }
         if dry_run {
             if linker::is_managed(&target) {
                 println!(
@@ -51,6 +61,9 @@ pub fn clean(config: &Config, dry_run: bool) -> Result<()> {
         );
 
         for target in paths::project_targets(config, project_path, project_config)? {
+            if is_out_of_scope(out_of_scope, &target) {
+                continue;
+            }
             if dry_run {
                 if linker::is_managed(&target) {
                     println!(
@@ -86,6 +99,19 @@ pub fn clean(config: &Config, dry_run: bool) -> Result<()> {
     Ok(())
 }
 
+/// Whether `--here` left `target` alone because another project uses it
+fn is_out_of_scope(out_of_scope: &[PathBuf], target: &Path) -> bool {
+    let skipped = out_of_scope.iter().any(|t| t == target);
+    if skipped {
+        println!(
+            "  {} {}",
+            target.display(),
+            "(skipped: also a target of a project outside --here)".dimmed()
+        );
+    }
+    skipped
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -111,10 +137,13 @@ mod tests {
         Config {
             sources: Sources {
                 skills: vec![skill_source],
+                templates: vec![],
             },
             global: Global {
                 targets: vec!["test_runner".to_string()],
                 skills: vec![],
+                exclude: vec![],
+                allow_drafts: false,
             },
             target_aliases,
             projects: {
@@ -123,6 +152,8 @@ mod tests {
                     project_path,
                     Project {
                         skills: vec![],
+                        exclude: vec![],
+                        sources: vec![],
                         inherit: false,
                         targets: None,
                     },
@@ -130,6 +161,10 @@ mod tests {
                 projects
             },
             check: Default::default(),
+            crossref: Default::default(),
+            budget: Default::default(),
+            profile: None,
+            profiles: Default::default(),
         }
     }
 
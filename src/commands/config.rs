@@ -0,0 +1,61 @@
+//! Config command implementation
+//!
+//! `loadout config validate` reports every problem in `loadout.toml` at
+//! once, and `loadout config schema` prints a JSON Schema for editors.
+
+use std::path::Path;
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::config::{schema, validate};
+
+/// Validate the config file, printing each problem with its location
+pub fn validate_config(config_path: &Path) -> Result<()> {
+    println!("{} {}", "Validating".cyan().bold(), config_path.display());
+    println!();
+
+    let problems = validate::validate_file(config_path)?;
+    if problems.is_empty() {
+        println!("{} No problems found", "✓".green().bold());
+        return Ok(());
+    }
+
+    for problem in &problems {
+        match problem.location {
+            Some(location) => println!(
+                "  {} {}:{}:{} {}",
+                "✗".red(),
+                config_path.display(),
+                location.line,
+                location.column,
+                problem.message
+            ),
+            None => println!(
+                "  {} {} {}",
+                "✗".red(),
+                config_path.display(),
+                problem.message
+            ),
+        }
+    }
+
+    println!();
+    println!(
+        "{} {} {}",
+        "✗".red().bold(),
+        problems.len(),
+        if problems.len() == 1 {
+            "problem"
+        } else {
+            "problems"
+        }
+    );
+    Err(anyhow::anyhow!("Validation failed"))
+}
+
+/// Print the JSON Schema for `loadout.toml`
+pub fn print_schema() -> Result<()> {
+    println!("{}", serde_json::to_string_pretty(&schema::schema())?);
+    Ok(())
+}
@@ -1,9 +1,10 @@
 use anyhow::Result;
 use std::collections::HashMap;
-use std::fs;
+use std::path::Path;
 
 use crate::config::Config;
 use crate::graph::SkillGraph;
+use crate::plan;
 use crate::skill;
 
 #[derive(Debug, Clone, Copy)]
@@ -33,22 +34,31 @@ pub enum GraphFilter {
     Tag(String),
 }
 
-pub fn graph(config: &Config, format: OutputFormat, filter: GraphFilter) -> Result<()> {
+/// Print the skill graph
+///
+/// `min_confidence` overrides `crossref.min_confidence` from config.
+pub fn graph(
+    config: &Config,
+    format: OutputFormat,
+    filter: GraphFilter,
+    min_confidence: Option<f32>,
+    project: Option<&Path>,
+) -> Result<()> {
     use std::collections::HashSet;
 
-    // Discover all skills
-    let all_skills = skill::discover_all(&config.sources.skills)?;
+    let all_skills = graph_skills(config, project)?;
 
     // Build set of known skill names for filtering
     let known_skills: HashSet<String> = all_skills.iter().map(|s| s.name.clone()).collect();
 
     // Extract cross-references
+    let mut options = skill::ExtractOptions::from_config(&config.crossref)?;
+    if let Some(min) = min_confidence {
+        options.min_confidence = min;
+    }
     let mut crossrefs = HashMap::new();
     for skill in &all_skills {
-        let skill_md = skill.path.join("SKILL.md");
-        let content = fs::read_to_string(&skill_md)?;
-        let refs =
-            skill::extract_references_with_filter(&content, &skill.name, Some(&known_skills));
+        let refs = skill::references_for_skill(skill, Some(&known_skills), &options)?;
         if !refs.is_empty() {
             crossrefs.insert(skill.name.clone(), refs);
         }
@@ -104,9 +114,83 @@ pub fn graph(config: &Config, format: OutputFormat, filter: GraphFilter) -> Resu
     Ok(())
 }
 
+/// All discovered skills, plus a project's own sources when asked
+fn graph_skills(config: &Config, project: Option<&Path>) -> Result<Vec<skill::Skill>> {
+    let skills = skill::discover_all(&config.sources.skills)?;
+    match project {
+        Some(path) => {
+            let (_, project) = plan::find_project(config, path)?;
+            plan::project_skills(project, &skills)
+        }
+        None => Ok(skills),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::{default_target_aliases, Global, Project, Sources};
+    use crate::skill::testing::write_skill;
+    use tempfile::TempDir;
+
+    #[test]
+    fn should_add_project_sources_to_graph_for_that_project_only() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let global_source = temp.path().join("skills");
+        let project = temp.path().join("app");
+        let other = temp.path().join("other");
+        write_skill(&global_source, "writer", "");
+        write_skill(&project.join(".skills"), "deploy", "");
+        std::fs::create_dir_all(&other).unwrap();
+        let project_config = |sources: Vec<std::path::PathBuf>| Project {
+            skills: vec![],
+            exclude: vec![],
+            sources,
+            inherit: true,
+            targets: None,
+        };
+        let config = Config {
+            sources: Sources {
+                skills: vec![global_source],
+                templates: vec![],
+            },
+            global: Global {
+                targets: vec![],
+                skills: vec![],
+                exclude: vec![],
+                allow_drafts: false,
+            },
+            target_aliases: default_target_aliases(),
+            projects: HashMap::from([
+                (
+                    project.clone(),
+                    project_config(vec![project.join(".skills")]),
+                ),
+                (other.clone(), project_config(vec![])),
+            ]),
+            check: Default::default(),
+            crossref: Default::default(),
+            budget: Default::default(),
+            profile: None,
+            profiles: Default::default(),
+        };
+        let names = |skills: Vec<skill::Skill>| {
+            let mut names: Vec<String> = skills.into_iter().map(|s| s.name).collect();
+            names.sort();
+            names
+        };
+
+        // When
+        let global = graph_skills(&config, None).unwrap();
+        let in_project = graph_skills(&config, Some(&project)).unwrap();
+        let in_other = graph_skills(&config, Some(&other)).unwrap();
+
+        // Then
+        assert_eq!(names(global), vec!["writer"]);
+        assert_eq!(names(in_project), vec!["deploy", "writer"]);
+        assert_eq!(names(in_other), vec!["writer"]);
+    }
 
     #[test]
     fn should_parse_output_format_case_insensitive() {
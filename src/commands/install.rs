@@ -1,6 +1,6 @@
 //! Install command implementation
 
-use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
@@ -8,17 +8,13 @@ use colored::Colorize;
 
 use crate::config::Config;
 use crate::linker;
+use crate::plan::{self, InstallPlan, TargetPlan};
 use crate::skill;
 
-#[derive(Debug)]
-struct TargetPlan {
-    target: PathBuf,
-    skills: Vec<String>,
-}
-
-#[derive(Debug)]
-struct InstallPlan {
-    targets: Vec<TargetPlan>,
+/// A target's plan with its skill name and source directory pairs resolved
+struct ResolvedTarget<'a> {
+    plan: &'a TargetPlan,
+    skills: Vec<(String, PathBuf)>,
 }
 
 /// Install skills by creating symlinks in target directories
@@ -28,19 +24,37 @@ struct InstallPlan {
 /// - Links global skills to global target directories
 /// - Links project skills to project-local target directories
 /// - Respects project `inherit` setting for global skills
+/// - Expands `pipeline:`, `tag:`, and glob selectors against discovered skills
+/// - Leaves out skills matched by `exclude`
 pub fn install(config: &Config, dry_run: bool) -> Result<()> {
+    install_scoped(config, &[], dry_run)
+}
+
+/// Install, leaving the targets in `out_of_scope` untouched
+pub fn install_scoped(config: &Config, out_of_scope: &[PathBuf], dry_run: bool) -> Result<()> {
     // Discover all available skills
     let skills = skill::discover_all(&config.sources.skills)
         .context("Failed to discover skills from source directories")?;
 
+    let global = plan::global_selection(config, &skills);
+    let install_plan = plan::build(config, &skills, out_of_scope)?;
     let skill_map = skill::build_skill_map(skills);
-    let install_plan = build_install_plan(config)?;
+    validate_global_drafts(config, &global, &skill_map)?;
 
     if dry_run {
         println!("{}", "[DRY RUN MODE]".yellow().bold());
         println!();
     }
 
+    for unmatched in &install_plan.unmatched {
+        println!(
+            "{} '{}' in {} matches no skill",
+            "Warning:".yellow().bold(),
+            unmatched.selector,
+            unmatched.scope
+        );
+    }
+
     // Reconcile + link targets
     install_targets(&install_plan, &skill_map, dry_run)?;
 
@@ -52,169 +66,115 @@ pub fn install(config: &Config, dry_run: bool) -> Result<()> {
     Ok(())
 }
 
-fn build_install_plan(config: &Config) -> Result<InstallPlan> {
-    let mut aliases: Vec<_> = config.target_aliases.keys().cloned().collect();
-    aliases.sort();
-    validate_global_aliases(config)?;
-
-    let selected_global: HashSet<_> = config.global.targets.iter().cloned().collect();
-    let mut consolidated: BTreeMap<PathBuf, BTreeSet<String>> = BTreeMap::new();
-
-    for alias in &aliases {
-        let alias_paths = config
-            .target_aliases
-            .get(alias)
-            .context(format!("Unknown target alias '{alias}' in global.targets"))?;
-
-        if selected_global.contains(alias) {
-            unique_skills(config.global.skills.iter().cloned())
-        } else {
-            Vec::new()
-        }
-        .into_iter()
-        .for_each(|skill_name| {
-            consolidated
-                .entry(alias_paths.global.clone())
-                .or_default()
-                .insert(skill_name);
-        });
-
-        consolidated.entry(alias_paths.global.clone()).or_default();
-    }
-
-    let mut project_entries: Vec<_> = config.projects.iter().collect();
-    project_entries.sort_by(|(left, _), (right, _)| left.cmp(right));
-
-    for (project_path, project_config) in project_entries {
-        validate_project_aliases(config, project_path, project_config)?;
-
-        let selected_aliases: HashSet<String> = project_config
-            .targets
-            .as_ref()
-            .unwrap_or(&config.global.targets)
-            .iter()
-            .cloned()
-            .collect();
-
-        for alias in &aliases {
-            let alias_paths = config.target_aliases.get(alias).context(format!(
-                "Unknown target alias '{alias}' in projects.\"{}\".targets",
-                project_path.display()
-            ))?;
-
-            let target = if alias_paths.project.is_relative() {
-                project_path.join(&alias_paths.project)
-            } else {
-                alias_paths.project.clone()
-            };
-
-            consolidated.entry(target.clone()).or_default();
-
-            let mut skills = Vec::new();
-            if selected_aliases.contains(alias) {
-                if project_config.inherit {
-                    skills.extend(config.global.skills.iter().cloned());
-                }
-                skills.extend(project_config.skills.iter().cloned());
-            }
-
-            for skill_name in unique_skills(skills) {
-                consolidated
-                    .entry(target.clone())
-                    .or_default()
-                    .insert(skill_name);
-            }
-        }
+/// Refuse `status: draft` skills in global scope unless `global.allow_drafts`
+fn validate_global_drafts(
+    config: &Config,
+    global: &plan::Selection,
+    skill_map: &HashMap<String, skill::Skill>,
+) -> Result<()> {
+    if config.global.allow_drafts {
+        return Ok(());
     }
 
-    let targets = consolidated
-        .into_iter()
-        .map(|(target, skills)| TargetPlan {
-            target,
-            skills: skills.into_iter().collect(),
+    let drafts: Vec<&str> = global
+        .names()
+        .filter(|name| {
+            skill_map
+                .get(*name)
+                .is_some_and(|s| s.frontmatter.status() == skill::SkillStatus::Draft)
         })
         .collect();
 
-    Ok(InstallPlan { targets })
-}
-
-fn validate_global_aliases(config: &Config) -> Result<()> {
-    for alias in &config.global.targets {
-        if !config.target_aliases.contains_key(alias) {
-            anyhow::bail!("Unknown target alias '{alias}' in global.targets");
-        }
-    }
-    Ok(())
-}
-
-fn validate_project_aliases(
-    config: &Config,
-    project_path: &Path,
-    project_config: &crate::config::Project,
-) -> Result<()> {
-    let aliases = project_config
-        .targets
-        .as_ref()
-        .unwrap_or(&config.global.targets);
-    for alias in aliases {
-        if !config.target_aliases.contains_key(alias) {
-            anyhow::bail!(
-                "Unknown target alias '{alias}' in projects.\"{}\".targets",
-                project_path.display()
-            );
-        }
+    if !drafts.is_empty() {
+        anyhow::bail!(
+            "Draft skills can't be installed in global scope: {}. Enable them for a project instead, or set allow_drafts = true under [global]",
+            drafts.join(", ")
+        );
     }
     Ok(())
 }
 
-fn unique_skills(skills: impl IntoIterator<Item = String>) -> Vec<String> {
-    let mut deduped = BTreeSet::new();
-    for skill in skills {
-        deduped.insert(skill);
-    }
-    deduped.into_iter().collect()
-}
-
 /// Reconcile + install skills to all unique target directories.
 fn install_targets(
-    plan: &InstallPlan,
+    install_plan: &InstallPlan,
     skill_map: &HashMap<String, skill::Skill>,
     dry_run: bool,
 ) -> Result<()> {
-    let resolved = resolve_planned_skills(plan, skill_map)?;
+    let resolved = resolve_planned_skills(install_plan, skill_map)?;
 
     println!("{}", "--- Reconcile targets ---".cyan().bold());
-    for (target, skills) in resolved {
+    for target in &install_plan.skipped {
+        println!(
+            "Target: {} {}",
+            target.display(),
+            "(skipped: also a target of a project outside --here)".dimmed()
+        );
+    }
+    for ResolvedTarget { plan, skills } in resolved {
+        let target = &plan.target;
         let desired_skill_names: Vec<String> =
             skills.iter().map(|(name, _)| name.clone()).collect();
 
         println!("Target: {}", target.display());
-        prune_stale_links(&target, &desired_skill_names, dry_run)?;
+        prune_stale_links(target, &desired_skill_names, dry_run)?;
+
+        for (skill_name, skill_path) in &skills {
+            let via = plan.via.get(skill_name).map(String::as_str);
+            install_resolved_skill(skill_name, skill_path, target, via, dry_run)?;
+        }
 
-        for (skill_name, skill_path) in skills {
-            install_resolved_skill(&skill_name, &skill_path, &target, dry_run)?;
+        if !dry_run && linker::is_managed(target) {
+            record_install_state(target, &skills, plan, skill_map)?;
         }
     }
 
     Ok(())
 }
 
-fn resolve_planned_skills(
-    plan: &InstallPlan,
+/// Record the version and content hash of each linked skill in the target
+fn record_install_state(
+    target: &Path,
+    skills: &[(String, PathBuf)],
+    target_plan: &TargetPlan,
+    skill_map: &HashMap<String, skill::Skill>,
+) -> Result<()> {
+    let mut state = linker::state::TargetState::default();
+    for (skill_name, skill_path) in skills {
+        let version = target_plan
+            .local_skills
+            .get(skill_name)
+            .or_else(|| skill_map.get(skill_name))
+            .and_then(|s| s.frontmatter.version.as_deref());
+        state.skills.insert(
+            skill_name.clone(),
+            linker::state::InstalledSkill::snapshot(skill_path, version)?,
+        );
+    }
+    linker::state::write(target, &state)
+}
+
+fn resolve_planned_skills<'a>(
+    install_plan: &'a InstallPlan,
     skill_map: &HashMap<String, skill::Skill>,
-) -> Result<Vec<(PathBuf, Vec<(String, PathBuf)>)>> {
+) -> Result<Vec<ResolvedTarget<'a>>> {
     let mut resolved = Vec::new();
 
-    for target_plan in &plan.targets {
+    for target_plan in &install_plan.targets {
         let mut target_skills = Vec::new();
         for skill_name in &target_plan.skills {
-            let skill = skill_map.get(skill_name).context(format!(
-                "Skill '{}' not found in source directories",
-                skill_name
-            ))?;
+            let skill = target_plan
+                .local_skills
+                .get(skill_name)
+                .or_else(|| skill_map.get(skill_name))
+                .ok_or_else(|| {
+                    skill::SkillError::not_found(skill_name, skill_map.keys().map(String::as_str))
+                })?;
             target_skills.push((skill_name.clone(), skill.path.clone()));
         }
-        resolved.push((target_plan.target.clone(), target_skills));
+        resolved.push(ResolvedTarget {
+            plan: target_plan,
+            skills: target_skills,
+        });
     }
 
     Ok(resolved)
@@ -247,14 +207,20 @@ fn install_resolved_skill(
     skill_name: &str,
     skill_path: &Path,
     target: &Path,
+    via: Option<&str>,
     dry_run: bool,
 ) -> Result<()> {
+    let via = via
+        .map(|selector| format!(" (via {})", selector).dimmed().to_string())
+        .unwrap_or_default();
+
     if dry_run {
         println!(
-            "  {} {} -> {}",
+            "  {} {} -> {}{}",
             "[dry-run]".yellow(),
             skill_path.display(),
-            target.join(skill_name).display()
+            target.join(skill_name).display(),
+            via
         );
     } else {
         linker::link_skill(skill_name, skill_path, target).context(format!(
@@ -264,10 +230,11 @@ fn install_resolved_skill(
         ))?;
 
         println!(
-            "  {} {} -> {}",
+            "  {} {} -> {}{}",
             "linked:".green(),
             skill_name,
-            target.display()
+            target.display(),
+            via
         );
     }
 
@@ -321,10 +288,13 @@ mod tests {
         Config {
             sources: Sources {
                 skills: vec![skill_source],
+                templates: vec![],
             },
             global: Global {
                 targets: vec!["test_runner".to_string()],
                 skills: vec!["test-skill".to_string()],
+                exclude: vec![],
+                allow_drafts: false,
             },
             target_aliases,
             projects: {
@@ -333,6 +303,8 @@ mod tests {
                     project_path,
                     Project {
                         skills: vec!["another-skill".to_string()],
+                        exclude: vec![],
+                        sources: vec![],
                         inherit: true,
                         targets: None,
                     },
@@ -340,6 +312,10 @@ mod tests {
                 projects
             },
             check: Default::default(),
+            crossref: Default::default(),
+            budget: Default::default(),
+            profile: None,
+            profiles: Default::default(),
         }
     }
 
@@ -381,6 +357,33 @@ mod tests {
         assert!(global_target.join("test-skill").is_symlink());
     }
 
+    #[test]
+    fn should_record_installed_version_and_hash_per_target() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        create_test_skills(&temp);
+        let skill_file = temp.path().join("skills/test-skill/SKILL.md");
+        fs::write(
+            &skill_file,
+            "---\nname: test-skill\ndescription: Test skill\nversion: 1.0.0\n---\n",
+        )
+        .unwrap();
+        let config = create_test_config(&temp);
+
+        // When
+        install(&config, false).unwrap();
+
+        // Then
+        let state = linker::state::read(&temp.path().join("global")).unwrap();
+        let installed = &state.skills["test-skill"];
+        assert_eq!(installed.version.as_deref(), Some("1.0.0"));
+        assert_eq!(
+            installed.hash,
+            linker::state::content_hash(skill_file.parent().unwrap()).unwrap()
+        );
+        assert!(!state.skills.contains_key("another-skill"));
+    }
+
     #[test]
     fn should_install_project_skills_with_inheritance() {
         // Given
@@ -497,6 +500,32 @@ mod tests {
         assert!(!global_target.exists());
     }
 
+    #[test]
+    fn should_refuse_draft_skills_in_global_scope_unless_allowed() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        create_test_skills(&temp);
+        fs::write(
+            temp.path().join("skills/test-skill/SKILL.md"),
+            "---\nname: test-skill\ndescription: Test skill\nstatus: draft\n---\n",
+        )
+        .unwrap();
+        let mut config = create_test_config(&temp);
+
+        // When
+        let refused = install(&config, false);
+        config.global.allow_drafts = true;
+        let allowed = install(&config, false);
+
+        // Then
+        assert!(refused
+            .unwrap_err()
+            .to_string()
+            .contains("Draft skills can't be installed in global scope: test-skill"));
+        assert!(allowed.is_ok());
+        assert!(temp.path().join("global/test-skill").exists());
+    }
+
     #[test]
     fn should_return_error_when_skill_not_found() {
         // Given
@@ -592,7 +621,7 @@ inherit = false
 "#,
         )
         .unwrap();
-        let config = crate::config::load_from(&config_path).unwrap();
+        let config = crate::config::load_from(&config_path).unwrap().config;
 
         // When
         install(&config, false).unwrap();
@@ -760,6 +789,8 @@ inherit = false
             temp.path().join("project-a"),
             Project {
                 skills: vec!["test-skill".to_string()],
+                exclude: vec![],
+                sources: vec![],
                 inherit: false,
                 targets: Some(vec!["shared".to_string()]),
             },
@@ -768,6 +799,8 @@ inherit = false
             temp.path().join("project-b"),
             Project {
                 skills: vec!["another-skill".to_string()],
+                exclude: vec![],
+                sources: vec![],
                 inherit: false,
                 targets: Some(vec!["shared".to_string()]),
             },
@@ -780,4 +813,58 @@ inherit = false
         assert!(shared_project_target.join("test-skill").exists());
         assert!(shared_project_target.join("another-skill").exists());
     }
+
+    #[test]
+    fn should_leave_targets_shared_with_other_projects_alone_when_scoped() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        create_test_skills(&temp);
+        let mut config = create_test_config(&temp);
+        let shared_project_target = temp.path().join("shared-project-target");
+        config.target_aliases.insert(
+            "shared".to_string(),
+            TargetAliasPaths {
+                global: temp.path().join("shared-global"),
+                project: shared_project_target.clone(),
+            },
+        );
+        config.global.skills.clear();
+        config.projects.clear();
+        let project_a = temp.path().join("project-a");
+        for (project, skill) in [
+            (&project_a, "test-skill"),
+            (&temp.path().join("project-b"), "another-skill"),
+        ] {
+            config.projects.insert(
+                project.clone(),
+                Project {
+                    skills: vec![skill.to_string()],
+                    exclude: vec![],
+                    sources: vec![],
+                    inherit: false,
+                    targets: Some(vec!["shared".to_string(), "test_runner".to_string()]),
+                },
+            );
+        }
+        install(&config, false).unwrap();
+        let own_target = project_a.join(".test-runner/skills");
+
+        // When
+        config.projects.get_mut(&project_a).unwrap().skills = vec!["another-skill".to_string()];
+        let scoped = paths::scope_to_project(&config, &project_a);
+        install_scoped(&scoped.config, &scoped.out_of_scope, false).unwrap();
+
+        // Then
+        assert!(own_target.join("another-skill").exists());
+        assert!(!own_target.join("test-skill").exists());
+        assert!(shared_project_target.join("test-skill").exists());
+        assert!(shared_project_target.join("another-skill").exists());
+
+        // When
+        crate::commands::clean::clean_scoped(&scoped.config, &scoped.out_of_scope, false).unwrap();
+
+        // Then
+        assert!(!own_target.join("another-skill").exists());
+        assert!(shared_project_target.join("another-skill").exists());
+    }
 }
@@ -2,11 +2,15 @@
 
 use anyhow::{Context, Result};
 use colored::Colorize;
-use std::collections::{HashMap, HashSet};
-use std::fs;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::env;
+use std::path::{Path, PathBuf};
 
 use crate::config::Config;
-use crate::skill;
+use crate::linker;
+use crate::paths;
+use crate::plan;
+use crate::skill::{self, SkillStatus};
 
 pub enum ListMode {
     Default,
@@ -17,6 +21,9 @@ pub enum ListMode {
     Tag(String),
     Pipelines,
     Pipeline(String),
+    Status,
+    Outdated,
+    Active,
 }
 
 /// List enabled skills per scope
@@ -30,31 +37,47 @@ pub fn list(config: &Config, mode: ListMode) -> Result<()> {
         ListMode::Tag(tag) => list_by_tag(config, &tag),
         ListMode::Pipelines => list_pipelines(config),
         ListMode::Pipeline(name) => list_pipeline(config, &name),
+        ListMode::Status => list_by_status(config),
+        ListMode::Outdated => list_outdated(config),
+        ListMode::Active => list_active(config),
     }
 }
 
 fn list_default(config: &Config) -> Result<()> {
     // Discover all available skills
     let skills = skill::discover_all(&config.sources.skills)?;
-    let skill_map = skill::build_skill_map(skills);
+    let global = plan::global_selection(config, &skills);
+    let skill_map = skill::build_skill_map(skills.clone());
+
+    if let Some(profile) = &config.profile {
+        println!("Profile: {}", profile.green().bold());
+        println!();
+    }
 
     // List global skills
     println!("{}", "--- Global scope ---".cyan().bold());
-    println!("Skills: {}", config.global.skills.len());
-    for skill_name in &config.global.skills {
-        if let Some(skill) = skill_map.get(skill_name) {
+    println!("Skills: {}", global.skills.len());
+    for selected in &global.skills {
+        let via = selected
+            .via
+            .as_ref()
+            .map(|selector| format!(" via {}", selector).dimmed().to_string())
+            .unwrap_or_default();
+        if let Some(skill) = skill_map.get(&selected.name) {
             println!(
-                "  {} {} ({})",
+                "  {} {} ({}){}",
                 "✓".green(),
-                skill_name,
-                skill.path.display().to_string().dimmed()
+                selected.name,
+                skill.path.display().to_string().dimmed(),
+                via
             );
         } else {
-            println!("  {} {} {}", "✗".red(), skill_name, "(not found)".red());
+            println!("  {} {} {}", "✗".red(), selected.name, "(not found)".red());
         }
     }
+    print_left_out(&global);
 
-    // List project skills
+    // List project skills, with why each is or isn't active
     for (project_path, project_config) in &config.projects {
         println!();
         println!(
@@ -63,23 +86,14 @@ fn list_default(config: &Config) -> Result<()> {
             project_path.display()
         );
 
-        let mut all_skills = Vec::new();
-
-        // Add global skills if inherited
-        if project_config.inherit {
-            all_skills.extend(config.global.skills.clone());
-        }
-
-        // Add project-specific skills
-        all_skills.extend(project_config.skills.clone());
-
-        // Deduplicate
-        all_skills.sort();
-        all_skills.dedup();
+        let pool = plan::project_skills(project_config, &skills)?;
+        let mut selection = plan::project_selection(config, project_config, &pool);
+        selection.skills.sort_by(|a, b| a.name.cmp(&b.name));
+        let project_map = skill::build_skill_map(pool);
 
         println!(
             "Skills: {} (inherit: {})",
-            all_skills.len(),
+            selection.skills.len(),
             if project_config.inherit {
                 "true"
             } else {
@@ -87,42 +101,153 @@ fn list_default(config: &Config) -> Result<()> {
             }
         );
 
-        for skill_name in &all_skills {
-            if let Some(skill) = skill_map.get(skill_name) {
-                let source = if config.global.skills.contains(skill_name) {
-                    "global".dimmed()
-                } else {
-                    "project".dimmed()
-                };
-                println!(
-                    "  {} {} ({}, {})",
-                    "✓".green(),
-                    skill_name,
-                    source,
-                    skill.path.display().to_string().dimmed()
-                );
-            } else {
-                println!("  {} {} {}", "✗".red(), skill_name, "(not found)".red());
+        for selected in &selection.skills {
+            print_selected(selected, &project_map);
+        }
+
+        if !project_config.inherit {
+            for name in global.names() {
+                if !selection.names().any(|n| n == name) {
+                    println!(
+                        "  {} {} {}",
+                        "-".dimmed(),
+                        name,
+                        "(global, not inherited)".dimmed()
+                    );
+                }
             }
         }
+        print_left_out(&selection);
+    }
+
+    Ok(())
+}
+
+/// Print the skill set that applies in the current directory
+fn list_active(config: &Config) -> Result<()> {
+    let cwd = env::current_dir().context("Failed to resolve current working directory")?;
+    let active = active_skills(config, &cwd)?;
+
+    match (&active.root, active.configured) {
+        (Some(root), true) => println!("{} {}", "Project:".cyan().bold(), root.display()),
+        (Some(root), false) => println!(
+            "{} {} {}",
+            "Project:".cyan().bold(),
+            root.display(),
+            "(not in loadout.toml; global skills apply)".dimmed()
+        ),
+        (None, _) => println!("{}", "Not inside a project; global skills apply".dimmed()),
     }
+    let skill_map = skill::build_skill_map(active.skills);
+
+    println!("Skills: {}", active.selection.skills.len());
+    for selected in &active.selection.skills {
+        print_selected(selected, &skill_map);
+    }
+    print_left_out(&active.selection);
 
     Ok(())
 }
 
+/// The skill set that applies in a directory
+struct ActiveSkills {
+    /// Enclosing project, if any
+    root: Option<PathBuf>,
+    /// Whether `root` has a `[projects]` entry
+    configured: bool,
+    /// Selected skills, sorted by name
+    selection: plan::Selection,
+    /// Skills the selection was drawn from
+    skills: Vec<skill::Skill>,
+}
+
+/// Select the skills of the project enclosing `dir`, or the global ones
+fn active_skills(config: &Config, dir: &Path) -> Result<ActiveSkills> {
+    let skills = skill::discover_all(&config.sources.skills)?;
+    let root = paths::detect_project(config, dir);
+
+    let (configured, skills, mut selection) =
+        match root.as_ref().and_then(|root| config.projects.get(root)) {
+            Some(project) => {
+                let skills = plan::project_skills(project, &skills)?;
+                let selection = plan::project_selection(config, project, &skills);
+                (true, skills, selection)
+            }
+            None => {
+                let selection = plan::global_selection(config, &skills);
+                (false, skills, selection)
+            }
+        };
+    selection.skills.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(ActiveSkills {
+        root,
+        configured,
+        selection,
+        skills,
+    })
+}
+
+fn print_selected(selected: &plan::SelectedSkill, skill_map: &HashMap<String, skill::Skill>) {
+    if let Some(skill) = skill_map.get(&selected.name) {
+        println!(
+            "  {} {} ({}, {})",
+            "✓".green(),
+            selected.name,
+            reason(selected).dimmed(),
+            skill.path.display().to_string().dimmed()
+        );
+    } else {
+        println!("  {} {} {}", "✗".red(), selected.name, "(not found)".red());
+    }
+}
+
+/// Why a project has a skill: listed, inherited, or matched by a selector
+fn reason(selected: &plan::SelectedSkill) -> String {
+    match (&selected.via, selected.inherited) {
+        (None, false) => "listed".to_string(),
+        (None, true) => "inherited".to_string(),
+        (Some(selector), false) => format!("via {}", selector),
+        (Some(selector), true) => format!("inherited via {}", selector),
+    }
+}
+
+/// Print excluded skills and selectors that matched nothing
+fn print_left_out(selection: &plan::Selection) {
+    for excluded in &selection.excluded {
+        let scope = match (excluded.global, excluded.listed) {
+            (true, true) => "[global] exclude, which wins over listing it",
+            (true, false) => "[global] exclude",
+            (false, _) => "project exclude",
+        };
+        println!(
+            "  {} {} {}",
+            "-".dimmed(),
+            excluded.name,
+            format!("(excluded by '{}' in {})", excluded.rule, scope).dimmed()
+        );
+    }
+    for selector in &selection.unmatched {
+        println!(
+            "  {} {} {}",
+            "✗".red(),
+            selector,
+            "(matches no skill)".red()
+        );
+    }
+}
+
 #[cfg(feature = "graph")]
 fn list_groups(config: &Config) -> Result<()> {
     use crate::graph::SkillGraph;
 
     let skills = skill::discover_all(&config.sources.skills)?;
     let known_skills: HashSet<String> = skills.iter().map(|s| s.name.clone()).collect();
+    let options = skill::ExtractOptions::from_config(&config.crossref)?;
     let mut crossrefs = HashMap::new();
 
     for skill in &skills {
-        let skill_md = skill.path.join("SKILL.md");
-        let content = fs::read_to_string(&skill_md)?;
-        let refs =
-            skill::extract_references_with_filter(&content, &skill.name, Some(&known_skills));
+        let refs = skill::references_for_skill(skill, Some(&known_skills), &options)?;
         if !refs.is_empty() {
             crossrefs.insert(skill.name.clone(), refs);
         }
@@ -204,17 +329,17 @@ fn list_refs(config: &Config, skill_name: &str) -> Result<()> {
 
     // Check if skill exists
     if !skill_map.contains_key(skill_name) {
-        anyhow::bail!("Skill '{}' not found in any source", skill_name);
+        return Err(
+            skill::SkillError::not_found(skill_name, skill_map.keys().map(String::as_str)).into(),
+        );
     }
 
     // Extract all cross-references
     let known_skills: HashSet<String> = skills.iter().map(|s| s.name.clone()).collect();
+    let options = skill::ExtractOptions::from_config(&config.crossref)?;
     let mut crossrefs: HashMap<String, Vec<skill::CrossRef>> = HashMap::new();
     for skill in &skills {
-        let skill_md = skill.path.join("SKILL.md");
-        let content = fs::read_to_string(&skill_md)?;
-        let refs =
-            skill::extract_references_with_filter(&content, &skill.name, Some(&known_skills));
+        let refs = skill::references_for_skill(skill, Some(&known_skills), &options)?;
         if !refs.is_empty() {
             crossrefs.insert(skill.name.clone(), refs);
         }
@@ -317,6 +442,118 @@ fn list_tags(config: &Config) -> Result<()> {
     Ok(())
 }
 
+fn list_by_status(config: &Config) -> Result<()> {
+    let skills = skill::discover_all(&config.sources.skills)?;
+    let by_status = group_by_status(&skills);
+
+    println!("{}", "--- Skills by status ---".cyan().bold());
+
+    for status in [
+        SkillStatus::Stable,
+        SkillStatus::Draft,
+        SkillStatus::Deprecated,
+    ] {
+        let Some(group) = by_status.get(&status) else {
+            continue;
+        };
+
+        println!(
+            "\n{} {}",
+            status.label().yellow().bold(),
+            format!("({})", group.len()).dimmed()
+        );
+        for s in group.iter() {
+            match &s.frontmatter.replaced_by {
+                Some(replacement) => println!("  • {} → {}", s.name, replacement.green()),
+                None => println!("  • {}", s.name),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Skills keyed by lifecycle status, each group sorted by name
+fn group_by_status(skills: &[skill::Skill]) -> BTreeMap<SkillStatus, Vec<&skill::Skill>> {
+    let mut by_status: BTreeMap<SkillStatus, Vec<&skill::Skill>> = BTreeMap::new();
+    for s in skills {
+        by_status.entry(s.frontmatter.status()).or_default().push(s);
+    }
+    for group in by_status.values_mut() {
+        group.sort_by(|a, b| a.name.cmp(&b.name));
+    }
+    by_status
+}
+
+/// A skill whose source version differs from the one installed in a target
+#[derive(Debug, PartialEq, Eq)]
+struct OutdatedSkill {
+    name: String,
+    installed: Option<String>,
+    source: Option<String>,
+}
+
+fn list_outdated(config: &Config) -> Result<()> {
+    let skills = skill::discover_all(&config.sources.skills)?;
+    let install_plan = plan::build(config, &skills, &[])?;
+    let skill_map = skill::build_skill_map(skills);
+
+    println!("{}", "--- Outdated skills ---".cyan().bold());
+
+    let mut total = 0;
+    for target in &install_plan.targets {
+        let outdated = outdated_in_target(target, &skill_map)?;
+        if outdated.is_empty() {
+            continue;
+        }
+
+        println!("\n{}", target.target.display().to_string().yellow().bold());
+        for entry in &outdated {
+            println!(
+                "  • {} {} → {}",
+                entry.name,
+                entry.installed.as_deref().unwrap_or("unversioned").dimmed(),
+                entry.source.as_deref().unwrap_or("unversioned").green()
+            );
+        }
+        total += outdated.len();
+    }
+
+    if total == 0 {
+        println!("{}", "All installed skills are up to date".dimmed());
+    } else {
+        println!("\nRun `loadout install` to record the new versions");
+    }
+
+    Ok(())
+}
+
+/// Compare the versions recorded at install time against the sources,
+/// preferring the project's own copy of a skill as `install` does
+fn outdated_in_target(
+    target: &plan::TargetPlan,
+    skill_map: &HashMap<String, skill::Skill>,
+) -> Result<Vec<OutdatedSkill>> {
+    let state = linker::state::read(&target.target)?;
+
+    Ok(state
+        .skills
+        .into_iter()
+        .filter_map(|(name, installed)| {
+            let skill = target
+                .local_skills
+                .get(&name)
+                .or_else(|| skill_map.get(&name))?;
+            let source = skill.frontmatter.version.clone();
+            (source != installed.version).then_some(OutdatedSkill {
+                name,
+                installed: installed.version,
+                source,
+            })
+        })
+        .collect())
+}
+
 fn list_by_tag(config: &Config, tag: &str) -> Result<()> {
     let skills = skill::discover_all(&config.sources.skills)?;
 
@@ -439,8 +676,8 @@ fn list_pipeline(config: &Config, pipeline_name: &str) -> Result<()> {
         );
     }
 
-    // Sort by order
-    stages.sort_by_key(|(_, stage)| stage.order);
+    // Sort by order, then name to match the derived order's tie-break
+    stages.sort_by(|(a, x), (b, y)| x.order.cmp(&y.order).then_with(|| a.cmp(b)));
 
     println!(
         "{} {}",
@@ -484,6 +721,23 @@ fn list_pipeline(config: &Config, pipeline_name: &str) -> Result<()> {
         );
     }
 
+    // Execution order implied by after/before, next to the declared one
+    let analysis =
+        skill::pipeline::analyze(stages.iter().map(|(name, stage)| (name.as_str(), stage)));
+    let declared: Vec<&str> = stages.iter().map(|(name, _)| name.as_str()).collect();
+    let derived = analysis.execution_order.join(" → ");
+
+    println!();
+    println!("  {} {}", "Declared:".dimmed(), declared.join(" → "));
+    if analysis.execution_order == declared {
+        println!("  {} {}", "Derived: ".dimmed(), derived);
+    } else {
+        println!("  {} {}", "Derived: ".dimmed(), derived.yellow());
+    }
+    for cycle in &analysis.cycles {
+        println!("  {} {}", "Cycle:   ".red(), cycle.join(" → ").red());
+    }
+
     Ok(())
 }
 
@@ -491,15 +745,12 @@ fn list_missing(config: &Config) -> Result<()> {
     let skills = skill::discover_all(&config.sources.skills)?;
     let skill_map = skill::build_skill_map(skills.clone());
     let known_skills: HashSet<String> = skills.iter().map(|s| s.name.clone()).collect();
+    let options = skill::ExtractOptions::from_config(&config.crossref)?;
 
     // Extract all cross-references
     let mut all_referenced: HashSet<String> = HashSet::new();
     for skill in &skills {
-        let skill_md = skill.path.join("SKILL.md");
-        let content = fs::read_to_string(&skill_md)
-            .context(format!("Failed to read {}", skill_md.display()))?;
-        let refs =
-            skill::extract_references_with_filter(&content, &skill.name, Some(&known_skills));
+        let refs = skill::references_for_skill(skill, Some(&known_skills), &options)?;
         for r in refs {
             all_referenced.insert(r.target);
         }
@@ -570,14 +821,21 @@ mod tests {
         let config = Config {
             sources: Sources {
                 skills: vec![temp.path().join("skills")],
+                templates: vec![],
             },
             global: Global {
                 targets: vec![],
                 skills: vec!["test-skill".to_string()],
+                exclude: vec![],
+                allow_drafts: false,
             },
             target_aliases: default_target_aliases(),
             projects: HashMap::new(),
             check: Default::default(),
+            crossref: Default::default(),
+            budget: Default::default(),
+            profile: None,
+            profiles: Default::default(),
         };
 
         // When
@@ -587,6 +845,68 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn should_select_active_skills_for_enclosing_project() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        create_test_skills(&temp);
+        let project = temp.path().join("app");
+        fs::create_dir_all(project.join("src")).unwrap();
+        let outside = temp.path().join("elsewhere");
+        fs::create_dir_all(&outside).unwrap();
+
+        let config = Config {
+            sources: Sources {
+                skills: vec![temp.path().join("skills")],
+                templates: vec![],
+            },
+            global: Global {
+                targets: vec![],
+                skills: vec!["test-skill".to_string()],
+                exclude: vec![],
+                allow_drafts: false,
+            },
+            target_aliases: default_target_aliases(),
+            projects: HashMap::from([(
+                project.clone(),
+                crate::config::Project {
+                    skills: vec!["another-skill".to_string()],
+                    exclude: vec![],
+                    sources: vec![],
+                    inherit: true,
+                    targets: None,
+                },
+            )]),
+            check: Default::default(),
+            crossref: Default::default(),
+            budget: Default::default(),
+            profile: None,
+            profiles: Default::default(),
+        };
+
+        // When
+        let inside = active_skills(&config, &project.join("src")).unwrap();
+        let global = active_skills(&config, &outside).unwrap();
+
+        // Then
+        assert_eq!(inside.root.as_ref(), Some(&project));
+        assert!(inside.configured);
+        assert_eq!(
+            inside
+                .selection
+                .skills
+                .iter()
+                .map(|s| (s.name.as_str(), s.inherited))
+                .collect::<Vec<_>>(),
+            vec![("another-skill", false), ("test-skill", true)]
+        );
+        assert_eq!(global.root, None);
+        assert_eq!(
+            global.selection.names().collect::<Vec<_>>(),
+            vec!["test-skill"]
+        );
+    }
+
     #[test]
     fn should_list_refs_for_skill() {
         // Given
@@ -596,14 +916,21 @@ mod tests {
         let config = Config {
             sources: Sources {
                 skills: vec![temp.path().join("skills")],
+                templates: vec![],
             },
             global: Global {
                 targets: vec![],
                 skills: vec![],
+                exclude: vec![],
+                allow_drafts: false,
             },
             target_aliases: default_target_aliases(),
             projects: HashMap::new(),
             check: Default::default(),
+            crossref: Default::default(),
+            budget: Default::default(),
+            profile: None,
+            profiles: Default::default(),
         };
 
         // When
@@ -622,14 +949,21 @@ mod tests {
         let config = Config {
             sources: Sources {
                 skills: vec![temp.path().join("skills")],
+                templates: vec![],
             },
             global: Global {
                 targets: vec![],
                 skills: vec![],
+                exclude: vec![],
+                allow_drafts: false,
             },
             target_aliases: default_target_aliases(),
             projects: HashMap::new(),
             check: Default::default(),
+            crossref: Default::default(),
+            budget: Default::default(),
+            profile: None,
+            profiles: Default::default(),
         };
 
         // When
@@ -648,14 +982,21 @@ mod tests {
         let config = Config {
             sources: Sources {
                 skills: vec![temp.path().join("skills")],
+                templates: vec![],
             },
             global: Global {
                 targets: vec![],
                 skills: vec![],
+                exclude: vec![],
+                allow_drafts: false,
             },
             target_aliases: default_target_aliases(),
             projects: HashMap::new(),
             check: Default::default(),
+            crossref: Default::default(),
+            budget: Default::default(),
+            profile: None,
+            profiles: Default::default(),
         };
 
         // When
@@ -665,6 +1006,107 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn should_group_skills_by_status() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        create_test_skills(&temp);
+        let skills_dir = temp.path().join("skills");
+        for (name, extra) in [
+            ("new-idea", "status: draft\n"),
+            ("old-voice", "status: deprecated\nreplaced-by: test-skill\n"),
+        ] {
+            fs::create_dir_all(skills_dir.join(name)).unwrap();
+            fs::write(
+                skills_dir.join(name).join("SKILL.md"),
+                format!("---\nname: {name}\ndescription: Test skill\n{extra}---\n"),
+            )
+            .unwrap();
+        }
+        let skills = skill::discover_all(&[skills_dir]).unwrap();
+
+        // When
+        let by_status = group_by_status(&skills);
+
+        // Then
+        let names =
+            |status| -> Vec<&str> { by_status[&status].iter().map(|s| s.name.as_str()).collect() };
+        assert_eq!(
+            names(SkillStatus::Stable),
+            vec!["another-skill", "test-skill"]
+        );
+        assert_eq!(names(SkillStatus::Draft), vec!["new-idea"]);
+        assert_eq!(names(SkillStatus::Deprecated), vec!["old-voice"]);
+        assert_eq!(
+            by_status[&SkillStatus::Deprecated][0]
+                .frontmatter
+                .replaced_by
+                .as_deref(),
+            Some("test-skill")
+        );
+    }
+
+    #[test]
+    fn should_find_skills_whose_source_version_differs_from_installed() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        create_test_skills(&temp);
+        let target = temp.path().join("target");
+        fs::create_dir_all(&target).unwrap();
+
+        let skills = skill::discover_all(&[temp.path().join("skills")]).unwrap();
+        let mut skill_map = skill::build_skill_map(skills);
+        let mut names: Vec<String> = skill_map.keys().cloned().collect();
+        names.sort();
+
+        let mut state = linker::state::TargetState::default();
+        for name in &names {
+            state.skills.insert(
+                name.clone(),
+                linker::state::InstalledSkill {
+                    version: Some("1.0.0".to_string()),
+                    hash: String::new(),
+                },
+            );
+        }
+        linker::state::write(&target, &state).unwrap();
+
+        for (i, name) in names.iter().enumerate() {
+            let version = if i == 0 { "1.1.0" } else { "1.0.0" };
+            skill_map.get_mut(name).unwrap().frontmatter.version = Some(version.to_string());
+        }
+
+        // A project source shadows the second skill with a newer copy
+        let mut shadowing = skill_map[&names[1]].clone();
+        shadowing.frontmatter.version = Some("2.0.0".to_string());
+        let target_plan = plan::TargetPlan {
+            target,
+            skills: names.clone(),
+            via: BTreeMap::new(),
+            local_skills: BTreeMap::from([(names[1].clone(), shadowing)]),
+        };
+
+        // When
+        let outdated = outdated_in_target(&target_plan, &skill_map).unwrap();
+
+        // Then
+        assert_eq!(
+            outdated,
+            vec![
+                OutdatedSkill {
+                    name: names[0].clone(),
+                    installed: Some("1.0.0".to_string()),
+                    source: Some("1.1.0".to_string()),
+                },
+                OutdatedSkill {
+                    name: names[1].clone(),
+                    installed: Some("1.0.0".to_string()),
+                    source: Some("2.0.0".to_string()),
+                },
+            ]
+        );
+    }
+
     #[test]
     fn should_list_skills_by_tag() {
         // Given
@@ -674,14 +1116,21 @@ mod tests {
         let config = Config {
             sources: Sources {
                 skills: vec![temp.path().join("skills")],
+                templates: vec![],
             },
             global: Global {
                 targets: vec![],
                 skills: vec![],
+                exclude: vec![],
+                allow_drafts: false,
             },
             target_aliases: default_target_aliases(),
             projects: HashMap::new(),
             check: Default::default(),
+            crossref: Default::default(),
+            budget: Default::default(),
+            profile: None,
+            profiles: Default::default(),
         };
 
         // When
@@ -700,14 +1149,21 @@ mod tests {
         let config = Config {
             sources: Sources {
                 skills: vec![temp.path().join("skills")],
+                templates: vec![],
             },
             global: Global {
                 targets: vec![],
                 skills: vec![],
+                exclude: vec![],
+                allow_drafts: false,
             },
             target_aliases: default_target_aliases(),
             projects: HashMap::new(),
             check: Default::default(),
+            crossref: Default::default(),
+            budget: Default::default(),
+            profile: None,
+            profiles: Default::default(),
         };
 
         // When
@@ -726,14 +1182,21 @@ mod tests {
         let config = Config {
             sources: Sources {
                 skills: vec![temp.path().join("skills")],
+                templates: vec![],
             },
             global: Global {
                 targets: vec![],
                 skills: vec![],
+                exclude: vec![],
+                allow_drafts: false,
             },
             target_aliases: default_target_aliases(),
             projects: HashMap::new(),
             check: Default::default(),
+            crossref: Default::default(),
+            budget: Default::default(),
+            profile: None,
+            profiles: Default::default(),
         };
 
         // When
@@ -752,14 +1215,21 @@ mod tests {
         let config = Config {
             sources: Sources {
                 skills: vec![temp.path().join("skills")],
+                templates: vec![],
             },
             global: Global {
                 targets: vec![],
                 skills: vec![],
+                exclude: vec![],
+                allow_drafts: false,
             },
             target_aliases: default_target_aliases(),
             projects: HashMap::new(),
             check: Default::default(),
+            crossref: Default::default(),
+            budget: Default::default(),
+            profile: None,
+            profiles: Default::default(),
         };
 
         // When
@@ -786,14 +1256,21 @@ mod tests {
         let config = Config {
             sources: Sources {
                 skills: vec![temp.path().join("skills")],
+                templates: vec![],
             },
             global: Global {
                 targets: vec![],
                 skills: vec![],
+                exclude: vec![],
+                allow_drafts: false,
             },
             target_aliases: default_target_aliases(),
             projects: HashMap::new(),
             check: Default::default(),
+            crossref: Default::default(),
+            budget: Default::default(),
+            profile: None,
+            profiles: Default::default(),
         };
 
         // When
@@ -1,19 +1,32 @@
 //! CLI command implementations
 
+pub mod budget;
 pub mod check;
 pub mod clean;
+pub mod config;
 #[cfg(feature = "graph")]
 pub mod graph;
 pub mod install;
 pub mod list;
 pub mod new;
+pub mod pipeline;
+pub mod profile;
+pub mod rename;
+pub mod route;
+pub mod trust;
 pub mod validate;
 
+pub use budget::budget;
 pub use check::{check, exit_code as check_exit_code, print_findings as print_check_findings};
 pub use clean::clean;
+pub use config::{print_schema, validate_config};
 #[cfg(feature = "graph")]
 pub use graph::graph;
 pub use install::install;
 pub use list::{list, ListMode};
 pub use new::new;
+pub use profile::{list_profiles, use_profile};
+pub use rename::rename;
+pub use route::{route, route_suite};
+pub use trust::trust;
 pub use validate::validate;
@@ -1,17 +1,32 @@
 //! New command implementation
+//!
+//! A template is a directory tree copied into the new skill. `{name}`,
+//! `{description}`, `{tags}`, and `{date}` are substituted in file names
+//! and in the contents of text files.
 
+use std::env;
 use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use anyhow::{Context, Result};
 use colored::Colorize;
+use regex::Regex;
+use walkdir::WalkDir;
 
-use crate::config::Config;
+use crate::commands::install::install;
+use crate::commands::trust::warn_untrusted;
+use crate::config::{self, Config};
+use crate::skill::{self, Frontmatter, Skill};
+
+/// Template used when `--template` is omitted
+pub const DEFAULT_TEMPLATE: &str = "default";
 
 const TEMPLATE_CONTENT: &str = r#"---
 name: {name}
 description: >-
   {description}
-# tags: []
+tags: {tags}
 ---
 
 # {name}
@@ -48,19 +63,66 @@ Write your instructions for the AI agent. You can use:
 ```
 "#;
 
+/// Where a template's files come from
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Template {
+    /// The SKILL.md compiled into loadout
+    Builtin,
+    /// A directory tree to render
+    Directory(PathBuf),
+}
+
+/// Values substituted for placeholders in a template
+struct Placeholders {
+    name: String,
+    description: String,
+    tags: Vec<String>,
+    date: String,
+}
+
+impl Placeholders {
+    fn render(&self, text: &str) -> String {
+        text.replace("{name}", &self.name)
+            .replace("{description}", &self.description)
+            .replace("{tags}", &format!("[{}]", self.tags.join(", ")))
+            .replace("{date}", &self.date)
+    }
+}
+
+/// Options for `loadout new` beyond the skill name
+#[derive(Debug, Default)]
+pub struct NewOptions {
+    pub description: Option<String>,
+    /// Template name (default: [`DEFAULT_TEMPLATE`])
+    pub template: Option<String>,
+    /// Skill source to create in: a 1-based index or a configured path
+    /// (default: the first source)
+    pub source: Option<String>,
+    pub tags: Vec<String>,
+    pub pipeline: Option<PipelinePlacement>,
+    /// Add the skill to `loadout.toml` and install it
+    pub enable: bool,
+    /// Enable in this project rather than global scope
+    pub project: Option<PathBuf>,
+}
+
+/// Where a new skill sits in a pipeline
+#[derive(Debug, Clone)]
+pub struct PipelinePlacement {
+    pub name: String,
+    pub stage: String,
+    pub order: u32,
+    /// Skills that run before the new one; each gets a reciprocal `before`
+    pub after: Vec<String>,
+}
+
 /// Create a new skill from template
-pub fn new(config: &Config, name: String, description: Option<String>) -> Result<()> {
+pub fn new(config: &Config, config_path: &Path, name: &str, options: NewOptions) -> Result<()> {
     // Validate skill name format
-    validate_skill_name(&name)?;
-
-    // Use first source directory as target
-    let source_dir = config
-        .sources
-        .skills
-        .first()
-        .context("No source directories configured")?;
+    validate_skill_name(name)?;
 
-    let skill_dir = source_dir.join(&name);
+    let source_dir = resolve_source(config, options.source.as_deref())?;
+    let skill_dir = source_dir.join(name);
 
     // Check if skill already exists
     if skill_dir.exists() {
@@ -70,39 +132,568 @@ pub fn new(config: &Config, name: String, description: Option<String>) -> Result
         ));
     }
 
+    let template = find_template(config, options.template.as_deref())?;
+
+    // Work out neighbour edits up front so a bad --after writes nothing
+    let neighbour_edits = match &options.pipeline {
+        Some(placement) => plan_neighbour_edits(config, name, placement)?,
+        None => Vec::new(),
+    };
+
     // Create skill directory
     fs::create_dir_all(&skill_dir).context(format!(
         "Failed to create skill directory: {}",
         skill_dir.display()
     ))?;
 
-    // Generate SKILL.md content
-    let desc = description.unwrap_or_else(|| format!("Description for {}", name));
-    let content = TEMPLATE_CONTENT
-        .replace("{name}", &name)
-        .replace("{description}", &desc);
+    let placeholders = Placeholders {
+        description: options
+            .description
+            .clone()
+            .unwrap_or_else(|| format!("Description for {}", name)),
+        name: name.to_string(),
+        tags: options.tags.clone(),
+        date: today(),
+    };
 
-    // Write SKILL.md file
-    let skill_file = skill_dir.join("SKILL.md");
-    fs::write(&skill_file, content).context(format!(
-        "Failed to write SKILL.md: {}",
-        skill_file.display()
-    ))?;
+    let result = scaffold(
+        &template,
+        &skill_dir,
+        &placeholders,
+        options.pipeline.as_ref(),
+    );
+    let skill = match result {
+        Ok(skill) => skill,
+        Err(e) => {
+            // Don't leave a half-rendered skill behind
+            let _ = fs::remove_dir_all(&skill_dir);
+            return Err(e);
+        }
+    };
+
+    for (path, content) in &neighbour_edits {
+        write_file(path, content.as_bytes())?;
+    }
 
     println!("{} {}", "Created skill:".green().bold(), name);
     println!("  Path: {}", skill_dir.display());
-    println!("  File: {}", skill_file.display());
+    println!("  File: {}", skill.skill_file.display());
+    if let Template::Directory(template_dir) = &template {
+        println!("  Template: {}", template_dir.display());
+    }
+    if !options.tags.is_empty() && skill.frontmatter.tags.is_none() {
+        println!(
+            "  {} template has no {{tags}} placeholder; tags were not written",
+            "Warning:".yellow().bold()
+        );
+    }
+    if let Some(placement) = &options.pipeline {
+        for neighbour in &placement.after {
+            println!(
+                "  Updated: {} (pipeline '{}' before: {})",
+                neighbour, placement.name, name
+            );
+        }
+    }
+
+    if options.enable {
+        let scope = enable(config_path, name, options.project.as_deref())?;
+        println!("  Enabled in {}", scope);
+        println!();
+
+        let loaded = config::load_from(config_path)?;
+        warn_untrusted(&loaded.untrusted);
+        install(&loaded.config, false)?;
+
+        println!();
+        println!(
+            "Next: edit {}",
+            skill.skill_file.display().to_string().cyan()
+        );
+        return Ok(());
+    }
+
     println!();
     println!("Next steps:");
-    println!("  1. Edit {}", skill_file.display().to_string().cyan());
+    println!(
+        "  1. Edit {}",
+        skill.skill_file.display().to_string().cyan()
+    );
     println!("  2. Add '{}' to loadout.toml [global] skills", name.cyan());
     println!("  3. Run {} to link it", "loadout install".cyan());
 
     Ok(())
 }
 
+/// Render the template, add pipeline placement, and check the result loads
+fn scaffold(
+    template: &Template,
+    skill_dir: &Path,
+    placeholders: &Placeholders,
+    pipeline: Option<&PipelinePlacement>,
+) -> Result<Skill> {
+    let skill_file = skill_dir.join("SKILL.md");
+    match template {
+        Template::Builtin => write_file(
+            &skill_file,
+            placeholders.render(TEMPLATE_CONTENT).as_bytes(),
+        )?,
+        Template::Directory(template_dir) => {
+            render_directory(template_dir, skill_dir, placeholders)?
+        }
+    }
+
+    if let Some(placement) = pipeline {
+        let content = fs::read_to_string(&skill_file)
+            .context(format!("Failed to read {}", skill_file.display()))?;
+        let content =
+            append_to_frontmatter(&content, &pipeline_block(placement)).context(format!(
+                "Template SKILL.md has no frontmatter: {}",
+                skill_file.display()
+            ))?;
+        write_file(&skill_file, content.as_bytes())?;
+    }
+
+    Skill::from_directory(skill_dir).context("Template rendered an invalid SKILL.md")
+}
+
+/// Pick the skill source to create in
+///
+/// Accepts a 1-based index into `[sources] skills` or one of its paths.
+fn resolve_source<'a>(config: &'a Config, source: Option<&str>) -> Result<&'a PathBuf> {
+    let sources = &config.sources.skills;
+    let Some(source) = source else {
+        return sources.first().context("No source directories configured");
+    };
+
+    let found = match source.parse::<usize>() {
+        Ok(index) => index.checked_sub(1).and_then(|i| sources.get(i)),
+        Err(_) => {
+            let path = env::current_dir()
+                .context("Failed to resolve current working directory")?
+                .join(source);
+            let canonical = fs::canonicalize(&path).ok();
+            sources.iter().find(|s| {
+                **s == path || (canonical.is_some() && fs::canonicalize(s).ok() == canonical)
+            })
+        }
+    };
+
+    found.with_context(|| {
+        let listed: Vec<String> = sources
+            .iter()
+            .enumerate()
+            .map(|(i, s)| format!("  {}. {}", i + 1, s.display()))
+            .collect();
+        format!(
+            "Source '{}' is not configured. Configured sources:\n{}",
+            source,
+            listed.join("\n")
+        )
+    })
+}
+
+/// Edits adding `before: [name]` to each `--after` skill's pipeline entry
+fn plan_neighbour_edits(
+    config: &Config,
+    name: &str,
+    placement: &PipelinePlacement,
+) -> Result<Vec<(PathBuf, String)>> {
+    let mut edits = Vec::new();
+    for neighbour in &placement.after {
+        let skill = skill::resolve(&config.sources.skills, neighbour)?;
+        let stage = skill
+            .frontmatter
+            .pipeline
+            .as_ref()
+            .and_then(|p| p.get(&placement.name))
+            .with_context(|| {
+                format!(
+                    "Skill '{}' is not in pipeline '{}'; add it there before placing '{}' after it",
+                    neighbour, placement.name, name
+                )
+            })?;
+        if stage.order >= placement.order {
+            anyhow::bail!(
+                "--order {} must be greater than '{}' (order {}) in pipeline '{}'",
+                placement.order,
+                neighbour,
+                stage.order,
+                placement.name
+            );
+        }
+        if stage.before_skills().any(|b| b == name) {
+            continue;
+        }
+
+        let content = fs::read_to_string(&skill.skill_file)
+            .context(format!("Failed to read {}", skill.skill_file.display()))?;
+        let updated = add_pipeline_before(&content, &placement.name, name).with_context(|| {
+            format!(
+                "Couldn't add before: [{}] to pipeline '{}' in {}; add it by hand",
+                name,
+                placement.name,
+                skill.skill_file.display()
+            )
+        })?;
+
+        // Make sure the edit parses back the way we meant
+        let parsed = Frontmatter::parse(&updated)?;
+        let reciprocal = parsed
+            .pipeline
+            .as_ref()
+            .and_then(|p| p.get(&placement.name))
+            .is_some_and(|stage| stage.before_skills().any(|b| b == name));
+        if !reciprocal {
+            anyhow::bail!(
+                "Couldn't add before: [{}] to pipeline '{}' in {}; add it by hand",
+                name,
+                placement.name,
+                skill.skill_file.display()
+            );
+        }
+
+        edits.push((skill.skill_file, updated));
+    }
+    Ok(edits)
+}
+
+/// YAML lines placing a skill in a pipeline
+fn pipeline_block(placement: &PipelinePlacement) -> String {
+    let mut block = format!(
+        "pipeline:\n  {}:\n    stage: {}\n    order: {}\n",
+        placement.name, placement.stage, placement.order
+    );
+    if !placement.after.is_empty() {
+        block.push_str(&format!("    after: [{}]\n", placement.after.join(", ")));
+    }
+    block
+}
+
+/// Insert lines just before the closing `---` of the frontmatter
+fn append_to_frontmatter(content: &str, lines: &str) -> Option<String> {
+    let mut offset = 0;
+    let mut delimiters = 0;
+    for raw in content.split_inclusive('\n') {
+        if raw.trim() == "---" {
+            delimiters += 1;
+            if delimiters == 2 {
+                return Some(format!(
+                    "{}{}{}",
+                    &content[..offset],
+                    lines,
+                    &content[offset..]
+                ));
+            }
+        }
+        offset += raw.len();
+    }
+    None
+}
+
+/// Add `skill` to the `before` list of a pipeline entry in the frontmatter
+///
+/// Works on the raw text so comments and formatting survive. Handles inline
+/// (`before: [a]`) and block (`- a`) lists, or adds a `before` line. Returns
+/// `None` if the pipeline entry isn't written in block style.
+fn add_pipeline_before(content: &str, pipeline: &str, skill: &str) -> Option<String> {
+    let indent = |line: &str| line.len() - line.trim_start().len();
+    let entry_re = Regex::new(&format!(
+        r#"^\s+["']?{}["']?:\s*(#.*)?$"#,
+        regex::escape(pipeline)
+    ))
+    .unwrap();
+    let inline_re = Regex::new(r"^(\s*before:\s*\[)([^\]]*)(\].*)$").unwrap();
+
+    let lines: Vec<&str> = content.lines().collect();
+    let end = lines.iter().skip(1).position(|l| l.trim() == "---")? + 1;
+
+    let pipeline_line = (1..end).find(|&i| lines[i].starts_with("pipeline:"))?;
+    let entry_line = (pipeline_line + 1..end)
+        .take_while(|&i| lines[i].trim().is_empty() || indent(lines[i]) > 0)
+        .find(|&i| entry_re.is_match(lines[i]))?;
+    let entry_indent = indent(lines[entry_line]);
+
+    let children: Vec<usize> = (entry_line + 1..end)
+        .take_while(|&i| lines[i].trim().is_empty() || indent(lines[i]) > entry_indent)
+        .filter(|&i| !lines[i].trim().is_empty())
+        .collect();
+    let child_indent = children.first().map(|&i| indent(lines[i]))?;
+
+    let mut output: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+    let before_line = children.iter().copied().find(|&i| {
+        indent(lines[i]) == child_indent && lines[i].trim_start().starts_with("before:")
+    });
+
+    match before_line {
+        Some(i) if inline_re.is_match(lines[i]) => {
+            let cap = inline_re.captures(lines[i])?;
+            let items = cap[2].trim();
+            let items = if items.is_empty() {
+                skill.to_string()
+            } else {
+                format!("{}, {}", items, skill)
+            };
+            output[i] = format!("{}{}{}", &cap[1], items, &cap[3]);
+        }
+        Some(i) => {
+            // Block list: items follow at a deeper or equal indent with "- "
+            let items: Vec<usize> = children
+                .iter()
+                .copied()
+                .skip_while(|&j| j <= i)
+                .take_while(|&j| lines[j].trim_start().starts_with('-'))
+                .collect();
+            let last = *items.last()?;
+            let item_prefix = &lines[last][..indent(lines[last])];
+            output.insert(last + 1, format!("{}- {}", item_prefix, skill));
+        }
+        None => {
+            let last = *children.last()?;
+            output.insert(
+                last + 1,
+                format!("{}before: [{}]", " ".repeat(child_indent), skill),
+            );
+        }
+    }
+
+    let mut updated = output.join("\n");
+    if content.ends_with('\n') {
+        updated.push('\n');
+    }
+    Some(updated)
+}
+
+/// Add a skill to `[global] skills` or a project's `skills` in `loadout.toml`
+///
+/// Uses `toml_edit` so comments and layout are preserved. Returns a label
+/// for the scope it was enabled in.
+fn enable(config_path: &Path, name: &str, project: Option<&Path>) -> Result<String> {
+    let contents = fs::read_to_string(config_path)
+        .context(format!("Failed to read {}", config_path.display()))?;
+    let config_dir = config_path.parent().unwrap_or(Path::new(""));
+    let (updated, scope) = enable_in_config(&contents, config_dir, name, project)
+        .context(format!("Failed to update {}", config_path.display()))?;
+    write_file(config_path, updated.as_bytes())?;
+    Ok(scope)
+}
+
+fn enable_in_config(
+    contents: &str,
+    config_dir: &Path,
+    name: &str,
+    project: Option<&Path>,
+) -> Result<(String, String)> {
+    let mut doc: toml_edit::DocumentMut = contents.parse()?;
+
+    let Some(project) = project else {
+        // An active profile that sets `skills` replaces `[global] skills`
+        let profile = doc
+            .get("profile")
+            .and_then(|p| p.as_str())
+            .map(str::to_string);
+        if let Some(profile) = profile {
+            let skills = doc
+                .get_mut("profiles")
+                .and_then(|p| p.get_mut(profile.as_str()))
+                .and_then(|p| p.get_mut("skills"))
+                .and_then(|s| s.as_array_mut());
+            if let Some(skills) = skills {
+                push_unique(skills, name);
+                return Ok((doc.to_string(), format!("profile '{}'", profile)));
+            }
+        }
+
+        let skills = doc
+            .get_mut("global")
+            .and_then(|g| g.get_mut("skills"))
+            .and_then(|s| s.as_array_mut())
+            .context("No [global] skills list")?;
+        push_unique(skills, name);
+        return Ok((doc.to_string(), "global scope".to_string()));
+    };
+
+    let absolute = env::current_dir()
+        .context("Failed to resolve current working directory")?
+        .join(project);
+    let canonical = fs::canonicalize(&absolute).ok();
+
+    let projects = doc
+        .entry("projects")
+        .or_insert_with(|| {
+            let mut table = toml_edit::Table::new();
+            table.set_implicit(true);
+            toml_edit::Item::Table(table)
+        })
+        .as_table_like_mut()
+        .context("[projects] is not a table")?;
+
+    let existing = projects.iter().map(|(key, _)| key.to_string()).find(|key| {
+        let Ok(expanded) = config::expand_config_path(Path::new(key), config_dir, key) else {
+            return false;
+        };
+        expanded == absolute
+            || (canonical.is_some() && fs::canonicalize(&expanded).ok() == canonical)
+    });
+    let key = existing.unwrap_or_else(|| absolute.display().to_string());
+
+    if projects.get(&key).is_none() {
+        projects.insert(&key, toml_edit::Item::Table(toml_edit::Table::new()));
+    }
+    let table = projects
+        .get_mut(&key)
+        .and_then(|p| p.as_table_like_mut())
+        .context(format!("[projects.\"{}\"] is not a table", key))?;
+    if table.get("skills").is_none() {
+        table.insert("skills", toml_edit::value(toml_edit::Array::new()));
+    }
+    let skills = table
+        .get_mut("skills")
+        .and_then(|s| s.as_array_mut())
+        .context(format!("[projects.\"{}\"] skills is not a list", key))?;
+    push_unique(skills, name);
+
+    Ok((doc.to_string(), format!("project {}", key)))
+}
+
+fn push_unique(array: &mut toml_edit::Array, name: &str) {
+    if !array.iter().any(|v| v.as_str() == Some(name)) {
+        array.push(name);
+    }
+}
+
+/// Resolve a template name to its files
+///
+/// Named templates are directories inside `[sources] templates`. The default
+/// template is `default` from those directories if present, then `_template`
+/// in a skill source, then the built-in SKILL.md.
+fn find_template(config: &Config, name: Option<&str>) -> Result<Template> {
+    let name = name.unwrap_or(DEFAULT_TEMPLATE);
+
+    for dir in &config.sources.templates {
+        let candidate = dir.join(name);
+        if candidate.is_dir() {
+            return Ok(Template::Directory(candidate));
+        }
+    }
+
+    if name != DEFAULT_TEMPLATE {
+        return Err(anyhow::anyhow!(
+            "Template '{}' not found. Available templates: {}",
+            name,
+            available_templates(config).join(", ")
+        ));
+    }
+
+    Ok(config
+        .sources
+        .skills
+        .iter()
+        .map(|source| source.join(skill::SOURCE_TEMPLATE_DIR))
+        .find(|dir| dir.is_dir())
+        .map_or(Template::Builtin, Template::Directory))
+}
+
+/// Names of all templates, default first
+fn available_templates(config: &Config) -> Vec<String> {
+    let mut names: Vec<String> = config
+        .sources
+        .templates
+        .iter()
+        .filter_map(|dir| fs::read_dir(dir).ok())
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| entry.file_name().to_str().map(String::from))
+        .filter(|name| name != DEFAULT_TEMPLATE && !name.starts_with('.'))
+        .collect();
+    names.sort();
+    names.dedup();
+    names.insert(0, DEFAULT_TEMPLATE.to_string());
+    names
+}
+
+/// Copy a template tree into the skill directory, substituting placeholders
+fn render_directory(
+    template_dir: &Path,
+    skill_dir: &Path,
+    placeholders: &Placeholders,
+) -> Result<()> {
+    if !template_dir.join("SKILL.md").is_file() {
+        return Err(anyhow::anyhow!(
+            "Template has no SKILL.md: {}",
+            template_dir.display()
+        ));
+    }
+
+    for entry in WalkDir::new(template_dir).min_depth(1) {
+        let entry = entry.context(format!(
+            "Failed to read template: {}",
+            template_dir.display()
+        ))?;
+        let relative = entry
+            .path()
+            .strip_prefix(template_dir)
+            .expect("walkdir entries are inside the root");
+        let dest = skill_dir.join(placeholders.render(&relative.to_string_lossy()));
+
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(&dest)
+                .context(format!("Failed to create directory: {}", dest.display()))?;
+            continue;
+        }
+
+        let bytes = fs::read(entry.path()).context(format!(
+            "Failed to read template file: {}",
+            entry.path().display()
+        ))?;
+        // Binary files are copied as-is
+        let bytes = match String::from_utf8(bytes) {
+            Ok(text) => placeholders.render(&text).into_bytes(),
+            Err(e) => e.into_bytes(),
+        };
+        write_file(&dest, &bytes)?;
+
+        // Keep scripts executable
+        let permissions = entry.metadata()?.permissions();
+        fs::set_permissions(&dest, permissions)
+            .context(format!("Failed to set permissions: {}", dest.display()))?;
+    }
+
+    Ok(())
+}
+
+fn write_file(path: &Path, contents: &[u8]) -> Result<()> {
+    fs::write(path, contents).context(format!("Failed to write {}", path.display()))
+}
+
+/// Today's UTC date as YYYY-MM-DD
+fn today() -> String {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let (year, month, day) = civil_from_days((seconds / 86_400) as i64);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Convert days since 1970-01-01 to a proleptic Gregorian date
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
 /// Validate skill name follows the pattern: ^[a-z0-9]+(-[a-z0-9]+)*$
-fn validate_skill_name(name: &str) -> Result<()> {
+pub(crate) fn validate_skill_name(name: &str) -> Result<()> {
     let re = regex::Regex::new(r"^[a-z0-9]+(-[a-z0-9]+)*$").unwrap();
 
     if !re.is_match(name) {
@@ -133,14 +724,21 @@ mod tests {
         Config {
             sources: Sources {
                 skills: vec![temp.path().join("skills")],
+                templates: vec![],
             },
             global: Global {
                 targets: vec![],
                 skills: vec![],
+                exclude: vec![],
+                allow_drafts: false,
             },
             target_aliases: default_target_aliases(),
             projects: HashMap::new(),
             check: Default::default(),
+            crossref: Default::default(),
+            budget: Default::default(),
+            profile: None,
+            profiles: Default::default(),
         }
     }
 
@@ -153,8 +751,12 @@ mod tests {
         // When
         new(
             &config,
-            "my-skill".to_string(),
-            Some("Test skill".to_string()),
+            &temp.path().join("loadout.toml"),
+            "my-skill",
+            NewOptions {
+                description: Some("Test skill".to_string()),
+                ..Default::default()
+            },
         )
         .unwrap();
 
@@ -175,7 +777,13 @@ mod tests {
         let config = create_test_config(&temp);
 
         // When
-        new(&config, "my-skill".to_string(), None).unwrap();
+        new(
+            &config,
+            &temp.path().join("loadout.toml"),
+            "my-skill",
+            NewOptions::default(),
+        )
+        .unwrap();
 
         // Then
         let skill_file = temp.path().join("skills/my-skill/SKILL.md");
@@ -183,6 +791,264 @@ mod tests {
         assert!(content.contains("Description for my-skill"));
     }
 
+    #[test]
+    fn should_render_named_template_tree() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let mut config = create_test_config(&temp);
+        let template_dir = temp.path().join("templates/scripted");
+        fs::create_dir_all(template_dir.join("scripts")).unwrap();
+        fs::create_dir_all(template_dir.join("references")).unwrap();
+        fs::write(
+            template_dir.join("SKILL.md"),
+            "---\nname: {name}\ndescription: {description}\ntags: {tags}\n---\n\nCreated {date}\n",
+        )
+        .unwrap();
+        fs::write(template_dir.join("scripts/{name}.sh"), "echo {name}\n").unwrap();
+        fs::write(template_dir.join("references/.gitkeep"), "").unwrap();
+        config.sources.templates = vec![temp.path().join("templates")];
+
+        // When
+        new(
+            &config,
+            &temp.path().join("loadout.toml"),
+            "my-skill",
+            NewOptions {
+                description: Some("Test skill".to_string()),
+                template: Some("scripted".to_string()),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        // Then
+        let skill_dir = temp.path().join("skills/my-skill");
+        let content = fs::read_to_string(skill_dir.join("SKILL.md")).unwrap();
+        assert!(content.starts_with("---\nname: my-skill\ndescription: Test skill\ntags: []\n"));
+        assert!(!content.contains("{date}"));
+        let script = fs::read_to_string(skill_dir.join("scripts/my-skill.sh")).unwrap();
+        assert_eq!(script, "echo my-skill\n");
+        assert!(skill_dir.join("references/.gitkeep").exists());
+    }
+
+    #[test]
+    fn should_use_source_template_as_default_and_reject_unknown_names() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let config = create_test_config(&temp);
+        let source_template = temp.path().join("skills/_template");
+        fs::create_dir_all(&source_template).unwrap();
+        fs::write(
+            source_template.join("SKILL.md"),
+            "---\nname: {name}\ndescription: From the source template\n---\n",
+        )
+        .unwrap();
+
+        // When
+        new(
+            &config,
+            &temp.path().join("loadout.toml"),
+            "my-skill",
+            NewOptions::default(),
+        )
+        .unwrap();
+        let result = new(
+            &config,
+            &temp.path().join("loadout.toml"),
+            "other",
+            NewOptions {
+                template: Some("missing".to_string()),
+                ..Default::default()
+            },
+        );
+
+        // Then
+        let content = fs::read_to_string(temp.path().join("skills/my-skill/SKILL.md")).unwrap();
+        assert!(content.contains("From the source template"));
+        let error = result.unwrap_err().to_string();
+        assert!(error.contains("Template 'missing' not found"));
+        assert!(error.contains("Available templates: default"));
+        assert!(!temp.path().join("skills/other").exists());
+    }
+
+    #[test]
+    fn should_place_skill_in_pipeline_with_tags_and_reciprocal_before() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let mut config = create_test_config(&temp);
+        let second = temp.path().join("second");
+        config.sources.skills.push(second.clone());
+        let draft_dir = second.join("draft");
+        fs::create_dir_all(&draft_dir).unwrap();
+        fs::write(
+            draft_dir.join("SKILL.md"),
+            "---\nname: draft\ndescription: Draft a post\npipeline:\n  blog:\n    stage: draft\n    order: 1\n---\n\n# draft\n",
+        )
+        .unwrap();
+
+        // When
+        new(
+            &config,
+            &temp.path().join("loadout.toml"),
+            "edit",
+            NewOptions {
+                description: Some("Edit a drafted post. Use when a draft is done.".to_string()),
+                source: Some("2".to_string()),
+                tags: vec!["writing".to_string(), "blog".to_string()],
+                pipeline: Some(PipelinePlacement {
+                    name: "blog".to_string(),
+                    stage: "edit".to_string(),
+                    order: 2,
+                    after: vec!["draft".to_string()],
+                }),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        // Then
+        let skill = Skill::from_directory(&second.join("edit")).unwrap();
+        assert_eq!(
+            skill.frontmatter.tags,
+            Some(vec!["writing".to_string(), "blog".to_string()])
+        );
+        let stage = &skill.frontmatter.pipeline.unwrap()["blog"];
+        assert_eq!(stage.order, 2);
+        assert_eq!(stage.after_skills().collect::<Vec<_>>(), vec!["draft"]);
+        let draft = fs::read_to_string(draft_dir.join("SKILL.md")).unwrap();
+        assert!(draft.contains("    order: 1\n    before: [edit]\n---\n\n# draft\n"));
+    }
+
+    #[test]
+    fn should_reject_unknown_source_and_pipeline_neighbours() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let config = create_test_config(&temp);
+        let loose = temp.path().join("skills/loose");
+        fs::create_dir_all(&loose).unwrap();
+        fs::write(
+            loose.join("SKILL.md"),
+            "---\nname: loose\ndescription: Not in any pipeline\n---\n",
+        )
+        .unwrap();
+        let config_path = temp.path().join("loadout.toml");
+
+        // When
+        let bad_source = new(
+            &config,
+            &config_path,
+            "my-skill",
+            NewOptions {
+                source: Some("3".to_string()),
+                ..Default::default()
+            },
+        );
+        let bad_neighbour = new(
+            &config,
+            &config_path,
+            "my-skill",
+            NewOptions {
+                pipeline: Some(PipelinePlacement {
+                    name: "blog".to_string(),
+                    stage: "edit".to_string(),
+                    order: 2,
+                    after: vec!["loose".to_string()],
+                }),
+                ..Default::default()
+            },
+        );
+
+        // Then
+        assert!(bad_source
+            .unwrap_err()
+            .to_string()
+            .contains("Source '3' is not configured"));
+        assert!(bad_neighbour
+            .unwrap_err()
+            .to_string()
+            .contains("'loose' is not in pipeline 'blog'"));
+        assert!(!temp.path().join("skills/my-skill").exists());
+    }
+
+    #[test]
+    fn should_add_before_to_inline_block_and_missing_lists() {
+        // Given
+        let inline = "---\npipeline:\n  blog:\n    order: 1\n    before: [publish]\n---\n";
+        let block = "---\npipeline:\n  blog:\n    before:\n      - publish\n    order: 1\n  other:\n    order: 1\n---\n";
+        let flow = "---\npipeline: {blog: {order: 1}}\n---\n";
+
+        // When/Then
+        assert_eq!(
+            add_pipeline_before(inline, "blog", "edit").unwrap(),
+            "---\npipeline:\n  blog:\n    order: 1\n    before: [publish, edit]\n---\n"
+        );
+        assert_eq!(
+            add_pipeline_before(block, "blog", "edit").unwrap(),
+            "---\npipeline:\n  blog:\n    before:\n      - publish\n      - edit\n    order: 1\n  other:\n    order: 1\n---\n"
+        );
+        assert_eq!(add_pipeline_before(flow, "blog", "edit"), None);
+        assert_eq!(add_pipeline_before(inline, "missing", "edit"), None);
+    }
+
+    #[test]
+    fn should_enable_skill_in_global_scope_or_project() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let contents =
+            "[global]\ntargets = []\nskills = [\"voice\"]  # kept\n\n[projects.app]\nskills = []\n";
+        let app = temp.path().join("app");
+
+        // When
+        let (global, global_scope) =
+            enable_in_config(contents, temp.path(), "my-skill", None).unwrap();
+        let (project, _) = enable_in_config(contents, temp.path(), "my-skill", Some(&app)).unwrap();
+        let (new_project, new_scope) = enable_in_config(
+            contents,
+            temp.path(),
+            "my-skill",
+            Some(&temp.path().join("other")),
+        )
+        .unwrap();
+
+        // Then
+        assert!(global.contains("skills = [\"voice\", \"my-skill\"]  # kept"));
+        assert_eq!(global_scope, "global scope");
+        assert!(project.contains("[projects.app]\nskills = [\"my-skill\"]"));
+        assert!(new_project.contains("skills = [\"my-skill\"]"));
+        assert!(new_scope.ends_with("other"));
+        let reparsed: toml::Value = toml::from_str(&new_project).unwrap();
+        assert_eq!(reparsed["projects"].as_table().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn should_enable_skill_in_active_profile_that_sets_skills() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let base = "[global]\ntargets = []\nskills = [\"voice\"]\n\n\
+                    [profiles.work]\nskills = [\"deploy\"]\n\n[profiles.home]\ntargets = []\n";
+        let work = format!("profile = \"work\"\n{base}");
+        let home = format!("profile = \"home\"\n{base}");
+
+        // When
+        let (in_work, work_scope) = enable_in_config(&work, temp.path(), "my-skill", None).unwrap();
+        let (in_home, home_scope) = enable_in_config(&home, temp.path(), "my-skill", None).unwrap();
+
+        // Then
+        assert!(in_work.contains("[profiles.work]\nskills = [\"deploy\", \"my-skill\"]"));
+        assert!(in_work.contains("[global]\ntargets = []\nskills = [\"voice\"]"));
+        assert_eq!(work_scope, "profile 'work'");
+        assert!(in_home.contains("skills = [\"voice\", \"my-skill\"]"));
+        assert_eq!(home_scope, "global scope");
+    }
+
+    #[test]
+    fn should_convert_days_to_calendar_dates() {
+        // Given/When/Then
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(11_016), (2000, 2, 29));
+        assert_eq!(civil_from_days(20_744), (2026, 10, 18));
+    }
+
     #[test]
     fn should_return_error_when_skill_exists() {
         // Given
@@ -192,7 +1058,12 @@ mod tests {
         fs::create_dir_all(temp.path().join("skills/my-skill")).unwrap();
 
         // When
-        let result = new(&config, "my-skill".to_string(), None);
+        let result = new(
+            &config,
+            &temp.path().join("loadout.toml"),
+            "my-skill",
+            NewOptions::default(),
+        );
 
         // Then
         assert!(result.is_err());
@@ -0,0 +1,300 @@
+//! Pipeline command implementation
+//!
+//! Exports a pipeline as a playbook: its stages in order, with parallel
+//! alternatives grouped, each carrying what a person or orchestrator needs
+//! to run it by hand.
+
+use std::collections::BTreeMap;
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+use crate::config::Config;
+use crate::skill::{self, Skill};
+
+/// Playbook output format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Markdown,
+    Json,
+    Yaml,
+}
+
+impl ExportFormat {
+    pub fn parse_format(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "markdown" | "md" => Some(Self::Markdown),
+            "json" => Some(Self::Json),
+            "yaml" | "yml" => Some(Self::Yaml),
+            _ => None,
+        }
+    }
+}
+
+/// A pipeline's stages in execution order
+#[derive(Debug, Serialize)]
+pub struct Playbook {
+    pub pipeline: String,
+    pub steps: Vec<PlaybookStep>,
+}
+
+/// Stages sharing one `order`; more than one means pick an alternative
+#[derive(Debug, Serialize)]
+pub struct PlaybookStep {
+    pub order: u32,
+    pub stages: Vec<PlaybookStage>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PlaybookStage {
+    pub skill: String,
+    pub stage: String,
+    pub description: String,
+    pub after: Vec<String>,
+    pub before: Vec<String>,
+    /// Slash invocation, with `argument-hint` when the skill declares one
+    pub invoke: String,
+}
+
+/// Print a pipeline playbook to stdout
+pub fn export(config: &Config, name: &str, format: ExportFormat) -> Result<()> {
+    let skills = skill::discover_all(&config.sources.skills)?;
+    let playbook = playbook(&skills, name)?;
+
+    print!("{}", render(&playbook, format)?);
+
+    Ok(())
+}
+
+/// Build the playbook for one pipeline
+///
+/// Steps follow the declared `order`, so a pipeline whose `after`/`before`
+/// entries disagree with it (or loop) is refused rather than exported in an
+/// order the entries contradict.
+pub fn playbook(skills: &[Skill], name: &str) -> Result<Playbook> {
+    let mut by_order: BTreeMap<u32, Vec<PlaybookStage>> = BTreeMap::new();
+    for skill in skills {
+        let Some(stage) = skill
+            .frontmatter
+            .pipeline
+            .as_ref()
+            .and_then(|p| p.get(name))
+        else {
+            continue;
+        };
+
+        let invoke = match &skill.frontmatter.argument_hint {
+            Some(hint) if !hint.trim().is_empty() => format!("/{} {}", skill.name, hint.trim()),
+            _ => format!("/{}", skill.name),
+        };
+        by_order
+            .entry(stage.order)
+            .or_default()
+            .push(PlaybookStage {
+                skill: skill.name.clone(),
+                stage: stage.stage.clone(),
+                description: skill.frontmatter.description.trim().to_string(),
+                after: stage.after.clone().unwrap_or_default(),
+                before: stage.before.clone().unwrap_or_default(),
+                invoke,
+            });
+    }
+
+    if by_order.is_empty() {
+        let mut available: Vec<&str> = skills
+            .iter()
+            .filter_map(|s| s.frontmatter.pipeline.as_ref())
+            .flat_map(|p| p.keys().map(String::as_str))
+            .collect();
+        available.sort();
+        available.dedup();
+        if available.is_empty() {
+            anyhow::bail!("No pipelines found in any skill");
+        }
+        anyhow::bail!(
+            "Pipeline '{}' not found. Available: {}",
+            name,
+            available.join(", ")
+        );
+    }
+
+    let stages: Vec<(&str, &skill::PipelineStage)> = skills
+        .iter()
+        .filter_map(|s| Some((s.name.as_str(), s.frontmatter.pipeline.as_ref()?.get(name)?)))
+        .collect();
+    let analysis = skill::pipeline::analyze(stages);
+    let mut problems: Vec<String> = analysis
+        .cycles
+        .iter()
+        .map(|cycle| format!("cycle {}", cycle.join(" → ")))
+        .collect();
+    problems.extend(analysis.contradictions.iter().map(|c| {
+        format!(
+            "'{}' (order {}) must run before '{}' (order {})",
+            c.first, c.first_order, c.then, c.then_order
+        )
+    }));
+    if !problems.is_empty() {
+        anyhow::bail!(
+            "Pipeline '{}' has ordering problems: {}. Run `loadout check` for details",
+            name,
+            problems.join("; ")
+        );
+    }
+
+    let steps = by_order
+        .into_iter()
+        .map(|(order, mut stages)| {
+            stages.sort_by(|a, b| a.skill.cmp(&b.skill));
+            PlaybookStep { order, stages }
+        })
+        .collect();
+
+    Ok(Playbook {
+        pipeline: name.to_string(),
+        steps,
+    })
+}
+
+/// Render a playbook as a Markdown checklist
+fn render_markdown(playbook: &Playbook) -> String {
+    let mut out = format!("# Pipeline: {}\n", playbook.pipeline);
+
+    for (position, step) in playbook.steps.iter().enumerate() {
+        let heading = if step.stages.len() > 1 {
+            format!("Step {} (choose one)", position + 1)
+        } else {
+            format!("Step {}: {}", position + 1, step.stages[0].stage)
+        };
+        out.push_str(&format!("\n## {}\n\n", heading));
+
+        for stage in &step.stages {
+            out.push_str(&format!("- [ ] `{}` — {}\n", stage.invoke, stage.stage));
+            for line in stage.description.lines() {
+                out.push_str(&format!("  {}\n", line.trim()));
+            }
+            if !stage.after.is_empty() {
+                out.push_str(&format!("  - After: {}\n", stage.after.join(", ")));
+            }
+            if !stage.before.is_empty() {
+                out.push_str(&format!("  - Before: {}\n", stage.before.join(", ")));
+            }
+        }
+    }
+
+    out
+}
+
+/// Render a playbook to a string in the given format
+pub fn render(playbook: &Playbook, format: ExportFormat) -> Result<String> {
+    Ok(match format {
+        ExportFormat::Markdown => render_markdown(playbook),
+        ExportFormat::Json => {
+            serde_json::to_string_pretty(playbook).context("Failed to render JSON")? + "\n"
+        }
+        ExportFormat::Yaml => serde_yaml::to_string(playbook).context("Failed to render YAML")?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::skill::testing::{write_skill, write_skills};
+    use tempfile::TempDir;
+
+    fn setup(temp: &TempDir) -> Vec<Skill> {
+        write_skills(
+            &temp.path().join("skills"),
+            &[
+                (
+                    "outline",
+                    "argument-hint: <topic>\npipeline:\n  blog:\n    stage: outline\n    order: 1\n    before: [draft, dictate]\n",
+                ),
+                (
+                    "draft",
+                    "pipeline:\n  blog:\n    stage: write\n    order: 2\n    after: [outline]\n",
+                ),
+                (
+                    "dictate",
+                    "pipeline:\n  blog:\n    stage: write\n    order: 2\n    after: [outline]\n",
+                ),
+                ("loose", ""),
+            ],
+        )
+    }
+
+    #[test]
+    fn should_group_parallel_stages_and_render_markdown() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let skills = setup(&temp);
+
+        // When
+        let playbook = playbook(&skills, "blog").unwrap();
+        let markdown = render(&playbook, ExportFormat::Markdown).unwrap();
+
+        // Then
+        assert_eq!(playbook.steps.len(), 2);
+        let parallel: Vec<&str> = playbook.steps[1]
+            .stages
+            .iter()
+            .map(|s| s.skill.as_str())
+            .collect();
+        assert_eq!(parallel, vec!["dictate", "draft"]);
+        assert_eq!(
+            markdown,
+            "# Pipeline: blog\n\n\
+             ## Step 1: outline\n\n\
+             - [ ] `/outline <topic>` — outline\n  Run the outline step\n  - Before: draft, dictate\n\n\
+             ## Step 2 (choose one)\n\n\
+             - [ ] `/dictate` — write\n  Run the dictate step\n  - After: outline\n\
+             - [ ] `/draft` — write\n  Run the draft step\n  - After: outline\n"
+        );
+    }
+
+    #[test]
+    fn should_export_json_and_yaml_and_reject_unknown_pipeline() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let skills = setup(&temp);
+        let playbook = playbook(&skills, "blog").unwrap();
+
+        // When
+        let json: serde_json::Value =
+            serde_json::from_str(&render(&playbook, ExportFormat::Json).unwrap()).unwrap();
+        let yaml: serde_yaml::Value =
+            serde_yaml::from_str(&render(&playbook, ExportFormat::Yaml).unwrap()).unwrap();
+        let missing = super::playbook(&skills, "vlog");
+
+        // Then
+        assert_eq!(json["steps"][0]["stages"][0]["invoke"], "/outline <topic>");
+        assert_eq!(json["steps"][1]["order"], 2);
+        assert_eq!(yaml["pipeline"], "blog");
+        assert!(missing
+            .unwrap_err()
+            .to_string()
+            .contains("Pipeline 'vlog' not found. Available: blog"));
+    }
+
+    #[test]
+    fn should_refuse_to_export_when_entries_contradict_order() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let dir = &temp.path().join("skills");
+        setup(&temp);
+        write_skill(
+            dir,
+            "publish",
+            "pipeline:\n  blog:\n    stage: publish\n    order: 2\n    before: [outline]\n",
+        );
+        let skills = skill::discover_in_directory(dir).unwrap();
+
+        // When
+        let result = playbook(&skills, "blog");
+
+        // Then
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("Pipeline 'blog' has ordering problems"));
+        assert!(err.contains("'publish' (order 2) must run before 'outline' (order 1)"));
+    }
+}
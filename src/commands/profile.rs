@@ -0,0 +1,186 @@
+//! Profile command implementation
+//!
+//! `loadout use` records the active profile in `loadout.toml`, then
+//! reinstalls so links left by the previous profile are pruned.
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+
+use crate::commands::install::install;
+use crate::commands::trust::warn_untrusted;
+use crate::config::{self, Config};
+
+/// Print the configured profiles, marking the active one
+pub fn list_profiles(config: &Config) -> Result<()> {
+    let mut names: Vec<&String> = config.profiles.keys().collect();
+    names.sort();
+
+    if names.is_empty() {
+        println!("No profiles defined. Add [profiles.<name>] to loadout.toml");
+        return Ok(());
+    }
+
+    for name in names {
+        if config.profile.as_ref() == Some(name) {
+            println!("{} {}", "*".green(), name.green().bold());
+        } else {
+            println!("  {}", name);
+        }
+    }
+    Ok(())
+}
+
+/// Activate a profile, or clear it with `None`, and reconcile targets
+pub fn use_profile(config_path: &Path, profile: Option<&str>) -> Result<()> {
+    let contents = fs::read_to_string(config_path)
+        .context(format!("Failed to read {}", config_path.display()))?;
+    let updated = set_profile_in_config(&contents, profile)
+        .context(format!("Failed to update {}", config_path.display()))?;
+
+    // Load before writing, so a broken profile leaves the file untouched
+    let loaded = config::load_contents(config_path, &updated)?;
+    fs::write(config_path, updated)
+        .context(format!("Failed to write {}", config_path.display()))?;
+    match profile {
+        Some(name) => println!("{} profile '{}'", "Switched to".green().bold(), name),
+        None => println!("{}", "Cleared the active profile".green().bold()),
+    }
+    println!();
+
+    warn_untrusted(&loaded.untrusted);
+    install(&loaded.config, false)
+}
+
+/// Set or remove the top-level `profile` key, keeping the file's layout
+fn set_profile_in_config(contents: &str, profile: Option<&str>) -> Result<String> {
+    let mut doc: toml_edit::DocumentMut = contents.parse()?;
+
+    let Some(name) = profile else {
+        doc.remove("profile");
+        return Ok(doc.to_string());
+    };
+
+    let mut available: Vec<String> = doc
+        .get("profiles")
+        .and_then(|p| p.as_table_like())
+        .map(|profiles| profiles.iter().map(|(key, _)| key.to_string()).collect())
+        .unwrap_or_default();
+    if !available.iter().any(|p| p == name) {
+        available.sort();
+        anyhow::bail!(
+            "Unknown profile '{}'. Available profiles: {}",
+            name,
+            if available.is_empty() {
+                "none".to_string()
+            } else {
+                available.join(", ")
+            }
+        );
+    }
+
+    doc["profile"] = toml_edit::value(name);
+    Ok(doc.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"# Shared config
+
+[sources]
+skills = ["~/skills"]
+
+[global]
+targets = ["claude_code"]
+skills = []
+
+[profiles.work]
+skills = ["deploy"]
+
+[profiles.demo]
+skills = ["showcase"]
+"#;
+
+    #[test]
+    fn should_set_and_clear_active_profile() {
+        // When
+        let set = set_profile_in_config(CONFIG, Some("demo")).unwrap();
+        let switched = set_profile_in_config(&set, Some("work")).unwrap();
+        let cleared = set_profile_in_config(&switched, None).unwrap();
+
+        // Then
+        let parsed: Config = toml::from_str(&switched).unwrap();
+        assert_eq!(parsed.profile.as_deref(), Some("work"));
+        assert!(set.contains("# Shared config"));
+        assert_eq!(cleared, CONFIG);
+    }
+
+    #[test]
+    fn should_reject_unknown_profile() {
+        // When
+        let result = set_profile_in_config(CONFIG, Some("travel"));
+
+        // Then
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Unknown profile 'travel'. Available profiles: demo, work"));
+    }
+
+    #[test]
+    fn should_prune_previous_profile_links_when_switching() {
+        // Given
+        let temp = tempfile::TempDir::new().unwrap();
+        for name in ["deploy", "showcase"] {
+            let skill_dir = temp.path().join("skills").join(name);
+            fs::create_dir_all(&skill_dir).unwrap();
+            fs::write(
+                skill_dir.join("SKILL.md"),
+                format!("---\nname: {name}\ndescription: Test skill\n---\n"),
+            )
+            .unwrap();
+        }
+        let config_path = temp.path().join("loadout.toml");
+        let contents = r#"[sources]
+skills = ["skills"]
+
+[global]
+targets = ["runner"]
+skills = []
+
+[target_aliases.runner]
+global = "target"
+project = ".runner/skills"
+
+[profiles.work]
+skills = ["deploy"]
+
+[profiles.demo]
+skills = ["showcase"]
+
+[profiles.broken]
+targets = ["missing"]
+"#;
+        fs::write(&config_path, contents).unwrap();
+        let target = temp.path().join("target");
+
+        // When
+        use_profile(&config_path, Some("demo")).unwrap();
+        let demo_installed = target.join("showcase").is_symlink();
+        use_profile(&config_path, Some("work")).unwrap();
+        let switched = fs::read_to_string(&config_path).unwrap();
+        let broken = use_profile(&config_path, Some("broken"));
+
+        // Then
+        assert!(demo_installed);
+        assert!(!target.join("showcase").exists());
+        assert!(target.join("deploy").is_symlink());
+        assert!(broken.is_err());
+        assert_eq!(fs::read_to_string(&config_path).unwrap(), switched);
+        assert!(switched.contains("profile = \"work\""));
+    }
+}
@@ -0,0 +1,716 @@
+//! Rename command implementation
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+use regex::Regex;
+
+use crate::commands::new::validate_skill_name;
+use crate::config::local::PROJECT_FILE_NAME;
+use crate::config::{trust, Config};
+use crate::skill::{self, markdown, Skill};
+
+/// Pending changes for a rename, computed before anything is written
+#[derive(Debug, Default)]
+struct RenamePlan {
+    /// Files whose content changes (path, before, after)
+    edits: Vec<FileEdit>,
+    /// Skill directory moves (from, to), one per source defining the skill
+    moves: Vec<(PathBuf, PathBuf)>,
+    /// Stub skills written at the old name, pointing to the new one
+    redirects: Vec<FileEdit>,
+    /// Project roots whose trusted `.loadout.toml` is edited, so the new
+    /// contents are trusted too
+    retrust: Vec<PathBuf>,
+}
+
+#[derive(Debug)]
+struct FileEdit {
+    path: PathBuf,
+    before: String,
+    after: String,
+}
+
+/// Rename a skill and rewrite every reference to it
+///
+/// Updates the skill's `name:` and directory, detected cross-references and
+/// pipeline `after`/`before` entries in all sources, including projects'
+/// own, and skill lists in `loadout.toml` and projects' `.loadout.toml`.
+/// A skill defined in several sources is renamed in each. With `redirect`,
+/// a stub skill is left at the old name.
+pub fn rename(
+    config: &Config,
+    config_path: &Path,
+    old: &str,
+    new: &str,
+    dry_run: bool,
+    redirect: bool,
+) -> Result<()> {
+    let plan = plan_rename(config, config_path, old, new, redirect)?;
+
+    let prefix = if dry_run { "[dry-run] " } else { "" };
+    println!(
+        "{}{} {} → {}",
+        prefix,
+        "Renaming skill:".cyan().bold(),
+        old,
+        new
+    );
+    print_plan(&plan);
+
+    if dry_run {
+        return Ok(());
+    }
+
+    apply_plan(&plan, config_path)?;
+
+    println!();
+    println!(
+        "{} Renamed '{}' to '{}' ({} files updated)",
+        "✓".green().bold(),
+        old,
+        new,
+        plan.edits.len()
+    );
+    println!("Run {} to relink targets", "loadout install".cyan());
+
+    Ok(())
+}
+
+fn plan_rename(
+    config: &Config,
+    config_path: &Path,
+    old: &str,
+    new: &str,
+    redirect: bool,
+) -> Result<RenamePlan> {
+    validate_skill_name(new)?;
+
+    let all_skills = discover_with_project_sources(config)?;
+    let known_skills: HashSet<String> = all_skills.iter().map(|s| s.name.clone()).collect();
+
+    let renamed: Vec<&Skill> = all_skills.iter().filter(|s| s.name == old).collect();
+    if renamed.is_empty() {
+        return Err(
+            skill::SkillError::not_found(old, all_skills.iter().map(|s| s.name.as_str())).into(),
+        );
+    }
+    if known_skills.contains(new) {
+        anyhow::bail!("Skill '{}' already exists", new);
+    }
+
+    let mut new_dirs = Vec::new();
+    for skill in &renamed {
+        let new_dir = skill
+            .path
+            .parent()
+            .context(format!(
+                "Skill directory has no parent: {}",
+                skill.path.display()
+            ))?
+            .join(new);
+        if new_dir.exists() {
+            anyhow::bail!("Directory already exists: {}", new_dir.display());
+        }
+        new_dirs.push(new_dir);
+    }
+
+    // Every occurrence counts when renaming, however weak the heuristic
+    let mut options = skill::ExtractOptions::from_config(&config.crossref)?;
+    options.min_confidence = 0.0;
+
+    let mut plan = RenamePlan::default();
+
+    for skill in &all_skills {
+        let before = fs::read_to_string(&skill.skill_file)
+            .context(format!("Failed to read {}", skill.skill_file.display()))?;
+
+        let mut after = before.clone();
+        let mut refs: Vec<skill::CrossRef> =
+            skill::references_for_skill(skill, Some(&known_skills), &options)?
+                .into_iter()
+                .filter(|r| r.target == old)
+                .collect();
+
+        // Rewrite from the end so earlier positions stay valid
+        refs.sort_by_key(|r| std::cmp::Reverse((r.line, r.column)));
+        refs.dedup_by_key(|r| (r.line, r.column));
+        for r in &refs {
+            if let Some(updated) = markdown::replace_at(&after, r.line, r.column, old, new) {
+                after = updated;
+            }
+        }
+
+        if references_in_pipeline(skill, old) || skill.name == old {
+            after = rename_in_frontmatter(&after, old, new, skill.name == old);
+        }
+
+        if after != before {
+            plan.edits.push(FileEdit {
+                path: skill.skill_file.clone(),
+                before,
+                after,
+            });
+        }
+    }
+
+    if config_path.exists() {
+        let before = fs::read_to_string(config_path)
+            .context(format!("Failed to read {}", config_path.display()))?;
+        let after = rename_in_config(&before, old, new)
+            .context(format!("Failed to update {}", config_path.display()))?;
+        if after != before {
+            plan.edits.push(FileEdit {
+                path: config_path.to_path_buf(),
+                before,
+                after,
+            });
+        }
+    }
+
+    let config_dir = config_path.parent().unwrap_or(Path::new(""));
+    let store = trust::read(config_dir)?;
+    let mut roots: Vec<&PathBuf> = config.projects.keys().collect();
+    roots.sort();
+    for root in roots {
+        let path = root.join(PROJECT_FILE_NAME);
+        if !path.is_file() {
+            continue;
+        }
+        let before =
+            fs::read_to_string(&path).context(format!("Failed to read {}", path.display()))?;
+        let after = rename_in_project_file(&before, old, new)
+            .context(format!("Failed to update {}", path.display()))?;
+        if after != before {
+            if store.is_trusted(root, &before) {
+                plan.retrust.push(root.clone());
+            }
+            plan.edits.push(FileEdit {
+                path,
+                before,
+                after,
+            });
+        }
+    }
+
+    for (skill, new_dir) in renamed.into_iter().zip(new_dirs) {
+        if skill.path.file_name().and_then(|n| n.to_str()) == Some(old) {
+            plan.moves.push((skill.path.clone(), new_dir));
+        }
+
+        if redirect {
+            plan.redirects.push(FileEdit {
+                path: skill.path.join("SKILL.md"),
+                before: String::new(),
+                after: redirect_stub(old, new),
+            });
+        }
+    }
+
+    Ok(plan)
+}
+
+/// Skills in the global sources and in every project's own sources
+fn discover_with_project_sources(config: &Config) -> Result<Vec<Skill>> {
+    let mut skills = skill::discover_all(&config.sources.skills)?;
+    let mut seen: HashSet<PathBuf> = skills.iter().map(|s| s.skill_file.clone()).collect();
+
+    let mut projects: Vec<_> = config.projects.iter().collect();
+    projects.sort_by_key(|(root, _)| *root);
+    for (_, project) in projects {
+        for skill in skill::discover_all(&project.sources)? {
+            if seen.insert(skill.skill_file.clone()) {
+                skills.push(skill);
+            }
+        }
+    }
+
+    Ok(skills)
+}
+
+fn references_in_pipeline(skill: &Skill, name: &str) -> bool {
+    skill
+        .frontmatter
+        .pipeline
+        .as_ref()
+        .map(|pipelines| {
+            pipelines.values().any(|stage| {
+                stage.after_skills().any(|s| s == name) || stage.before_skills().any(|s| s == name)
+            })
+        })
+        .unwrap_or(false)
+}
+
+/// Rewrite `name:` and pipeline `after`/`before` entries in the frontmatter
+///
+/// Works on the raw text so comments and formatting survive.
+fn rename_in_frontmatter(content: &str, old: &str, new: &str, rename_name: bool) -> String {
+    let quoted = |value: &str| format!(r#"["']?{}["']?"#, regex::escape(value));
+    let name_re = Regex::new(&format!(r"^(name:\s*){}(\s*)$", quoted(old))).unwrap();
+    let list_key_re = Regex::new(r"^\s*(?:after|before):\s*$").unwrap();
+    let list_item_re = Regex::new(&format!(
+        r#"^(\s*-\s*["']?){}((?:@[^"'\s]*)?["']?\s*)$"#,
+        regex::escape(old)
+    ))
+    .unwrap();
+    let inline_re = Regex::new(r"^(\s*(?:after|before):\s*\[)([^\]]*)(\].*)$").unwrap();
+
+    let mut output = String::with_capacity(content.len());
+    let mut delimiters = 0;
+    let mut in_list = false;
+
+    for raw in content.split_inclusive('\n') {
+        let line = raw.trim_end_matches(['\n', '\r']);
+        let ending = &raw[line.len()..];
+
+        if delimiters >= 2 {
+            output.push_str(raw);
+            continue;
+        }
+        if line.trim() == "---" {
+            delimiters += 1;
+            output.push_str(raw);
+            continue;
+        }
+
+        let replaced = if rename_name && name_re.is_match(line) {
+            in_list = false;
+            format!("name: {}", new)
+        } else if list_key_re.is_match(line) {
+            in_list = true;
+            line.to_string()
+        } else if in_list && line.trim_start().starts_with('-') {
+            list_item_re
+                .replace(line, |cap: &regex::Captures| {
+                    format!("{}{}{}", &cap[1], new, &cap[2])
+                })
+                .into_owned()
+        } else if let Some(cap) = inline_re.captures(line) {
+            in_list = false;
+            let items: Vec<String> = cap[2]
+                .split(',')
+                .map(|item| {
+                    let value = item.trim().trim_matches(['"', '\'']);
+                    if skill::frontmatter::requirement_name(value) == old {
+                        item.replacen(old, new, 1)
+                    } else {
+                        item.to_string()
+                    }
+                })
+                .collect();
+            format!("{}{}{}", &cap[1], items.join(","), &cap[3])
+        } else {
+            if !line.trim().is_empty() {
+                in_list = false;
+            }
+            line.to_string()
+        };
+
+        output.push_str(&replaced);
+        output.push_str(ending);
+    }
+
+    output
+}
+
+/// Rewrite skill lists, exclude lists, and check suppressions in `loadout.toml`,
+/// including those in profiles and `[when]` tables
+///
+/// Uses `toml_edit` so comments and layout are preserved.
+fn rename_in_config(contents: &str, old: &str, new: &str) -> Result<String> {
+    let mut doc: toml_edit::DocumentMut = contents.parse()?;
+    let rename = |value: &str| (value == old).then(|| new.to_string());
+
+    rename_in_scopes(doc.as_table_mut(), &rename);
+    if let Some(profiles) = doc.get_mut("profiles").and_then(|p| p.as_table_like_mut()) {
+        for (_, profile) in profiles.iter_mut() {
+            if let Some(skills) = profile.get_mut("skills").and_then(|s| s.as_array_mut()) {
+                rename_in_array(skills, rename);
+            }
+        }
+    }
+    // [when.host."name"] and [when.env.VAR] hold overrides of the same shape
+    if let Some(when) = doc.get_mut("when").and_then(|w| w.as_table_like_mut()) {
+        for (_, conditions) in when.iter_mut() {
+            let Some(conditions) = conditions.as_table_like_mut() else {
+                continue;
+            };
+            for (_, overrides) in conditions.iter_mut() {
+                if let Some(overrides) = overrides.as_table_like_mut() {
+                    rename_in_scopes(overrides, &rename);
+                }
+            }
+        }
+    }
+
+    // Suppression keys are "check-type:source:detail"; rename matching segments
+    if let Some(ignore) = doc
+        .get_mut("check")
+        .and_then(|c| c.get_mut("ignore"))
+        .and_then(|i| i.as_array_mut())
+    {
+        rename_in_array(ignore, |value| {
+            let segments: Vec<&str> = value.split(':').collect();
+            segments.contains(&old).then(|| {
+                segments
+                    .iter()
+                    .map(|s| if *s == old { new } else { s })
+                    .collect::<Vec<_>>()
+                    .join(":")
+            })
+        });
+    }
+
+    Ok(doc.to_string())
+}
+
+/// Rename entries in the `[global]` and `[projects]` skill lists of a table
+fn rename_in_scopes(
+    table: &mut dyn toml_edit::TableLike,
+    rename: &impl Fn(&str) -> Option<String>,
+) {
+    for key in ["skills", "exclude"] {
+        if let Some(skills) = table
+            .get_mut("global")
+            .and_then(|g| g.get_mut(key))
+            .and_then(|s| s.as_array_mut())
+        {
+            rename_in_array(skills, rename);
+        }
+
+        if let Some(projects) = table
+            .get_mut("projects")
+            .and_then(|p| p.as_table_like_mut())
+        {
+            for (_, project) in projects.iter_mut() {
+                if let Some(skills) = project.get_mut(key).and_then(|s| s.as_array_mut()) {
+                    rename_in_array(skills, rename);
+                }
+            }
+        }
+    }
+}
+
+/// Rewrite the `skills` and `exclude` lists of a project's `.loadout.toml`
+fn rename_in_project_file(contents: &str, old: &str, new: &str) -> Result<String> {
+    let mut doc: toml_edit::DocumentMut = contents.parse()?;
+    let rename = |value: &str| (value == old).then(|| new.to_string());
+
+    for key in ["skills", "exclude"] {
+        if let Some(skills) = doc.get_mut(key).and_then(|s| s.as_array_mut()) {
+            rename_in_array(skills, rename);
+        }
+    }
+
+    Ok(doc.to_string())
+}
+
+fn rename_in_array(array: &mut toml_edit::Array, rename: impl Fn(&str) -> Option<String>) {
+    for value in array.iter_mut() {
+        let Some(renamed) = value.as_str().and_then(&rename) else {
+            continue;
+        };
+        let decor = value.decor().clone();
+        *value = renamed.into();
+        *value.decor_mut() = decor;
+    }
+}
+
+fn redirect_stub(old: &str, new: &str) -> String {
+    format!(
+        "---\nname: {old}\ndescription: >-\n  Renamed to {new}. Load the {new} skill instead.\n---\n\n\
+         # {old}\n\nThis skill has been renamed to [{new}](../{new}/SKILL.md).\n"
+    )
+}
+
+fn print_plan(plan: &RenamePlan) {
+    for edit in &plan.edits {
+        println!();
+        println!("{}", edit.path.display().to_string().bold());
+        print_line_diff(&edit.before, &edit.after);
+    }
+
+    for (from, to) in &plan.moves {
+        println!();
+        println!("{} {} → {}", "move".yellow(), from.display(), to.display());
+    }
+
+    for stub in &plan.redirects {
+        println!();
+        println!("{} {}", "create".green(), stub.path.display());
+        for line in stub.after.lines() {
+            println!("  {}", format!("+{}", line).green());
+        }
+    }
+}
+
+/// Print changed lines; renames never add or remove lines, so pairs line up
+fn print_line_diff(before: &str, after: &str) {
+    for (index, (old_line, new_line)) in before.lines().zip(after.lines()).enumerate() {
+        if old_line != new_line {
+            println!("  {:>4} {}", index + 1, format!("-{}", old_line).red());
+            println!("  {:>4} {}", "", format!("+{}", new_line).green());
+        }
+    }
+}
+
+fn apply_plan(plan: &RenamePlan, config_path: &Path) -> Result<()> {
+    // Content first: the renamed skill's own SKILL.md lives under the old path
+    for edit in &plan.edits {
+        fs::write(&edit.path, &edit.after)
+            .context(format!("Failed to write {}", edit.path.display()))?;
+    }
+
+    for (from, to) in &plan.moves {
+        fs::rename(from, to).context(format!(
+            "Failed to move {} to {}",
+            from.display(),
+            to.display()
+        ))?;
+    }
+
+    for stub in &plan.redirects {
+        if let Some(dir) = stub.path.parent() {
+            fs::create_dir_all(dir).context(format!("Failed to create {}", dir.display()))?;
+        }
+        fs::write(&stub.path, &stub.after)
+            .context(format!("Failed to write {}", stub.path.display()))?;
+    }
+
+    if !plan.retrust.is_empty() {
+        let config_dir = config_path.parent().unwrap_or(Path::new(""));
+        let mut store = trust::read(config_dir)?;
+        for root in &plan.retrust {
+            let path = root.join(PROJECT_FILE_NAME);
+            if let Some(edit) = plan.edits.iter().find(|edit| edit.path == path) {
+                store.trust(root, &edit.after);
+            }
+        }
+        trust::write(config_dir, &store)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{default_target_aliases, Global, Project, Sources};
+    use crate::skill::testing::write_skill_with;
+    use std::collections::HashMap;
+    use tempfile::TempDir;
+
+    fn setup(temp: &TempDir) -> (Config, PathBuf) {
+        let skills_dir = temp.path().join("skills");
+        write_skill_with(&skills_dir, "voice", "Test skill", "", "# Voice\n");
+        write_skill_with(
+            &skills_dir,
+            "writer",
+            "Test skill",
+            "pipeline:\n  publish:\n    stage: draft\n    order: 2\n    after:\n      - voice\n\
+             \x20 revise:\n    stage: revise\n    order: 1\n    after:\n      - \"voice@^1.0\"\n",
+            "<see ref=\"voice\">tone</see>\nUse the `voice` skill, see [[voice]].\n",
+        );
+        write_skill_with(
+            &skills_dir,
+            "editor",
+            "Test skill",
+            "pipeline:\n  publish:\n    stage: edit\n    order: 3\n    after: [writer, \"voice\"]\n",
+            "Tone: [voice](../voice/SKILL.md)\n\n```\n<see ref=\"voice\">example</see>\n```\n",
+        );
+
+        let config_path = temp.path().join("loadout.toml");
+        fs::write(
+            &config_path,
+            "[sources]\nskills = [\"skills\"]\n\n[global]\ntargets = []\n\
+             skills = [\"voice\", \"writer\"] # core\n\n\
+             [projects.\"/tmp/app\"]\nskills = [\"voice\"]\n\n\
+             [check]\nignore = [\"orphaned:voice\"]\n\n\
+             [profiles.demo]\nskills = [\"voice\"]\n\n\
+             [when.env.CI.global]\nexclude = [\"voice\"]\n",
+        )
+        .unwrap();
+
+        let config = Config {
+            sources: Sources {
+                skills: vec![skills_dir],
+                templates: vec![],
+            },
+            global: Global {
+                targets: vec![],
+                skills: vec!["voice".to_string(), "writer".to_string()],
+                exclude: vec![],
+                allow_drafts: false,
+            },
+            target_aliases: default_target_aliases(),
+            projects: HashMap::new(),
+            check: Default::default(),
+            crossref: Default::default(),
+            budget: Default::default(),
+            profile: None,
+            profiles: Default::default(),
+        };
+        (config, config_path)
+    }
+
+    #[test]
+    fn should_rename_skill_and_rewrite_references() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let (config, config_path) = setup(&temp);
+        let skills_dir = temp.path().join("skills");
+
+        // When
+        rename(&config, &config_path, "voice", "tone", false, false).unwrap();
+
+        // Then
+        assert!(!skills_dir.join("voice").exists());
+        let renamed = fs::read_to_string(skills_dir.join("tone/SKILL.md")).unwrap();
+        assert!(renamed.starts_with("---\nname: tone\n"));
+
+        let writer = fs::read_to_string(skills_dir.join("writer/SKILL.md")).unwrap();
+        assert!(writer.contains("    after:\n      - tone\n"));
+        assert!(writer.contains("      - \"tone@^1.0\"\n"));
+        assert!(
+            writer.contains("<see ref=\"tone\">tone</see>\nUse the `tone` skill, see [[tone]].")
+        );
+
+        let editor = fs::read_to_string(skills_dir.join("editor/SKILL.md")).unwrap();
+        assert!(editor.contains("after: [writer, \"tone\"]"));
+        assert!(editor.contains("[voice](../tone/SKILL.md)"));
+        assert!(editor.contains("<see ref=\"voice\">example</see>"));
+
+        let config_text = fs::read_to_string(&config_path).unwrap();
+        assert!(config_text.contains("skills = [\"tone\", \"writer\"] # core"));
+        assert!(config_text.contains("[projects.\"/tmp/app\"]\nskills = [\"tone\"]"));
+        assert!(config_text.contains("ignore = [\"orphaned:tone\"]"));
+        assert!(config_text.contains("[profiles.demo]\nskills = [\"tone\"]"));
+        assert!(config_text.contains("[when.env.CI.global]\nexclude = [\"tone\"]"));
+
+        let skills = skill::discover_all(&config.sources.skills).unwrap();
+        assert_eq!(skills.len(), 3);
+    }
+
+    #[test]
+    fn should_not_write_anything_on_dry_run() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let (config, config_path) = setup(&temp);
+        let writer_file = temp.path().join("skills/writer/SKILL.md");
+        let before = fs::read_to_string(&writer_file).unwrap();
+
+        // When
+        rename(&config, &config_path, "voice", "tone", true, true).unwrap();
+
+        // Then
+        assert!(temp.path().join("skills/voice").exists());
+        assert!(!temp.path().join("skills/tone").exists());
+        assert_eq!(fs::read_to_string(&writer_file).unwrap(), before);
+    }
+
+    #[test]
+    fn should_leave_redirect_stub_at_old_name() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let (config, config_path) = setup(&temp);
+
+        // When
+        rename(&config, &config_path, "voice", "tone", false, true).unwrap();
+
+        // Then
+        let stub = Skill::from_directory(&temp.path().join("skills/voice")).unwrap();
+        assert_eq!(stub.name, "voice");
+        assert!(stub.frontmatter.description.contains("Renamed to tone"));
+        let refs = skill::references_for_skill(
+            &stub,
+            Some(&HashSet::from(["tone".to_string()])),
+            &Default::default(),
+        )
+        .unwrap();
+        assert_eq!(refs[0].target, "tone");
+    }
+
+    /// Add a project at `temp/app` whose own source holds `skills`, and a
+    /// trusted `.loadout.toml` listing `deploy`
+    fn add_project(temp: &TempDir, config: &mut Config, skills: &[&str]) -> PathBuf {
+        let root = temp.path().join("app");
+        let source = root.join(".skills");
+        for name in skills {
+            write_skill_with(&source, name, "Test skill", "", "See [[voice]].\n");
+        }
+        let project_file = "skills = [\"deploy\"]  # ours\nexclude = [\"voice\"]\n";
+        fs::write(root.join(PROJECT_FILE_NAME), project_file).unwrap();
+        let mut store = trust::TrustStore::default();
+        store.trust(&root, project_file);
+        trust::write(temp.path(), &store).unwrap();
+
+        config.projects.insert(
+            root.clone(),
+            Project {
+                skills: vec!["deploy".to_string()],
+                exclude: vec![],
+                sources: vec![source],
+                inherit: true,
+                targets: None,
+            },
+        );
+        root
+    }
+
+    #[test]
+    fn should_rename_skill_that_lives_only_in_project_source() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let (mut config, config_path) = setup(&temp);
+        let root = add_project(&temp, &mut config, &["deploy"]);
+
+        // When
+        rename(&config, &config_path, "deploy", "ship", false, false).unwrap();
+
+        // Then
+        assert!(!root.join(".skills/deploy").exists());
+        let renamed = Skill::from_directory(&root.join(".skills/ship")).unwrap();
+        assert_eq!(renamed.name, "ship");
+        let project_file = fs::read_to_string(root.join(PROJECT_FILE_NAME)).unwrap();
+        assert!(project_file.contains("skills = [\"ship\"]  # ours"));
+        assert!(trust::read(temp.path())
+            .unwrap()
+            .is_trusted(&root, &project_file));
+    }
+
+    #[test]
+    fn should_rename_every_copy_of_shadowed_skill() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let (mut config, config_path) = setup(&temp);
+        let root = add_project(&temp, &mut config, &["deploy", "voice"]);
+
+        // When
+        rename(&config, &config_path, "voice", "tone", false, false).unwrap();
+
+        // Then
+        assert!(temp.path().join("skills/tone/SKILL.md").exists());
+        assert!(root.join(".skills/tone/SKILL.md").exists());
+        assert!(!root.join(".skills/voice").exists());
+        let deploy = fs::read_to_string(root.join(".skills/deploy/SKILL.md")).unwrap();
+        assert!(deploy.contains("See [[tone]]."));
+        let project_file = fs::read_to_string(root.join(PROJECT_FILE_NAME)).unwrap();
+        assert!(project_file.contains("exclude = [\"tone\"]"));
+    }
+
+    #[test]
+    fn should_reject_rename_to_existing_skill() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let (config, config_path) = setup(&temp);
+
+        // When
+        let result = rename(&config, &config_path, "voice", "writer", false, false);
+
+        // Then
+        assert!(result.unwrap_err().to_string().contains("already exists"));
+    }
+}
@@ -0,0 +1,359 @@
+//! Route command implementation
+//!
+//! Ranks the skills enabled in a scope against a sample prompt, to debug
+//! why a skill does or doesn't trigger. BM25 over each skill's name,
+//! description, and tags stands in for the runner's own matching.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+use serde::Deserialize;
+
+use crate::config::Config;
+use crate::plan;
+use crate::skill::{self, text::Bm25};
+
+/// One skill's score for a prompt
+#[derive(Debug, Clone, PartialEq)]
+pub struct RouteMatch {
+    pub name: String,
+    pub score: f64,
+    /// Prompt terms found in the skill's name, description, or tags
+    pub terms: Vec<String>,
+}
+
+/// Prompts with the skills they are expected to trigger
+#[derive(Debug, Deserialize)]
+struct RouteSuite {
+    #[serde(rename = "case", default)]
+    cases: Vec<RouteCase>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RouteCase {
+    prompt: String,
+    /// Skills that must all rank within the top `top` results
+    expect: Vec<String>,
+    #[serde(default = "default_top")]
+    top: usize,
+    /// Project scope for this case, relative to the suite file
+    /// (default: the `--project` flag, or global)
+    #[serde(default)]
+    project: Option<PathBuf>,
+}
+
+fn default_top() -> usize {
+    1
+}
+
+/// Rank the skills enabled in a scope against a prompt
+///
+/// Only skills matching at least one prompt term are returned, best first.
+pub fn rank(config: &Config, project: Option<&Path>, prompt: &str) -> Result<Vec<RouteMatch>> {
+    let (skills, enabled) = scope_skills(config, project)?;
+    let skill_map = skill::build_skill_map(skills);
+
+    let candidates: Vec<&skill::Skill> = enabled
+        .iter()
+        .filter_map(|name| skill_map.get(name))
+        .collect();
+    let documents: Vec<String> = candidates
+        .iter()
+        .map(|s| {
+            format!(
+                "{} {} {}",
+                s.name,
+                s.frontmatter.description,
+                s.frontmatter.tags.as_deref().unwrap_or_default().join(" ")
+            )
+        })
+        .collect();
+    let bm25 = Bm25::new(documents.iter().map(String::as_str));
+
+    let mut matches: Vec<RouteMatch> = candidates
+        .iter()
+        .enumerate()
+        .filter_map(|(i, s)| {
+            let (score, terms) = bm25.score(prompt, i);
+            (score > 0.0).then(|| RouteMatch {
+                name: s.name.clone(),
+                score,
+                terms,
+            })
+        })
+        .collect();
+    matches.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+
+    Ok(matches)
+}
+
+/// Print the skills a prompt would most likely trigger
+pub fn route(config: &Config, prompt: &str, project: Option<&Path>, top: usize) -> Result<()> {
+    let matches = rank(config, project, prompt)?;
+
+    println!(
+        "{} {}",
+        "--- Route:".cyan().bold(),
+        scope_label(project).cyan().bold()
+    );
+    println!("Prompt: \"{}\"", prompt);
+    println!();
+
+    if matches.is_empty() {
+        println!(
+            "{}",
+            "No enabled skill matches any term in the prompt".dimmed()
+        );
+        return Ok(());
+    }
+
+    let width = matches.iter().map(|m| m.name.len()).max().unwrap_or(0);
+    for (position, entry) in matches.iter().take(top).enumerate() {
+        println!(
+            "  {}. {:<width$}  {:>6.2}  {}",
+            position + 1,
+            entry.name,
+            entry.score,
+            entry.terms.join(", ").dimmed()
+        );
+    }
+
+    Ok(())
+}
+
+/// Check every case in a suite file and fail if any expectation is missed
+pub fn route_suite(config: &Config, suite_path: &Path, project: Option<&Path>) -> Result<()> {
+    let contents = fs::read_to_string(suite_path).context(format!(
+        "Failed to read route suite: {}",
+        suite_path.display()
+    ))?;
+    let suite: RouteSuite = toml::from_str(&contents).context(format!(
+        "Failed to parse route suite: {}",
+        suite_path.display()
+    ))?;
+
+    println!(
+        "{} {}",
+        "--- Route suite:".cyan().bold(),
+        suite_path.display()
+    );
+
+    let suite_dir = suite_path.parent().unwrap_or(Path::new(""));
+    let mut failed = 0;
+    for case in &suite.cases {
+        let case_project = case.project.as_ref().map(|p| suite_dir.join(p));
+        let matches = rank(config, case_project.as_deref().or(project), &case.prompt)?;
+        let missing = missing_expectations(case, &matches);
+
+        if missing.is_empty() {
+            println!("  {} \"{}\"", "✓".green(), case.prompt);
+            continue;
+        }
+
+        failed += 1;
+        let got: Vec<&str> = matches
+            .iter()
+            .take(case.top)
+            .map(|m| m.name.as_str())
+            .collect();
+        println!("  {} \"{}\"", "✗".red(), case.prompt);
+        println!(
+            "      expected in top {}: {}",
+            case.top,
+            missing.join(", ").red()
+        );
+        println!(
+            "      got: {}",
+            if got.is_empty() {
+                "(nothing)".to_string()
+            } else {
+                got.join(", ")
+            }
+        );
+    }
+
+    println!();
+    if failed == 0 {
+        println!("{} {} cases passed", "✓".green().bold(), suite.cases.len());
+        Ok(())
+    } else {
+        println!(
+            "{} {} of {} cases failed",
+            "✗".red().bold(),
+            failed,
+            suite.cases.len()
+        );
+        Err(anyhow::anyhow!("Route suite failed"))
+    }
+}
+
+/// Expected skills that didn't rank within the case's top results
+fn missing_expectations(case: &RouteCase, matches: &[RouteMatch]) -> Vec<String> {
+    let top: Vec<&str> = matches
+        .iter()
+        .take(case.top)
+        .map(|m| m.name.as_str())
+        .collect();
+    case.expect
+        .iter()
+        .filter(|name| !top.contains(&name.as_str()))
+        .cloned()
+        .collect()
+}
+
+/// The skills a scope can see, and the names of those enabled in it
+///
+/// A project sees its own sources ahead of the global ones and includes
+/// inherited skills.
+fn scope_skills(
+    config: &Config,
+    project: Option<&Path>,
+) -> Result<(Vec<skill::Skill>, Vec<String>)> {
+    let global = skill::discover_all(&config.sources.skills)?;
+    let (skills, selection) = match project {
+        Some(path) => {
+            let (_, project) = plan::find_project(config, path)?;
+            let skills = plan::project_skills(project, &global)?;
+            let selection = plan::project_selection(config, project, &skills);
+            (skills, selection)
+        }
+        None => {
+            let selection = plan::global_selection(config, &global);
+            (global, selection)
+        }
+    };
+
+    let mut names: Vec<String> = selection.names().map(String::from).collect();
+    names.sort();
+    Ok((skills, names))
+}
+
+fn scope_label(project: Option<&Path>) -> String {
+    match project {
+        Some(path) => format!("project {}", path.display()),
+        None => "global scope".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{default_target_aliases, Global, Project, Sources};
+    use crate::skill::testing::write_skill_with;
+    use std::collections::HashMap;
+    use tempfile::TempDir;
+
+    fn setup(temp: &TempDir) -> Config {
+        let skills_dir = temp.path().join("skills");
+        write_skill_with(
+            &skills_dir,
+            "code-review",
+            "Review pull requests for correctness",
+            "",
+            "",
+        );
+        write_skill_with(
+            &skills_dir,
+            "deploy",
+            "Deploy the staging environment",
+            "tags: [release]\n",
+            "",
+        );
+        write_skill_with(&skills_dir, "voice", "Write in the house voice", "", "");
+        write_skill_with(&skills_dir, "changelog", "Draft release notes", "", "");
+
+        let project_path = temp.path().join("app");
+        fs::create_dir_all(&project_path).unwrap();
+
+        Config {
+            sources: Sources {
+                skills: vec![skills_dir],
+                templates: vec![],
+            },
+            global: Global {
+                targets: vec![],
+                skills: vec![
+                    "code-review".to_string(),
+                    "deploy".to_string(),
+                    "voice".to_string(),
+                ],
+                exclude: vec![],
+                allow_drafts: false,
+            },
+            target_aliases: default_target_aliases(),
+            projects: HashMap::from([(
+                project_path,
+                Project {
+                    skills: vec!["changelog".to_string()],
+                    exclude: vec![],
+                    sources: vec![],
+                    inherit: false,
+                    targets: None,
+                },
+            )]),
+            check: Default::default(),
+            crossref: Default::default(),
+            budget: Default::default(),
+            profile: None,
+            profiles: Default::default(),
+        }
+    }
+
+    #[test]
+    fn should_rank_enabled_skills_for_scope() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let config = setup(&temp);
+
+        // When
+        let global = rank(&config, None, "cut a release of the staging build").unwrap();
+        let project = rank(
+            &config,
+            Some(&temp.path().join("app")),
+            "cut a release of the staging build",
+        )
+        .unwrap();
+
+        // Then
+        assert_eq!(global.len(), 1);
+        assert_eq!(global[0].name, "deploy");
+        assert_eq!(global[0].terms, vec!["release", "staging"]);
+        let names: Vec<&str> = project.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, vec!["changelog"]);
+        assert!(rank(&config, Some(Path::new("/nowhere")), "x").is_err());
+    }
+
+    #[test]
+    fn should_fail_suite_when_expected_skill_is_not_in_top_k() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let config = setup(&temp);
+        let passing = temp.path().join("passing.toml");
+        fs::write(
+            &passing,
+            "[[case]]\nprompt = \"review my pull request\"\nexpect = [\"code-review\"]\n\n\
+             [[case]]\nprompt = \"release notes\"\nexpect = [\"changelog\"]\nproject = \"app\"\n",
+        )
+        .unwrap();
+        let failing = temp.path().join("failing.toml");
+        fs::write(
+            &failing,
+            "[[case]]\nprompt = \"write the house style\"\nexpect = [\"code-review\"]\ntop = 2\n",
+        )
+        .unwrap();
+
+        // When
+        let passed = route_suite(&config, &passing, None);
+        let failed = route_suite(&config, &failing, None);
+
+        // Then
+        assert!(passed.is_ok());
+        assert!(failed.is_err());
+    }
+}
@@ -0,0 +1,91 @@
+//! Trust command implementation
+//!
+//! Shows a project's `.loadout.toml` and records it in the allowlist, so
+//! `install` starts honoring it.
+
+use std::fs;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+
+use crate::config::local::PROJECT_FILE_NAME;
+use crate::config::trust;
+
+/// Trust the `.loadout.toml` at `root`, asking first unless `yes` is set
+pub fn trust(config_path: &Path, root: &Path, yes: bool) -> Result<()> {
+    let path = root.join(PROJECT_FILE_NAME);
+    let contents =
+        fs::read_to_string(&path).context(format!("Failed to read {}", path.display()))?;
+    let config_dir = config_path.parent().unwrap_or(Path::new(""));
+    let mut store = trust::read(config_dir)?;
+
+    if store.is_trusted(root, &contents) {
+        println!("{} is already trusted", path.display());
+        return Ok(());
+    }
+
+    if !yes {
+        println!("{}", path.display().to_string().cyan().bold());
+        for line in contents.lines() {
+            println!("  {}", line);
+        }
+        println!();
+        if !confirm("Trust this file?")? {
+            println!("Not trusted");
+            return Ok(());
+        }
+    }
+
+    store.trust(root, &contents);
+    trust::write(config_dir, &store)?;
+    println!(
+        "{} {}. Run `loadout install` to apply it",
+        "Trusted".green().bold(),
+        path.display()
+    );
+    Ok(())
+}
+
+/// Remove a project from the allowlist
+pub fn revoke(config_path: &Path, root: &Path) -> Result<()> {
+    let config_dir = config_path.parent().unwrap_or(Path::new(""));
+    let mut store = trust::read(config_dir)?;
+
+    if store.revoke(root) {
+        trust::write(config_dir, &store)?;
+        println!(
+            "{} {}",
+            "Revoked trust in".green().bold(),
+            root.join(PROJECT_FILE_NAME).display()
+        );
+    } else {
+        println!("{} was not trusted", root.display());
+    }
+    Ok(())
+}
+
+/// Warn about project files that were skipped because they aren't trusted
+pub fn warn_untrusted(untrusted: &[PathBuf]) {
+    for root in untrusted {
+        println!(
+            "{} {} is not trusted and was ignored; review it and run `loadout trust {}`",
+            "Warning:".yellow().bold(),
+            root.join(PROJECT_FILE_NAME).display(),
+            root.display()
+        );
+    }
+}
+
+fn confirm(question: &str) -> Result<bool> {
+    if !io::stdin().is_terminal() {
+        anyhow::bail!("Not running interactively; pass --yes to trust without a prompt");
+    }
+
+    print!("{} [y/N] ", question);
+    io::stdout().flush()?;
+    let mut answer = String::new();
+    io::stdin().lock().read_line(&mut answer)?;
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
@@ -147,14 +147,21 @@ mod tests {
         let config = Config {
             sources: Sources {
                 skills: vec![PathBuf::from("tests/fixtures/skills")],
+                templates: vec![],
             },
             global: Global {
                 targets: vec![],
                 skills: vec![],
+                exclude: vec![],
+                allow_drafts: false,
             },
             target_aliases: default_target_aliases(),
             projects: HashMap::new(),
             check: Default::default(),
+            crossref: Default::default(),
+            budget: Default::default(),
+            profile: None,
+            profiles: Default::default(),
         };
 
         // When
@@ -170,14 +177,21 @@ mod tests {
         let config = Config {
             sources: Sources {
                 skills: vec![PathBuf::from("tests/fixtures/skills")],
+                templates: vec![],
             },
             global: Global {
                 targets: vec![],
                 skills: vec![],
+                exclude: vec![],
+                allow_drafts: false,
             },
             target_aliases: default_target_aliases(),
             projects: HashMap::new(),
             check: Default::default(),
+            crossref: Default::default(),
+            budget: Default::default(),
+            profile: None,
+            profiles: Default::default(),
         };
 
         // When
@@ -191,14 +205,23 @@ mod tests {
     fn should_validate_skills_in_directory() {
         // Given
         let config = Config {
-            sources: Sources { skills: vec![] },
+            sources: Sources {
+                skills: vec![],
+                templates: vec![],
+            },
             global: Global {
                 targets: vec![],
                 skills: vec![],
+                exclude: vec![],
+                allow_drafts: false,
             },
             target_aliases: default_target_aliases(),
             projects: HashMap::new(),
             check: Default::default(),
+            crossref: Default::default(),
+            budget: Default::default(),
+            profile: None,
+            profiles: Default::default(),
         };
 
         // When
@@ -214,14 +237,21 @@ mod tests {
         let config = Config {
             sources: Sources {
                 skills: vec![PathBuf::from("tests/fixtures/skills")],
+                templates: vec![],
             },
             global: Global {
                 targets: vec![],
                 skills: vec![],
+                exclude: vec![],
+                allow_drafts: false,
             },
             target_aliases: default_target_aliases(),
             projects: HashMap::new(),
             check: Default::default(),
+            crossref: Default::default(),
+            budget: Default::default(),
+            profile: None,
+            profiles: Default::default(),
         };
 
         // When
@@ -0,0 +1,108 @@
+//! Project-local `.loadout.toml` files
+//!
+//! A repository can commit a `.loadout.toml` at its root to declare the
+//! skills it wants. Once trusted, the file is merged into the user's
+//! `[projects]` entry for that root, which is created if missing:
+//!
+//! - `skills` and `exclude` are added to the user's lists
+//! - `targets` applies only when the user's entry sets none
+//! - `inherit` stays under the user's control
+//! - `sources` are added to the project's own sources
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use super::trust::TrustStore;
+use super::{Config, Project};
+
+/// Name of the project-local config file at a project root
+pub const PROJECT_FILE_NAME: &str = ".loadout.toml";
+
+/// Contents of a project-local `.loadout.toml`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProjectFile {
+    /// Skills the project wants enabled
+    #[serde(default)]
+    pub skills: Vec<String>,
+
+    /// Skills to leave out of the project
+    #[serde(default)]
+    pub exclude: Vec<String>,
+
+    /// Target aliases, used when the user's entry sets none
+    #[serde(default)]
+    pub targets: Option<Vec<String>>,
+
+    /// Skill directories relative to the project root
+    #[serde(default)]
+    pub sources: Vec<PathBuf>,
+}
+
+/// What happened to a project's `.loadout.toml`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectFileStatus {
+    Missing,
+    Applied,
+    Untrusted,
+}
+
+/// Merge the `.loadout.toml` at `root` into `config` if it is trusted
+pub fn apply(config: &mut Config, root: &Path, trust: &TrustStore) -> Result<ProjectFileStatus> {
+    let path = root.join(PROJECT_FILE_NAME);
+    if !path.is_file() {
+        return Ok(ProjectFileStatus::Missing);
+    }
+
+    let contents =
+        fs::read_to_string(&path).context(format!("Failed to read {}", path.display()))?;
+    if !trust.is_trusted(root, &contents) {
+        return Ok(ProjectFileStatus::Untrusted);
+    }
+    let file: ProjectFile =
+        toml::from_str(&contents).context(format!("Failed to parse {}", path.display()))?;
+
+    for alias in file.targets.iter().flatten() {
+        super::ensure_alias_exists(config, alias, &format!("{} targets", path.display()))?;
+    }
+
+    let sources = file
+        .sources
+        .iter()
+        .map(|source| super::expand_config_path(source, root, "sources"))
+        .collect::<Result<Vec<_>>>()
+        .context(format!("Invalid source in {}", path.display()))?;
+
+    let project = config
+        .projects
+        .entry(root.to_path_buf())
+        .or_insert_with(|| Project {
+            skills: Vec::new(),
+            exclude: Vec::new(),
+            sources: Vec::new(),
+            inherit: true,
+            targets: None,
+        });
+    for skill in file.skills {
+        if !project.skills.contains(&skill) {
+            project.skills.push(skill);
+        }
+    }
+    for rule in file.exclude {
+        if !project.exclude.contains(&rule) {
+            project.exclude.push(rule);
+        }
+    }
+    for source in sources {
+        if !project.sources.contains(&source) {
+            project.sources.push(source);
+        }
+    }
+    if project.targets.is_none() {
+        project.targets = file.targets;
+    }
+
+    Ok(ProjectFileStatus::Applied)
+}
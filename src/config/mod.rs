@@ -1,9 +1,17 @@
 //! Configuration loading and path resolution
 
+pub mod local;
+pub mod schema;
+pub mod trust;
 mod types;
+pub mod validate;
+mod when;
 
+pub use crate::skill::crossref::{CrossrefConfig, CrossrefPattern};
+pub use crate::skill::description::DescriptionLintConfig;
 pub use types::{
-    default_target_aliases, CheckConfig, Config, Global, Project, Sources, TargetAliasPaths,
+    default_target_aliases, BudgetConfig, BudgetSeverity, CheckConfig, Config, Global, Profile,
+    Project, Sources, TargetAliasPaths,
 };
 
 use std::env;
@@ -12,46 +20,128 @@ use std::path::{Path, PathBuf};
 
 use anyhow::{anyhow, Context, Result};
 
+/// A loaded config with the project files it skipped
+#[derive(Debug)]
+pub struct Loaded {
+    pub config: Config,
+    /// Project roots whose `.loadout.toml` was skipped as untrusted
+    pub untrusted: Vec<PathBuf>,
+}
+
 /// Load configuration from the standard location
 ///
 /// Resolution order:
 /// 1. $LOADOUT_CONFIG (if set)
 /// 2. $XDG_CONFIG_HOME/loadout/loadout.toml (if set)
 /// 3. ~/.config/loadout/loadout.toml (default)
-pub fn load() -> Result<Config> {
+pub fn load() -> Result<Loaded> {
     let path = resolve_config_path()?;
     load_from(&path)
 }
 
 /// Load configuration from a specific path
-pub fn load_from(path: &Path) -> Result<Config> {
+pub fn load_from(path: &Path) -> Result<Loaded> {
     let contents = fs::read_to_string(path)
         .context(format!("Failed to read config file: {}", path.display()))?;
+    load_contents(path, &contents)
+}
 
-    let mut config: Config = toml::from_str(&contents)
+/// Load config from `contents` as if read from `path`, which relative
+/// paths and project trust are resolved against
+pub fn load_contents(path: &Path, contents: &str) -> Result<Loaded> {
+    let mut config = parse_config(contents)
         .context(format!("Failed to parse config file: {}", path.display()))?;
 
     merge_default_target_aliases(&mut config);
+    apply_profile(&mut config).context(format!("Invalid profile in {}", path.display()))?;
+
+    let config_dir = config_dir(path)?;
+
+    // Expand path fields
+    expand_paths(&mut config, &config_dir)
+        .context(format!("Invalid path in {}", path.display()))?;
+
+    // Merge trusted project-local files into their project entries
+    let trust = trust::read(&config_dir)?;
+    let mut roots: Vec<PathBuf> = config.projects.keys().cloned().collect();
+    roots.sort();
+    let mut untrusted = Vec::new();
+    for root in roots {
+        if local::apply(&mut config, &root, &trust)? == local::ProjectFileStatus::Untrusted {
+            untrusted.push(root);
+        }
+    }
+
+    // Validate aliases and references after expansion.
+    validate_aliases(&config)?;
+
+    // Compile custom crossref patterns now so mistakes surface at load time
+    crate::skill::ExtractOptions::from_config(&config.crossref)
+        .context(format!("Invalid [crossref] section in {}", path.display()))?;
+
+    crate::skill::description::LintOptions::from_config(&config.check.description).context(
+        format!("Invalid [check.description] section in {}", path.display()),
+    )?;
+
+    if !(0.0..=1.0).contains(&config.check.overlap_threshold) {
+        anyhow::bail!(
+            "Invalid [check] section in {}: overlap_threshold {} is not between 0.0 and 1.0",
+            path.display(),
+            config.check.overlap_threshold
+        );
+    }
+
+    Ok(Loaded { config, untrusted })
+}
 
+/// Absolute directory containing the config file, which relative paths
+/// in it are resolved against
+fn config_dir(path: &Path) -> Result<PathBuf> {
     let config_dir = path.parent().context(format!(
         "Config file has no parent directory: {}",
         path.display()
     ))?;
-    let config_dir = if config_dir.is_absolute() {
-        config_dir.to_path_buf()
+    if config_dir.is_absolute() {
+        Ok(config_dir.to_path_buf())
     } else {
-        env::current_dir()
+        Ok(env::current_dir()
             .context("Failed to resolve current working directory")?
-            .join(config_dir)
-    };
+            .join(config_dir))
+    }
+}
 
-    // Expand path fields
-    expand_paths(&mut config, &config_dir)?;
+/// Parse the config, merging the `[when]` tables that apply on this machine
+fn parse_config(contents: &str) -> Result<Config> {
+    let mut table: toml::Table = toml::from_str(contents)?;
+    if !table.contains_key(when::WHEN_KEY) {
+        // Parse the text itself so type errors keep their line numbers
+        return Ok(toml::from_str(contents)?);
+    }
 
-    // Validate aliases and references after expansion.
-    validate_aliases(&config)?;
+    when::apply(&mut table, &when::host_name(), |name| env::var(name).ok())?;
+    Ok(toml::Value::Table(table).try_into()?)
+}
 
-    Ok(config)
+/// Merge the `.loadout.toml` at a project root, as `load_from` does for
+/// configured projects
+///
+/// Used for a project found from the working directory that has no
+/// `[projects]` entry; a trusted file creates one.
+pub fn load_project_file(
+    config: &mut Config,
+    root: &Path,
+    config_path: &Path,
+) -> Result<local::ProjectFileStatus> {
+    let config_dir = config_path.parent().unwrap_or(Path::new(""));
+    let trust = trust::read(config_dir)?;
+    local::apply(config, root, &trust)
+}
+
+/// Path of the config file `load()` reads
+///
+/// Commands that edit `loadout.toml` in place use this to find it.
+pub fn config_path() -> Result<PathBuf> {
+    resolve_config_path()
 }
 
 /// Resolve the config file path using environment variables and XDG conventions
@@ -87,6 +177,57 @@ fn resolve_config_path_from_env(
         .join("loadout.toml"))
 }
 
+/// Replace `${VAR}` and `${VAR:-default}` with environment values
+fn interpolate(value: &str, field_name: &str) -> Result<String> {
+    interpolate_with(value, field_name, |name| env::var(name).ok())
+}
+
+/// `${VAR:-default}` uses the default when VAR is unset or empty, like a
+/// POSIX shell; a `${VAR}` with neither is an error
+fn interpolate_with(
+    value: &str,
+    field_name: &str,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> Result<String> {
+    let mut result = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find("${") {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let end = after
+            .find('}')
+            .ok_or_else(|| anyhow!("Unterminated ${{ in {field_name}: \"{value}\""))?;
+        let (name, default) = match after[..end].split_once(":-") {
+            Some((name, default)) => (name, Some(default)),
+            None => (&after[..end], None),
+        };
+        if !is_valid_variable_name(name) {
+            anyhow::bail!("Invalid variable name '{name}' in {field_name}: \"{value}\"");
+        }
+
+        match (lookup(name), default) {
+            (Some(found), Some(default)) if found.is_empty() => result.push_str(default),
+            (Some(found), _) => result.push_str(&found),
+            (None, Some(default)) => result.push_str(default),
+            (None, None) => anyhow::bail!(
+                "Unresolved variable ${{{name}}} in {field_name}. Set it, or give a default \
+                 with ${{{name}:-...}}"
+            ),
+        }
+        rest = &after[end + 1..];
+    }
+    result.push_str(rest);
+    Ok(result)
+}
+
+fn is_valid_variable_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
 /// Expand ~ and ~/ to $HOME in a path string
 fn expand_tilde(path: &str) -> Result<PathBuf> {
     let home = env::var("HOME").ok();
@@ -111,6 +252,9 @@ fn expand_paths(config: &mut Config, config_dir: &Path) -> Result<()> {
     for source in &mut config.sources.skills {
         *source = expand_config_path(source, config_dir, "sources.skills")?;
     }
+    for templates in &mut config.sources.templates {
+        *templates = expand_config_path(templates, config_dir, "sources.templates")?;
+    }
 
     // Expand target alias paths
     for (alias, paths) in &mut config.target_aliases {
@@ -123,7 +267,8 @@ fn expand_paths(config: &mut Config, config_dir: &Path) -> Result<()> {
     // Expand project paths (keys)
     let project_keys: Vec<PathBuf> = config.projects.keys().cloned().collect();
     for old_key in project_keys {
-        let new_key = expand_config_path(&old_key, config_dir, "projects path key")?;
+        let field = format!("projects.\"{}\"", old_key.display());
+        let new_key = expand_config_path(&old_key, config_dir, &field)?;
         if new_key != old_key {
             if let Some(project) = config.projects.remove(&old_key) {
                 config.projects.insert(new_key, project);
@@ -131,14 +276,26 @@ fn expand_paths(config: &mut Config, config_dir: &Path) -> Result<()> {
         }
     }
 
+    // Project sources are relative to their project
+    for (project_path, project) in &mut config.projects {
+        let field = format!("projects.\"{}\".sources", project_path.display());
+        for source in &mut project.sources {
+            *source = expand_config_path(source, project_path, &field)?;
+        }
+    }
+
     Ok(())
 }
 
-fn expand_config_path(path: &Path, config_dir: &Path, field_name: &str) -> Result<PathBuf> {
+pub(crate) fn expand_config_path(
+    path: &Path,
+    config_dir: &Path,
+    field_name: &str,
+) -> Result<PathBuf> {
     let path_str = path
         .to_str()
         .ok_or_else(|| anyhow!("{field_name} contains non-UTF-8 path"))?;
-    let expanded = expand_tilde(path_str)?;
+    let expanded = expand_tilde(&interpolate(path_str, field_name)?)?;
     if expanded.is_relative() {
         Ok(config_dir.join(expanded))
     } else {
@@ -150,7 +307,38 @@ fn expand_tilde_path(path: &Path, field_name: &str) -> Result<PathBuf> {
     let path_str = path
         .to_str()
         .ok_or_else(|| anyhow!("{field_name} contains non-UTF-8 path"))?;
-    expand_tilde(path_str)
+    expand_tilde(&interpolate(path_str, field_name)?)
+}
+
+/// Replace global lists with those of the active profile
+fn apply_profile(config: &mut Config) -> Result<()> {
+    let Some(name) = &config.profile else {
+        return Ok(());
+    };
+    let Some(profile) = config.profiles.get(name).cloned() else {
+        let mut available: Vec<&str> = config.profiles.keys().map(String::as_str).collect();
+        available.sort();
+        anyhow::bail!(
+            "Unknown profile '{}'. Available profiles: {}",
+            name,
+            if available.is_empty() {
+                "none".to_string()
+            } else {
+                available.join(", ")
+            }
+        );
+    };
+
+    if let Some(sources) = profile.sources {
+        config.sources.skills = sources;
+    }
+    if let Some(targets) = profile.targets {
+        config.global.targets = targets;
+    }
+    if let Some(skills) = profile.skills {
+        config.global.skills = skills;
+    }
+    Ok(())
 }
 
 fn merge_default_target_aliases(config: &mut Config) {
@@ -244,6 +432,48 @@ mod tests {
         assert_eq!(expanded, PathBuf::from(path));
     }
 
+    #[test]
+    fn should_interpolate_variables_with_defaults() {
+        // Given
+        let lookup = |name: &str| match name {
+            "WORKSPACE" => Some("/work".to_string()),
+            "EMPTY" => Some(String::new()),
+            _ => None,
+        };
+
+        // When
+        let set = interpolate_with("${WORKSPACE}/skills", "sources.skills", lookup).unwrap();
+        let defaulted = interpolate_with(
+            "${MISSING:-~/skills}/${EMPTY:-team}",
+            "sources.skills",
+            lookup,
+        )
+        .unwrap();
+        let plain = interpolate_with("$HOME/skills", "sources.skills", lookup).unwrap();
+
+        // Then
+        assert_eq!(set, "/work/skills");
+        assert_eq!(defaulted, "~/skills/team");
+        assert_eq!(plain, "$HOME/skills");
+    }
+
+    #[test]
+    fn should_return_error_for_malformed_variables() {
+        // When
+        let unterminated = interpolate_with("${HOME/skills", "sources.skills", |_| None);
+        let invalid = interpolate_with("${1DIR}", "sources.skills", |_| None);
+
+        // Then
+        assert!(unterminated
+            .unwrap_err()
+            .to_string()
+            .contains("Unterminated ${ in sources.skills"));
+        assert!(invalid
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid variable name '1DIR' in sources.skills"));
+    }
+
     #[test]
     fn should_prefer_loadout_config_over_xdg_and_home() {
         // Given
@@ -353,7 +583,7 @@ mod tests {
         let fixture_path = PathBuf::from("tests/fixtures/loadout.toml");
 
         // When
-        let config = load_from(&fixture_path).unwrap();
+        let config = load_from(&fixture_path).unwrap().config;
 
         // Then
         assert_eq!(config.sources.skills.len(), 1);
@@ -465,6 +695,40 @@ project = ".bad/skills"
             .contains("Invalid target alias"));
     }
 
+    #[test]
+    fn should_return_error_for_crossref_pattern_without_skill_capture() {
+        // Given
+        use std::io::Write;
+        use tempfile::NamedTempFile;
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        writeln!(
+            temp_file,
+            r#"
+[sources]
+skills = []
+
+[global]
+targets = []
+skills = []
+
+[[crossref.patterns]]
+name = "pair-with"
+regex = "pair with ([a-z-]+)"
+"#
+        )
+        .unwrap();
+
+        // When
+        let result = load_from(temp_file.path());
+
+        // Then
+        let message = format!("{:#}", result.unwrap_err());
+        assert!(message.contains("Invalid [crossref] section"));
+        assert!(message.contains("pair-with"));
+        assert!(message.contains("(?P<skill>"));
+    }
+
     #[test]
     fn should_merge_builtin_aliases_when_custom_aliases_defined() {
         // Given
@@ -490,7 +754,7 @@ project = ".my-runner/skills"
         .unwrap();
 
         // When
-        let config = load_from(temp_file.path()).unwrap();
+        let config = load_from(temp_file.path()).unwrap().config;
 
         // Then
         assert!(config.target_aliases.contains_key("my_runner"));
@@ -499,6 +763,205 @@ project = ".my-runner/skills"
         assert!(config.target_aliases.contains_key("codex"));
     }
 
+    #[test]
+    fn should_apply_active_profile_overrides() {
+        // Given
+        use std::io::Write;
+        use tempfile::NamedTempFile;
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        writeln!(
+            temp_file,
+            r#"
+profile = "demo"
+
+[sources]
+skills = ["/skills/work"]
+
+[global]
+targets = ["claude_code", "codex"]
+skills = ["deploy"]
+
+[profiles.demo]
+sources = ["/skills/demo"]
+skills = ["showcase"]
+
+[profiles.personal]
+targets = ["codex"]
+"#
+        )
+        .unwrap();
+
+        // When
+        let config = load_from(temp_file.path()).unwrap().config;
+
+        // Then
+        assert_eq!(config.profile.as_deref(), Some("demo"));
+        assert_eq!(config.sources.skills, vec![PathBuf::from("/skills/demo")]);
+        assert_eq!(config.global.skills, vec!["showcase"]);
+        assert_eq!(config.global.targets, vec!["claude_code", "codex"]);
+    }
+
+    #[test]
+    fn should_return_error_for_unknown_profile() {
+        // Given
+        use std::io::Write;
+        use tempfile::NamedTempFile;
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        writeln!(
+            temp_file,
+            r#"
+profile = "travel"
+
+[sources]
+skills = []
+
+[global]
+targets = []
+skills = []
+
+[profiles.work]
+"#
+        )
+        .unwrap();
+
+        // When
+        let result = load_from(temp_file.path());
+
+        // Then
+        let message = format!("{:#}", result.unwrap_err());
+        assert!(message.contains("Unknown profile 'travel'. Available profiles: work"));
+    }
+
+    #[test]
+    fn should_apply_when_tables_and_variable_defaults() {
+        // Given
+        use std::io::Write;
+        use tempfile::NamedTempFile;
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        writeln!(
+            temp_file,
+            r#"
+[sources]
+skills = ["${{LOADOUT_TEST_UNSET_ROOT:-/opt}}/skills"]
+
+[global]
+targets = ["claude_code"]
+skills = ["deploy"]
+
+[when.host."{}".global]
+skills = ["container-setup"]
+
+[when.host."some-other-host".global]
+targets = []
+"#,
+            when::host_name()
+        )
+        .unwrap();
+
+        // When
+        let config = load_from(temp_file.path()).unwrap().config;
+
+        // Then
+        assert_eq!(config.sources.skills, vec![PathBuf::from("/opt/skills")]);
+        assert_eq!(config.global.skills, vec!["container-setup"]);
+        assert_eq!(config.global.targets, vec!["claude_code"]);
+    }
+
+    #[test]
+    fn should_return_error_naming_field_and_file_for_unresolved_variable() {
+        // Given
+        use std::io::Write;
+        use tempfile::NamedTempFile;
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        writeln!(
+            temp_file,
+            r#"
+[sources]
+skills = ["${{LOADOUT_TEST_UNSET_ROOT}}/skills"]
+
+[global]
+targets = []
+skills = []
+"#
+        )
+        .unwrap();
+
+        // When
+        let result = load_from(temp_file.path());
+
+        // Then
+        let message = format!("{:#}", result.unwrap_err());
+        assert!(message.contains(&format!("Invalid path in {}", temp_file.path().display())));
+        assert!(
+            message.contains("Unresolved variable ${LOADOUT_TEST_UNSET_ROOT} in sources.skills")
+        );
+    }
+
+    #[test]
+    fn should_merge_trusted_project_files_only() {
+        // Given
+        use tempfile::TempDir;
+
+        let temp = TempDir::new().unwrap();
+        let trusted = temp.path().join("trusted");
+        let untrusted = temp.path().join("untrusted");
+        let project_file = "skills = [\"release-notes\", \"deploy\"]\n\
+                            exclude = [\"tag:slow\"]\n\
+                            targets = [\"codex\"]\n\
+                            sources = [\".skills\"]\n";
+        for root in [&trusted, &untrusted] {
+            fs::create_dir_all(root).unwrap();
+            fs::write(root.join(local::PROJECT_FILE_NAME), project_file).unwrap();
+        }
+        let mut store = trust::TrustStore::default();
+        store.trust(&trusted, project_file);
+        trust::write(temp.path(), &store).unwrap();
+
+        let config_path = temp.path().join("loadout.toml");
+        fs::write(
+            &config_path,
+            format!(
+                r#"
+[sources]
+skills = ["skills"]
+
+[global]
+targets = ["claude_code"]
+skills = []
+
+[projects."{}"]
+skills = ["deploy"]
+inherit = false
+
+[projects."{}"]
+skills = []
+"#,
+                trusted.display(),
+                untrusted.display()
+            ),
+        )
+        .unwrap();
+
+        // When
+        let loaded = load_from(&config_path).unwrap();
+
+        // Then
+        let config = &loaded.config;
+        let project = &config.projects[&trusted];
+        assert_eq!(project.skills, vec!["deploy", "release-notes"]);
+        assert_eq!(project.exclude, vec!["tag:slow"]);
+        assert_eq!(project.targets, Some(vec!["codex".to_string()]));
+        assert!(!project.inherit);
+        assert_eq!(config.sources.skills, vec![temp.path().join("skills")]);
+        assert_eq!(project.sources, vec![trusted.join(".skills")]);
+        assert!(config.projects[&untrusted].skills.is_empty());
+        assert_eq!(loaded.untrusted, vec![untrusted]);
+    }
+
     #[test]
     fn should_return_error_when_config_file_missing() {
         // Given
@@ -544,10 +1007,13 @@ project = ".my-runner/skills"
                 skills: vec![PathBuf::from(OsString::from_vec(vec![
                     0x66, 0x6f, 0x80, 0x6f,
                 ]))],
+                templates: vec![],
             },
             global: Global {
                 targets: vec!["claude_code".to_string()],
                 skills: vec![],
+                exclude: vec![],
+                allow_drafts: false,
             },
             target_aliases: HashMap::from([(
                 "claude_code".to_string(),
@@ -558,6 +1024,10 @@ project = ".my-runner/skills"
             )]),
             projects: Default::default(),
             check: Default::default(),
+            crossref: Default::default(),
+            budget: Default::default(),
+            profile: None,
+            profiles: Default::default(),
         };
 
         // When
@@ -0,0 +1,185 @@
+//! JSON Schema for loadout.toml
+//!
+//! Generated from the config types, plus the `[when]` tables that are
+//! merged away before those types are deserialized. Objects are closed
+//! (`additionalProperties: false`) so editors flag the same unknown keys
+//! `loadout config validate` does.
+
+use serde_json::{json, Map, Value};
+
+use super::when::WHEN_KEY;
+use super::Config;
+
+/// JSON Schema describing `loadout.toml`
+pub fn schema() -> Value {
+    let mut schema = schemars::schema_for!(Config).to_value();
+    close_objects(&mut schema);
+
+    // Overrides take any part of the config and are merged over it, so no
+    // key is required at any depth
+    let defs = schema["$defs"].as_object().cloned().unwrap_or_default();
+    let mut properties = schema["properties"].clone();
+    let mut override_defs = Map::new();
+    make_optional(&mut properties, &defs, &mut override_defs);
+    if let Some(defs) = schema["$defs"].as_object_mut() {
+        defs.extend(override_defs);
+    }
+    let overrides = json!({
+        "type": "object",
+        "description": "Config values to merge over the rest of the file",
+        "properties": properties,
+        "additionalProperties": false,
+    });
+    schema["$defs"]["Overrides"] = overrides;
+    schema["properties"][WHEN_KEY] = json!({
+        "type": "object",
+        "description": "Overrides applied on matching machines",
+        "properties": {
+            "host": {
+                "type": "object",
+                "description": "Overrides keyed by hostname",
+                "additionalProperties": { "$ref": "#/$defs/Overrides" },
+            },
+            "env": {
+                "type": "object",
+                "description": "Overrides keyed by an environment variable that is set and non-empty",
+                "additionalProperties": { "$ref": "#/$defs/Overrides" },
+            },
+        },
+        "additionalProperties": false,
+    });
+    schema
+}
+
+/// Resolve a local `$ref` and unwrap `Option` unions to the schema of the value
+pub(crate) fn resolve<'a>(schema: &'a Value, root: &'a Value) -> &'a Value {
+    if let Some(name) = schema
+        .get("$ref")
+        .and_then(Value::as_str)
+        .and_then(|r| r.strip_prefix("#/$defs/"))
+    {
+        return resolve(&root["$defs"][name], root);
+    }
+    if let Some(variants) = schema.get("anyOf").and_then(Value::as_array) {
+        if let Some(value) = variants
+            .iter()
+            .find(|v| v.get("type") != Some(&json!("null")))
+        {
+            return resolve(value, root);
+        }
+    }
+    schema
+}
+
+/// Names of the properties an object schema allows
+pub(crate) fn property_names(schema: &Value) -> Vec<&str> {
+    schema
+        .get("properties")
+        .and_then(Value::as_object)
+        .map(|properties| properties.keys().map(String::as_str).collect())
+        .unwrap_or_default()
+}
+
+/// Remove `required` from a schema, following `$ref`s into copies of their
+/// definitions suffixed with `Override`
+///
+/// Arrays replace the value they override rather than merging into it, so
+/// array items keep their required keys.
+fn make_optional(
+    schema: &mut Value,
+    defs: &Map<String, Value>,
+    override_defs: &mut Map<String, Value>,
+) {
+    match schema {
+        Value::Object(object) => {
+            object.remove("required");
+            if let Some(Value::String(reference)) = object.get_mut("$ref") {
+                if let Some(name) = reference.strip_prefix("#/$defs/") {
+                    let override_name = format!("{name}Override");
+                    if !override_defs.contains_key(&override_name) {
+                        let mut def = defs[name].clone();
+                        make_optional(&mut def, defs, override_defs);
+                        if def == defs[name] {
+                            return;
+                        }
+                        override_defs.insert(override_name.clone(), def);
+                    }
+                    *reference = format!("#/$defs/{override_name}");
+                }
+            }
+            for (key, value) in object.iter_mut() {
+                match key.as_str() {
+                    "properties" => {
+                        if let Value::Object(properties) = value {
+                            properties
+                                .values_mut()
+                                .for_each(|property| make_optional(property, defs, override_defs));
+                        }
+                    }
+                    "items" | "default" => {}
+                    _ => make_optional(value, defs, override_defs),
+                }
+            }
+        }
+        Value::Array(items) => items
+            .iter_mut()
+            .for_each(|item| make_optional(item, defs, override_defs)),
+        _ => {}
+    }
+}
+
+fn close_objects(schema: &mut Value) {
+    match schema {
+        Value::Object(object) => {
+            if object.contains_key("properties") && !object.contains_key("additionalProperties") {
+                object.insert("additionalProperties".to_string(), Value::Bool(false));
+            }
+            object.values_mut().for_each(close_objects);
+        }
+        Value::Array(items) => items.iter_mut().for_each(close_objects),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_valid(config: &str) -> bool {
+        let table: toml::Table = toml::from_str(config).unwrap();
+        let instance = serde_json::to_value(table).unwrap();
+        jsonschema::validator_for(&schema())
+            .unwrap()
+            .is_valid(&instance)
+    }
+
+    const BASE: &str = "[sources]\nskills = [\"~/skills\"]\n\n[global]\ntargets = [\"claude_code\"]\nskills = []\n";
+
+    #[test]
+    fn should_accept_partial_when_overrides() {
+        // Given
+        let config = format!(
+            "{BASE}\n[when.env.CI.global]\ntargets = [\"codex\"]\n\n\
+             [when.host.\"build-box\".projects.\"/srv/app\"]\ntargets = [\"codex\"]\n\n\
+             [when.host.\"build-box\".target_aliases.codex]\nglobal = \"/srv/agents\"\n"
+        );
+
+        // When/Then
+        assert!(is_valid(BASE));
+        assert!(is_valid(&config));
+    }
+
+    #[test]
+    fn should_reject_incomplete_arrays_and_unknown_keys_in_overrides() {
+        // Given
+        let incomplete_pattern =
+            format!("{BASE}\n[[when.env.CI.crossref.patterns]]\nname = \"ticket\"\n");
+        let unknown_key = format!("{BASE}\n[when.env.CI.global]\ntarget = [\"codex\"]\n");
+        let missing_section = "[when.env.CI.global]\ntargets = [\"codex\"]\n";
+
+        // When/Then
+        assert!(!is_valid(&incomplete_pattern));
+        assert!(!is_valid(&unknown_key));
+        assert!(!is_valid(missing_section));
+    }
+}
@@ -0,0 +1,125 @@
+//! Allowlist of project-local config files
+//!
+//! A repository's `.loadout.toml` decides what gets linked into the agent's
+//! context, so it only applies once the user has trusted it. Trust is
+//! recorded against a SHA-256 of the file, and any edit requires trusting it
+//! again.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// File next to `loadout.toml` that records trusted project files
+pub const TRUST_FILE_NAME: &str = "trusted-projects.toml";
+
+/// Trusted project roots and the hash of the file that was trusted
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct TrustStore {
+    #[serde(default)]
+    pub projects: BTreeMap<String, String>,
+}
+
+impl TrustStore {
+    /// Whether `contents` is the file trusted for the project at `root`
+    pub fn is_trusted(&self, root: &Path, contents: &str) -> bool {
+        self.projects.get(&key(root)) == Some(&hash(contents))
+    }
+
+    /// Whether a different version of the project's file was trusted before
+    pub fn was_trusted(&self, root: &Path) -> bool {
+        self.projects.contains_key(&key(root))
+    }
+
+    pub fn trust(&mut self, root: &Path, contents: &str) {
+        self.projects.insert(key(root), hash(contents));
+    }
+
+    /// Forget a project; returns whether it was trusted
+    pub fn revoke(&mut self, root: &Path) -> bool {
+        self.projects.remove(&key(root)).is_some()
+    }
+}
+
+/// Path of the trust store for a config directory
+pub fn store_path(config_dir: &Path) -> PathBuf {
+    config_dir.join(TRUST_FILE_NAME)
+}
+
+/// Read the trust store, or an empty one if there is none
+pub fn read(config_dir: &Path) -> Result<TrustStore> {
+    let path = store_path(config_dir);
+    if !path.exists() {
+        return Ok(TrustStore::default());
+    }
+
+    let contents =
+        fs::read_to_string(&path).context(format!("Failed to read {}", path.display()))?;
+    toml::from_str(&contents).context(format!("Failed to parse {}", path.display()))
+}
+
+pub fn write(config_dir: &Path, store: &TrustStore) -> Result<()> {
+    let path = store_path(config_dir);
+    let contents = toml::to_string(store).context("Failed to serialize trusted projects")?;
+    fs::write(&path, contents).context(format!("Failed to write {}", path.display()))
+}
+
+/// Projects are keyed by canonical path so `./repo` and a symlink agree
+fn key(root: &Path) -> String {
+    fs::canonicalize(root)
+        .unwrap_or_else(|_| root.to_path_buf())
+        .display()
+        .to_string()
+}
+
+/// SHA-256 of the file, as lowercase hex
+fn hash(contents: &str) -> String {
+    Sha256::digest(contents.as_bytes())
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn should_require_trust_again_after_file_changes() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let root = temp.path().join("repo");
+        fs::create_dir_all(&root).unwrap();
+        let mut store = TrustStore::default();
+        store.trust(&root, "skills = [\"deploy\"]\n");
+        write(temp.path(), &store).unwrap();
+
+        // When
+        let store = read(temp.path()).unwrap();
+
+        // Then
+        assert!(store.is_trusted(&root, "skills = [\"deploy\"]\n"));
+        assert!(!store.is_trusted(&root, "skills = [\"deploy\", \"wipe\"]\n"));
+        assert!(store.was_trusted(&root));
+        assert!(!store.was_trusted(temp.path()));
+    }
+
+    #[test]
+    fn should_record_sha256_of_trusted_file() {
+        // Given
+        let mut store = TrustStore::default();
+
+        // When
+        store.trust(Path::new("/repo"), "abc");
+
+        // Then
+        assert_eq!(
+            store.projects["/repo"],
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}
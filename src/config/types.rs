@@ -3,10 +3,14 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::skill::crossref::CrossrefConfig;
+use crate::skill::description::DescriptionLintConfig;
+
 /// Complete configuration loaded from loadout.toml
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Config {
     /// Source directories containing skills
     pub sources: Sources,
@@ -25,36 +29,126 @@ pub struct Config {
     /// Check command configuration
     #[serde(default)]
     pub check: CheckConfig,
+
+    /// Cross-reference detection configuration
+    #[serde(default)]
+    pub crossref: CrossrefConfig,
+
+    /// Token budget limits
+    #[serde(default)]
+    pub budget: BudgetConfig,
+
+    /// Active profile, set by `loadout use`
+    #[serde(default)]
+    pub profile: Option<String>,
+
+    /// Named overrides of global skills, targets, and sources
+    #[serde(default)]
+    pub profiles: HashMap<String, Profile>,
 }
 
 /// Configuration for the check command
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CheckConfig {
     /// Patterns to suppress: "check-type:source:detail"
     /// e.g., "dangling:skill-format:related-skill"
     #[serde(default)]
     pub ignore: Vec<String>,
+
+    /// Description similarity (0.0 to 1.0) above which two skills sharing a
+    /// target are flagged as ambiguous triggers
+    #[serde(default = "default_overlap_threshold")]
+    pub overlap_threshold: f32,
+
+    /// Description quality lint settings
+    #[serde(default)]
+    pub description: DescriptionLintConfig,
+}
+
+impl Default for CheckConfig {
+    fn default() -> Self {
+        Self {
+            ignore: Vec::new(),
+            overlap_threshold: default_overlap_threshold(),
+            description: DescriptionLintConfig::default(),
+        }
+    }
+}
+
+/// Token budget limits, enforced by the check command
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct BudgetConfig {
+    /// Maximum tokens of names and descriptions loaded into each target
+    #[serde(default)]
+    pub max_target_tokens: Option<usize>,
+
+    /// Maximum tokens of a single skill's full SKILL.md
+    #[serde(default)]
+    pub max_skill_tokens: Option<usize>,
+
+    /// How check reports an exceeded limit (default: warning)
+    #[serde(default)]
+    pub severity: BudgetSeverity,
+}
+
+/// Severity of budget findings
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum BudgetSeverity {
+    #[default]
+    Warning,
+    Error,
 }
 
 /// Source directories configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Sources {
     /// List of directories to search for skills (in priority order)
     pub skills: Vec<PathBuf>,
+
+    /// Directories of named skill templates for `loadout new --template`
+    #[serde(default)]
+    pub templates: Vec<PathBuf>,
 }
 
 /// Global skill configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Global {
     /// Target aliases where skills will be symlinked
     pub targets: Vec<String>,
 
     /// Skills to enable globally
     pub skills: Vec<String>,
+
+    /// Skills never to enable, in any scope
+    #[serde(default)]
+    pub exclude: Vec<String>,
+
+    /// Allow skills with `status: draft` in global scope (default: false)
+    #[serde(default)]
+    pub allow_drafts: bool,
+}
+
+/// Overrides applied when a profile is active
+///
+/// Each field replaces the corresponding top-level list when set.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct Profile {
+    /// Replaces `[sources] skills`
+    #[serde(default)]
+    pub sources: Option<Vec<PathBuf>>,
+
+    /// Replaces `[global] targets`
+    #[serde(default)]
+    pub targets: Option<Vec<String>>,
+
+    /// Replaces `[global] skills`
+    #[serde(default)]
+    pub skills: Option<Vec<String>>,
 }
 
 /// Target paths for a runner alias
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct TargetAliasPaths {
     /// Global-scope target path for this alias
     pub global: PathBuf,
@@ -64,11 +158,20 @@ pub struct TargetAliasPaths {
 }
 
 /// Project-specific skill configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Project {
     /// Skills to enable for this project
     pub skills: Vec<String>,
 
+    /// Skills to leave out, including inherited global ones
+    #[serde(default)]
+    pub exclude: Vec<String>,
+
+    /// Skill directories for this project only, relative to the project
+    /// root and searched before `[sources] skills`
+    #[serde(default)]
+    pub sources: Vec<PathBuf>,
+
     /// Whether to include global skills (default: true)
     #[serde(default = "default_inherit")]
     pub inherit: bool,
@@ -108,6 +211,10 @@ fn default_inherit() -> bool {
     true
 }
 
+fn default_overlap_threshold() -> f32 {
+    0.6
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -275,4 +382,40 @@ mod tests {
         assert!(config.target_aliases.contains_key("my_runner"));
         assert_eq!(config.global.targets, vec!["my_runner".to_string()]);
     }
+
+    #[test]
+    fn should_deserialize_crossref_config() {
+        // Given
+        let toml = r#"
+            [sources]
+            skills = []
+
+            [global]
+            targets = []
+            skills = []
+
+            [crossref]
+            min_confidence = 0.6
+
+            [crossref.methods]
+            backtick_context = false
+
+            [[crossref.patterns]]
+            name = "hand-off"
+            regex = "hand off to (?P<skill>[a-z0-9-]+)"
+        "#;
+
+        // When
+        let config: Config = toml::from_str(toml).unwrap();
+
+        // Then
+        assert_eq!(config.crossref.min_confidence, 0.6);
+        assert_eq!(
+            config.crossref.methods.get("backtick_context"),
+            Some(&false)
+        );
+        assert_eq!(config.crossref.patterns.len(), 1);
+        assert_eq!(config.crossref.patterns[0].name, "hand-off");
+        assert_eq!(config.crossref.patterns[0].confidence, 0.5);
+    }
 }
@@ -0,0 +1,515 @@
+//! Whole-file validation of loadout.toml
+//!
+//! `load_from` stops at the first error and silently ignores keys it
+//! doesn't know. This reports every problem it can find, each with the
+//! line and column it points at:
+//!
+//! - keys the schema doesn't allow, with suggestions
+//! - invalid alias names and targets naming undefined aliases
+//! - sources and project directories that don't exist
+//! - variables that can't be resolved, and values of the wrong type
+//!
+//! Paths inside `[when]` tables are only checked for unknown keys and
+//! aliases, since they usually describe other machines.
+
+use std::fs;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde_json::Value;
+use toml_edit::{ImDocument, Item, TableLike};
+
+use super::schema::{self, property_names};
+use super::when::WHEN_KEY;
+use super::{default_target_aliases, Config};
+use crate::skill::suggest::{did_you_mean, suggestions};
+
+/// Line and column of a problem, both starting at 1
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// One problem found in the config file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Problem {
+    /// Where it is, when the problem points at a specific place
+    pub location: Option<Location>,
+    pub message: String,
+}
+
+/// Check the config file at `path` and return every problem found
+pub fn validate_file(path: &Path) -> Result<Vec<Problem>> {
+    let contents =
+        fs::read_to_string(path).context(format!("Failed to read {}", path.display()))?;
+    let config_dir = super::config_dir(path)?;
+
+    let doc = match ImDocument::parse(contents.as_str()) {
+        Ok(doc) => doc,
+        Err(e) => {
+            return Ok(vec![Problem {
+                location: e.span().map(|span| locate(&contents, span.start)),
+                message: e.message().trim_end().to_string(),
+            }]);
+        }
+    };
+
+    let schema = schema::schema();
+    let mut validator = Validator {
+        contents: &contents,
+        schema: &schema,
+        problems: Vec::new(),
+    };
+    validator.check_keys(doc.as_table(), &schema, &Section::root(), None);
+    validator.check_aliases(doc.as_table());
+    validator.check_paths(doc.as_table(), &config_dir);
+
+    if let Err(e) = toml::from_str::<Config>(&contents) {
+        validator.push(e.span(), e.message().to_string());
+    }
+
+    // Anything `load_from` rejects that the checks above don't cover
+    let mut problems = validator.problems;
+    if problems.is_empty() {
+        if let Err(e) = super::load_from(path) {
+            problems.push(Problem {
+                location: None,
+                message: format!("{:#}", e),
+            });
+        }
+    }
+
+    problems.sort_by_key(|p| p.location);
+    Ok(problems)
+}
+
+struct Validator<'a> {
+    contents: &'a str,
+    schema: &'a Value,
+    problems: Vec<Problem>,
+}
+
+impl Validator<'_> {
+    fn push(&mut self, span: Option<Range<usize>>, message: String) {
+        self.problems.push(Problem {
+            location: span.map(|span| locate(self.contents, span.start)),
+            message,
+        });
+    }
+
+    /// Report keys the schema doesn't allow, recursing into known ones
+    fn check_keys(
+        &mut self,
+        table: &dyn TableLike,
+        schema: &Value,
+        section: &Section,
+        span: Option<Range<usize>>,
+    ) {
+        let schema = schema::resolve(schema, self.schema);
+        let properties = schema.get("properties");
+        let additional = schema.get("additionalProperties");
+
+        for (key, item) in table.iter() {
+            let key_span = table
+                .get_key_value(key)
+                .and_then(|(key, _)| key.span())
+                .or_else(|| span.clone());
+            let child = section.child(key);
+
+            if let Some(property) = properties.and_then(|p| p.get(key)) {
+                self.check_item(item, property, &child, key_span);
+            } else if let Some(values) = additional.filter(|a| a.is_object()) {
+                self.check_item(item, values, &child, key_span);
+            } else if additional == Some(&Value::Bool(false)) {
+                let close = suggestions(key, property_names(schema));
+                self.push(
+                    key_span,
+                    format!(
+                        "Unknown key '{}' in {}{}",
+                        key,
+                        section.describe(),
+                        did_you_mean(&close)
+                    ),
+                );
+            }
+        }
+    }
+
+    fn check_item(
+        &mut self,
+        item: &Item,
+        schema: &Value,
+        section: &Section,
+        span: Option<Range<usize>>,
+    ) {
+        let schema = schema::resolve(schema, self.schema);
+        if let Some(table) = item.as_table_like() {
+            self.check_keys(table, schema, section, item.span().or(span));
+            return;
+        }
+
+        let Some(items) = schema.get("items") else {
+            return;
+        };
+        if let Some(tables) = item.as_array_of_tables() {
+            for table in tables.iter() {
+                self.check_keys(table, items, section, table.span().or(span.clone()));
+            }
+        } else if let Some(array) = item.as_array() {
+            for value in array.iter() {
+                if let Some(table) = value.as_inline_table() {
+                    self.check_keys(table, items, section, value.span().or(span.clone()));
+                }
+            }
+        }
+    }
+
+    /// Report invalid alias names and targets that name undefined aliases
+    fn check_aliases(&mut self, root: &dyn TableLike) {
+        let scopes = scopes(root);
+
+        let mut known: Vec<String> = default_target_aliases().into_keys().collect();
+        for (scope, _) in &scopes {
+            let Some(aliases) = table(*scope, "target_aliases") else {
+                continue;
+            };
+            for (alias, _) in aliases.iter() {
+                if super::is_valid_alias_name(alias) {
+                    known.push(alias.to_string());
+                } else {
+                    let span = aliases.get_key_value(alias).and_then(|(key, _)| key.span());
+                    self.push(
+                        span,
+                        format!(
+                            "Invalid target alias '{alias}'. Alias names must match ^[a-z0-9_]+$"
+                        ),
+                    );
+                }
+            }
+        }
+
+        for (scope, prefix) in &scopes {
+            let mut lists = Vec::new();
+            if let Some(targets) = table(*scope, "global").and_then(|g| g.get("targets")) {
+                lists.push((format!("{prefix}global.targets"), targets));
+            }
+            for section in ["projects", "profiles"] {
+                for (name, entry) in table(*scope, section).into_iter().flat_map(|t| t.iter()) {
+                    if let Some(targets) = entry.as_table_like().and_then(|e| e.get("targets")) {
+                        let field = format!("{prefix}{section}.{}.targets", quote_key(name));
+                        lists.push((field, targets));
+                    }
+                }
+            }
+
+            for (field, targets) in lists {
+                for value in targets.as_array().into_iter().flat_map(|a| a.iter()) {
+                    let Some(alias) = value.as_str() else {
+                        continue;
+                    };
+                    if known.iter().any(|k| k == alias) {
+                        continue;
+                    }
+                    let close = suggestions(alias, known.iter().map(String::as_str));
+                    self.push(
+                        value.span(),
+                        format!(
+                            "Unknown target alias '{alias}' in {field}{}",
+                            did_you_mean(&close)
+                        ),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Report unresolvable variables, missing sources, and missing projects
+    fn check_paths(&mut self, root: &dyn TableLike, config_dir: &Path) {
+        let mut sources = Vec::new();
+        if let Some(section) = table(root, "sources") {
+            for key in ["skills", "templates"] {
+                if let Some(item) = section.get(key) {
+                    sources.push((format!("sources.{key}"), item, config_dir.to_path_buf()));
+                }
+            }
+        }
+        for (name, profile) in table(root, "profiles").into_iter().flat_map(|t| t.iter()) {
+            if let Some(item) = profile.as_table_like().and_then(|p| p.get("sources")) {
+                sources.push((
+                    format!("profiles.{}.sources", quote_key(name)),
+                    item,
+                    config_dir.to_path_buf(),
+                ));
+            }
+        }
+
+        if let Some(projects) = table(root, "projects") {
+            for (key, project) in projects.iter() {
+                let field = format!("projects.{}", quote_key(key));
+                let span = projects.get_key_value(key).and_then(|(key, _)| key.span());
+                let Some(project_dir) = self.expand(key, config_dir, &field, span.clone()) else {
+                    continue;
+                };
+                if !project_dir.is_dir() {
+                    self.push(
+                        span,
+                        format!("Project directory {} does not exist", project_dir.display()),
+                    );
+                }
+                if let Some(item) = project.as_table_like().and_then(|p| p.get("sources")) {
+                    sources.push((format!("{field}.sources"), item, project_dir));
+                }
+            }
+        }
+
+        if let Some(aliases) = table(root, "target_aliases") {
+            for (alias, paths) in aliases.iter() {
+                let Some(paths) = paths.as_table_like() else {
+                    continue;
+                };
+                for key in ["global", "project"] {
+                    if let Some(value) = paths.get(key).and_then(|p| p.as_value()) {
+                        let field = format!("target_aliases.{alias}.{key}");
+                        if let Some(path) = value.as_str() {
+                            self.expand(path, config_dir, &field, value.span());
+                        }
+                    }
+                }
+            }
+        }
+
+        for (field, item, base) in sources {
+            for value in item.as_array().into_iter().flat_map(|a| a.iter()) {
+                let Some(path) = value.as_str() else {
+                    continue;
+                };
+                let Some(dir) = self.expand(path, &base, &field, value.span()) else {
+                    continue;
+                };
+                if !dir.is_dir() {
+                    self.push(
+                        value.span(),
+                        format!(
+                            "Source directory {} in {field} does not exist",
+                            dir.display()
+                        ),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Expand a path field, reporting why it can't be
+    fn expand(
+        &mut self,
+        path: &str,
+        base: &Path,
+        field: &str,
+        span: Option<Range<usize>>,
+    ) -> Option<PathBuf> {
+        match super::expand_config_path(Path::new(path), base, field) {
+            Ok(path) => Some(path),
+            Err(e) => {
+                self.push(span, e.to_string());
+                None
+            }
+        }
+    }
+}
+
+/// A table path such as `projects."/home/user/app"`, for messages
+struct Section(Vec<String>);
+
+impl Section {
+    fn root() -> Self {
+        Self(Vec::new())
+    }
+
+    fn child(&self, key: &str) -> Self {
+        let mut keys = self.0.clone();
+        keys.push(key.to_string());
+        Self(keys)
+    }
+
+    fn describe(&self) -> String {
+        if self.0.is_empty() {
+            return "the top level".to_string();
+        }
+        let keys: Vec<String> = self.0.iter().map(|key| quote_key(key)).collect();
+        format!("[{}]", keys.join("."))
+    }
+}
+
+/// The root table plus each `[when]` override table, with its key prefix
+fn scopes(root: &dyn TableLike) -> Vec<(&dyn TableLike, String)> {
+    let mut scopes = vec![(root, String::new())];
+    for (kind, conditions) in table(root, WHEN_KEY).into_iter().flat_map(|t| t.iter()) {
+        let Some(conditions) = conditions.as_table_like() else {
+            continue;
+        };
+        for (name, overrides) in conditions.iter() {
+            if let Some(overrides) = overrides.as_table_like() {
+                scopes.push((overrides, format!("{WHEN_KEY}.{kind}.{}.", quote_key(name))));
+            }
+        }
+    }
+    scopes
+}
+
+fn table<'a>(parent: &'a dyn TableLike, key: &str) -> Option<&'a dyn TableLike> {
+    parent.get(key).and_then(|item| item.as_table_like())
+}
+
+fn quote_key(key: &str) -> String {
+    let bare = !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    if bare {
+        key.to_string()
+    } else {
+        format!("\"{}\"", key.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+}
+
+/// Line and column of a byte offset
+fn locate(contents: &str, offset: usize) -> Location {
+    let before = &contents[..offset.min(contents.len())];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    Location {
+        line: before.matches('\n').count() + 1,
+        column: before[line_start..].chars().count() + 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn validate(temp: &TempDir, contents: &str) -> Vec<Problem> {
+        let path = temp.path().join("loadout.toml");
+        fs::write(&path, contents).unwrap();
+        validate_file(&path).unwrap()
+    }
+
+    fn at(line: usize, column: usize) -> Option<Location> {
+        Some(Location { line, column })
+    }
+
+    #[test]
+    fn should_report_every_problem_with_its_location() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        fs::create_dir_all(temp.path().join("skills")).unwrap();
+        fs::create_dir_all(temp.path().join("app")).unwrap();
+        let contents = r#"[sources]
+skills = ["skills", "missing-skills"]
+
+[global]
+targets = ["claude_code", "cladue_code"]
+skills = []
+
+[target_aliases.Bad]
+global = "~/bad"
+project = ".bad"
+
+[projects.app]
+skills = []
+inherits = false
+
+[projects.gone]
+skills = []
+
+[when.env.CI.global]
+targetz = ["codex"]
+"#;
+
+        // When
+        let problems = validate(&temp, contents);
+
+        // Then
+        let missing = temp.path().join("missing-skills");
+        let gone = temp.path().join("gone");
+        assert_eq!(
+            problems,
+            vec![
+                Problem {
+                    location: at(2, 21),
+                    message: format!(
+                        "Source directory {} in sources.skills does not exist",
+                        missing.display()
+                    ),
+                },
+                Problem {
+                    location: at(5, 27),
+                    message: "Unknown target alias 'cladue_code' in global.targets. \
+                              Did you mean 'claude_code'?"
+                        .to_string(),
+                },
+                Problem {
+                    location: at(8, 17),
+                    message: "Invalid target alias 'Bad'. Alias names must match ^[a-z0-9_]+$"
+                        .to_string(),
+                },
+                Problem {
+                    location: at(14, 1),
+                    message: "Unknown key 'inherits' in [projects.app]. Did you mean 'inherit'?"
+                        .to_string(),
+                },
+                Problem {
+                    location: at(16, 11),
+                    message: format!("Project directory {} does not exist", gone.display()),
+                },
+                Problem {
+                    location: at(20, 1),
+                    message: "Unknown key 'targetz' in [when.env.CI.global]. \
+                              Did you mean 'targets'?"
+                        .to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn should_report_syntax_and_type_errors() {
+        // Given
+        let temp = TempDir::new().unwrap();
+
+        // When
+        let syntax = validate(&temp, "[sources\nskills = []\n");
+        let types = validate(
+            &temp,
+            "[sources]\nskills = []\n\n[global]\ntargets = \"codex\"\nskills = []\n",
+        );
+
+        // Then
+        assert_eq!(syntax.len(), 1);
+        assert_eq!(syntax[0].location, at(1, 9));
+        assert_eq!(types.len(), 1);
+        assert_eq!(types[0].location, at(5, 11));
+        assert!(types[0].message.contains("invalid type: string \"codex\""));
+    }
+
+    #[test]
+    fn should_report_unresolved_variables() {
+        // Given
+        let temp = TempDir::new().unwrap();
+
+        // When
+        let problems = validate(
+            &temp,
+            "[sources]\nskills = [\"${LOADOUT_TEST_UNSET_ROOT}/skills\"]\n\n\
+             [global]\ntargets = []\nskills = []\n",
+        );
+
+        // Then
+        assert_eq!(problems.len(), 1);
+        assert_eq!(problems[0].location, at(2, 11));
+        assert!(problems[0]
+            .message
+            .starts_with("Unresolved variable ${LOADOUT_TEST_UNSET_ROOT} in sources.skills"));
+    }
+}
@@ -0,0 +1,156 @@
+//! Conditional `[when]` tables
+//!
+//! One `loadout.toml` is often shared between laptops and dev containers
+//! whose layouts differ. `[when.host."<name>"]` applies on a matching host
+//! and `[when.env.<VAR>]` when the variable is set and non-empty:
+//!
+//! ```toml
+//! [when.host."build-box".sources]
+//! skills = ["/srv/skills"]
+//!
+//! [when.env.CI.global]
+//! targets = ["codex"]
+//! ```
+//!
+//! Each holds any part of the config and is merged over it: tables merge
+//! key by key, anything else replaces the value. Host tables apply before
+//! env tables, each in name order.
+
+use anyhow::{bail, Result};
+use toml::{Table, Value};
+
+/// Top-level key holding the conditional tables
+pub const WHEN_KEY: &str = "when";
+
+/// Remove `[when]` from `table` and merge the entries that apply
+pub fn apply(table: &mut Table, host: &str, env: impl Fn(&str) -> Option<String>) -> Result<()> {
+    let Some(when) = table.remove(WHEN_KEY) else {
+        return Ok(());
+    };
+    let Value::Table(when) = when else {
+        bail!("[when] must be a table");
+    };
+
+    let mut host_tables = None;
+    let mut env_tables = None;
+    for (kind, conditions) in when {
+        let Value::Table(conditions) = conditions else {
+            bail!("when.{kind} must be a table");
+        };
+        match kind.as_str() {
+            "host" => host_tables = Some(conditions),
+            "env" => env_tables = Some(conditions),
+            _ => bail!("Unknown condition when.{kind}. Use when.host or when.env"),
+        }
+    }
+
+    for (name, overrides) in host_tables.into_iter().flatten() {
+        if host_matches(&name, host) {
+            merge(table, overrides_table(overrides, "host", &name)?);
+        }
+    }
+    for (name, overrides) in env_tables.into_iter().flatten() {
+        if env(&name).is_some_and(|value| !value.is_empty()) {
+            merge(table, overrides_table(overrides, "env", &name)?);
+        }
+    }
+    Ok(())
+}
+
+/// Name of this machine, as matched by `[when.host]`
+pub fn host_name() -> String {
+    gethostname::gethostname().to_string_lossy().into_owned()
+}
+
+/// Hosts match ignoring case, by full name or by the name before the first dot
+fn host_matches(name: &str, host: &str) -> bool {
+    let short = host.split('.').next().unwrap_or(host);
+    name.eq_ignore_ascii_case(host) || name.eq_ignore_ascii_case(short)
+}
+
+fn overrides_table(value: Value, kind: &str, name: &str) -> Result<Table> {
+    match value {
+        Value::Table(table) => Ok(table),
+        _ => bail!("when.{kind}.\"{name}\" must be a table of config overrides"),
+    }
+}
+
+fn merge(base: &mut Table, overrides: Table) {
+    for (key, value) in overrides {
+        match (base.get_mut(&key), value) {
+            (Some(Value::Table(existing)), Value::Table(value)) => merge(existing, value),
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"
+[sources]
+skills = ["~/skills"]
+
+[global]
+targets = ["claude_code"]
+skills = ["git-commit"]
+
+[when.host."build-box".sources]
+skills = ["/srv/skills"]
+
+[when.env.CI.global]
+targets = ["codex"]
+
+[when.env.UNSET.global]
+skills = []
+"#;
+
+    #[test]
+    fn should_merge_tables_for_matching_host_and_env() {
+        // Given
+        let mut table: Table = toml::from_str(CONFIG).unwrap();
+
+        // When
+        apply(&mut table, "Build-Box.local", |name| {
+            (name == "CI").then(|| "true".to_string())
+        })
+        .unwrap();
+
+        // Then
+        assert!(!table.contains_key(WHEN_KEY));
+        assert_eq!(table["sources"]["skills"][0].as_str(), Some("/srv/skills"));
+        assert_eq!(table["global"]["targets"][0].as_str(), Some("codex"));
+        assert_eq!(table["global"]["skills"][0].as_str(), Some("git-commit"));
+    }
+
+    #[test]
+    fn should_leave_config_alone_when_nothing_matches() {
+        // Given
+        let mut table: Table = toml::from_str(CONFIG).unwrap();
+
+        // When
+        apply(&mut table, "laptop", |_| Some(String::new())).unwrap();
+
+        // Then
+        assert_eq!(table["sources"]["skills"][0].as_str(), Some("~/skills"));
+        assert_eq!(table["global"]["targets"][0].as_str(), Some("claude_code"));
+    }
+
+    #[test]
+    fn should_reject_unknown_condition() {
+        // Given
+        let mut table: Table = toml::from_str("[when.os.linux]\nprofile = \"work\"\n").unwrap();
+
+        // When
+        let result = apply(&mut table, "laptop", |_| None);
+
+        // Then
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Unknown condition when.os"));
+    }
+}
@@ -12,6 +12,8 @@ use crate::skill::{CrossRef, Skill};
 pub enum EdgeKind {
     /// Detected from content cross-references
     CrossRef,
+    /// Written as an explicit markdown or wiki link
+    Link,
     /// Declared in pipeline after/before fields
     Pipeline,
 }
@@ -67,15 +69,23 @@ impl SkillGraph {
             name_to_node.insert(skill.clone(), node);
         }
 
-        // Add deduplicated edges from cross-references
-        for (source, refs) in crossrefs {
-            let source_node = name_to_node[source];
-            for r in refs {
-                let edge_key = (source.clone(), r.target.clone());
-                if !edge_set.contains(&edge_key) {
-                    if let Some(&target_node) = name_to_node.get(&r.target) {
-                        graph.add_edge(source_node, target_node, EdgeKind::CrossRef);
-                        edge_set.insert(edge_key);
+        // Add deduplicated edges from cross-references, explicit links first
+        // so an edge that is both linked and mentioned is styled as a link
+        for explicit in [true, false] {
+            for (source, refs) in crossrefs {
+                let source_node = name_to_node[source];
+                for r in refs.iter().filter(|r| r.method.is_link() == explicit) {
+                    let edge_key = (source.clone(), r.target.clone());
+                    if !edge_set.contains(&edge_key) {
+                        if let Some(&target_node) = name_to_node.get(&r.target) {
+                            let kind = if explicit {
+                                EdgeKind::Link
+                            } else {
+                                EdgeKind::CrossRef
+                            };
+                            graph.add_edge(source_node, target_node, kind);
+                            edge_set.insert(edge_key);
+                        }
                     }
                 }
             }
@@ -86,30 +96,26 @@ impl SkillGraph {
             if let Some(pipeline) = &skill.frontmatter.pipeline {
                 for stage in pipeline.values() {
                     // "after" means this skill depends on those skills
-                    if let Some(after) = &stage.after {
-                        for dep in after {
-                            let edge_key = (skill.name.clone(), dep.clone());
-                            if !edge_set.contains(&edge_key) {
-                                if let (Some(&source_node), Some(&target_node)) =
-                                    (name_to_node.get(&skill.name), name_to_node.get(dep))
-                                {
-                                    graph.add_edge(source_node, target_node, EdgeKind::Pipeline);
-                                    edge_set.insert(edge_key);
-                                }
+                    for dep in stage.after_skills() {
+                        let edge_key = (skill.name.clone(), dep.to_string());
+                        if !edge_set.contains(&edge_key) {
+                            if let (Some(&source_node), Some(&target_node)) =
+                                (name_to_node.get(&skill.name), name_to_node.get(dep))
+                            {
+                                graph.add_edge(source_node, target_node, EdgeKind::Pipeline);
+                                edge_set.insert(edge_key);
                             }
                         }
                     }
                     // "before" means those skills depend on this skill (reverse direction)
-                    if let Some(before) = &stage.before {
-                        for dep in before {
-                            let edge_key = (dep.clone(), skill.name.clone());
-                            if !edge_set.contains(&edge_key) {
-                                if let (Some(&source_node), Some(&target_node)) =
-                                    (name_to_node.get(dep), name_to_node.get(&skill.name))
-                                {
-                                    graph.add_edge(source_node, target_node, EdgeKind::Pipeline);
-                                    edge_set.insert(edge_key);
-                                }
+                    for dep in stage.before_skills() {
+                        let edge_key = (dep.to_string(), skill.name.clone());
+                        if !edge_set.contains(&edge_key) {
+                            if let (Some(&source_node), Some(&target_node)) =
+                                (name_to_node.get(dep), name_to_node.get(&skill.name))
+                            {
+                                graph.add_edge(source_node, target_node, EdgeKind::Pipeline);
+                                edge_set.insert(edge_key);
                             }
                         }
                     }
@@ -188,7 +194,13 @@ impl SkillGraph {
                 .map(|e| CrossRef {
                     target: self.graph[e.target()].clone(),
                     line: 0,
-                    method: crate::skill::DetectionMethod::XmlCrossref,
+                    column: 0,
+                    heading: None,
+                    method: match e.weight() {
+                        EdgeKind::Link => crate::skill::DetectionMethod::MarkdownLink,
+                        _ => crate::skill::DetectionMethod::XmlCrossref,
+                    },
+                    confidence: 1.0,
                 })
                 .collect();
             if !edges.is_empty() {
@@ -239,6 +251,7 @@ impl SkillGraph {
             let target = &self.graph[edge.target()];
             let style = match edge.weight() {
                 EdgeKind::CrossRef => "",
+                EdgeKind::Link => " [style=bold, color=darkgreen]",
                 EdgeKind::Pipeline => " [style=dashed, color=blue]",
             };
             output.push_str(&format!("  \"{}\" -> \"{}\"{};\n", source, target, style));
@@ -306,6 +319,7 @@ impl SkillGraph {
                 let target = &self.graph[edge.target()];
                 let kind = match edge.weight() {
                     EdgeKind::CrossRef => "crossref",
+                    EdgeKind::Link => "link",
                     EdgeKind::Pipeline => "pipeline",
                 };
                 edges.push(serde_json::json!({
@@ -340,6 +354,7 @@ impl SkillGraph {
 
             let arrow = match edge.weight() {
                 EdgeKind::CrossRef => "-->",
+                EdgeKind::Link => "==>",
                 EdgeKind::Pipeline => "-.->",
             };
             output.push_str(&format!(
@@ -461,7 +476,10 @@ mod tests {
         CrossRef {
             target: target.to_string(),
             line: 1,
+            column: 1,
+            heading: None,
             method: DetectionMethod::XmlCrossref,
+            confidence: 1.0,
         }
     }
 
@@ -587,6 +605,31 @@ mod tests {
         assert_eq!(line, "skill-a: skill-b");
     }
 
+    #[test]
+    fn should_style_explicit_link_edges() {
+        // Given: skill-a mentions skill-b and also links to it
+        let mut link = test_crossref("skill-b");
+        link.method = DetectionMethod::WikiLink;
+        let mut crossrefs = HashMap::new();
+        crossrefs.insert(
+            "skill-a".to_string(),
+            vec![test_crossref("skill-b"), link, test_crossref("skill-c")],
+        );
+
+        // When
+        let graph = SkillGraph::from_crossrefs(&crossrefs);
+
+        // Then: the linked edge wins, the mention-only edge stays plain
+        assert!(graph
+            .to_dot()
+            .contains("\"skill-a\" -> \"skill-b\" [style=bold, color=darkgreen]"));
+        assert!(graph.to_dot().contains("\"skill-a\" -> \"skill-c\";"));
+        assert!(graph
+            .to_mermaid()
+            .contains("skill_a[skill-a] ==> skill_b[skill-b]"));
+        assert!(graph.to_json().contains("\"kind\":\"link\""));
+    }
+
     #[test]
     fn should_include_pipeline_edges() {
         // Given: skills with pipeline after/before declarations
@@ -625,6 +668,10 @@ mod tests {
                         );
                         m
                     }),
+                    crossref: None,
+                    status: None,
+                    replaced_by: None,
+                    version: None,
                 },
             },
             Skill {
@@ -658,6 +705,10 @@ mod tests {
                         );
                         m
                     }),
+                    crossref: None,
+                    status: None,
+                    replaced_by: None,
+                    version: None,
                 },
             },
         ];
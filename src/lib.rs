@@ -9,4 +9,5 @@ pub mod config;
 pub mod graph;
 pub mod linker;
 pub mod paths;
+pub mod plan;
 pub mod skill;
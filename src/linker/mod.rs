@@ -6,6 +6,8 @@ use std::path::{Component, Path, PathBuf};
 use anyhow::{Context, Result};
 use thiserror::Error;
 
+pub mod state;
+
 const MARKER_FILE_NAME: &str = ".managed-by-loadout";
 
 /// Errors that can occur during linking operations
@@ -95,8 +97,8 @@ pub fn clean_target(target_dir: &Path) -> Result<Vec<PathBuf>> {
             let entry = entry?;
             let path = entry.path();
 
-            // Skip the marker file itself
-            if path.file_name().and_then(|n| n.to_str()) == Some(MARKER_FILE_NAME) {
+            // Skip the marker and state files
+            if is_loadout_file(&path) {
                 continue;
             }
 
@@ -108,8 +110,9 @@ pub fn clean_target(target_dir: &Path) -> Result<Vec<PathBuf>> {
         }
     }
 
-    // Remove marker file
+    // Remove marker and state files
     remove_marker(target_dir)?;
+    state::remove(target_dir)?;
 
     // Remove directory if it's empty
     if is_directory_empty(target_dir)? {
@@ -193,7 +196,7 @@ fn prune_target_impl(
             let entry = entry?;
             let path = entry.path();
 
-            if path.file_name().and_then(|n| n.to_str()) == Some(MARKER_FILE_NAME) {
+            if is_loadout_file(&path) {
                 continue;
             }
 
@@ -213,8 +216,9 @@ fn prune_target_impl(
         }
     }
 
-    if !dry_run && has_only_marker_or_is_empty(target_dir)? {
+    if !dry_run && has_only_loadout_files_or_is_empty(target_dir)? {
         remove_marker(target_dir)?;
+        state::remove(target_dir)?;
         if is_directory_empty(target_dir)? {
             fs::remove_dir(target_dir).context(format!(
                 "Failed to remove empty directory: {}",
@@ -303,14 +307,14 @@ fn is_directory_empty(dir: &Path) -> Result<bool> {
     Ok(entries.is_empty())
 }
 
-fn has_only_marker_or_is_empty(dir: &Path) -> Result<bool> {
+fn has_only_loadout_files_or_is_empty(dir: &Path) -> Result<bool> {
     if !dir.exists() || !dir.is_dir() {
         return Ok(false);
     }
 
     for entry in fs::read_dir(dir)? {
         let entry = entry?;
-        if entry.file_name().to_str() != Some(MARKER_FILE_NAME) {
+        if !is_loadout_file(&entry.path()) {
             return Ok(false);
         }
     }
@@ -318,6 +322,14 @@ fn has_only_marker_or_is_empty(dir: &Path) -> Result<bool> {
     Ok(true)
 }
 
+/// Whether a path is the marker or state file that loadout keeps in a target
+fn is_loadout_file(path: &Path) -> bool {
+    matches!(
+        path.file_name().and_then(|n| n.to_str()),
+        Some(MARKER_FILE_NAME | state::STATE_FILE_NAME)
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -461,6 +473,7 @@ mod tests {
 
         fs::create_dir(&skill_dir).unwrap();
         link_skill("my-skill", &skill_dir, &target_dir).unwrap();
+        state::write(&target_dir, &state::TargetState::default()).unwrap();
 
         // When
         clean_target(&target_dir).unwrap();
@@ -551,6 +564,7 @@ mod tests {
         let target_dir = temp.path().join("target");
         fs::create_dir_all(&source).unwrap();
         link_skill("stale-skill", &source, &target_dir).unwrap();
+        state::write(&target_dir, &state::TargetState::default()).unwrap();
         let keep = Vec::new();
 
         // When
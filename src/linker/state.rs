@@ -0,0 +1,190 @@
+//! Per-target record of what `install` linked
+//!
+//! Symlinks always show the current source, so the state file is the only
+//! place that remembers which version and content a target was installed
+//! with. `check` and `list --outdated` compare against it.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use walkdir::WalkDir;
+
+use crate::skill::is_not_hidden;
+
+pub const STATE_FILE_NAME: &str = ".loadout-state.toml";
+
+/// Version and content hash of one skill at install time
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstalledSkill {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    pub hash: String,
+}
+
+/// Contents of a target's state file
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct TargetState {
+    #[serde(default)]
+    pub skills: BTreeMap<String, InstalledSkill>,
+}
+
+impl InstalledSkill {
+    /// Snapshot a skill directory as it is now
+    pub fn snapshot(skill_dir: &Path, version: Option<&str>) -> Result<Self> {
+        Ok(Self {
+            version: version.map(str::to_string),
+            hash: content_hash(skill_dir)?,
+        })
+    }
+}
+
+/// Read the state file of a target, or an empty state if there is none
+pub fn read(target_dir: &Path) -> Result<TargetState> {
+    let state_path = target_dir.join(STATE_FILE_NAME);
+    if !state_path.exists() {
+        return Ok(TargetState::default());
+    }
+
+    let contents = fs::read_to_string(&state_path).context(format!(
+        "Failed to read state file: {}",
+        state_path.display()
+    ))?;
+    toml::from_str(&contents).context(format!(
+        "Failed to parse state file: {}",
+        state_path.display()
+    ))
+}
+
+/// Write the state file of a target, replacing any previous record
+pub fn write(target_dir: &Path, state: &TargetState) -> Result<()> {
+    let state_path = target_dir.join(STATE_FILE_NAME);
+    let contents = toml::to_string(state).context("Failed to serialize install state")?;
+    fs::write(&state_path, contents).context(format!(
+        "Failed to write state file: {}",
+        state_path.display()
+    ))
+}
+
+/// Remove the state file from a target directory
+pub(super) fn remove(target_dir: &Path) -> Result<()> {
+    let state_path = target_dir.join(STATE_FILE_NAME);
+
+    if state_path.exists() {
+        fs::remove_file(&state_path).context(format!(
+            "Failed to remove state file: {}",
+            state_path.display()
+        ))?;
+    }
+
+    Ok(())
+}
+
+/// SHA-256 over every file in a skill directory, as lowercase hex
+///
+/// Files are visited in path order and each contributes its relative path
+/// and contents, so renames count as changes too. Hidden files such as
+/// `.DS_Store` or editor swap files are skipped, as discovery skips them.
+pub fn content_hash(skill_dir: &Path) -> Result<String> {
+    let mut hasher = Sha256::new();
+    let walker = WalkDir::new(skill_dir)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(is_not_hidden);
+
+    for entry in walker {
+        let entry = entry.context(format!("Failed to walk {}", skill_dir.display()))?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+
+        let relative = entry.path().strip_prefix(skill_dir).unwrap_or(entry.path());
+        let contents =
+            fs::read(entry.path()).context(format!("Failed to read {}", entry.path().display()))?;
+
+        hasher.update(relative.to_string_lossy().as_bytes());
+        hasher.update([0]);
+        hasher.update(&contents);
+        hasher.update([0]);
+    }
+
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn should_round_trip_state_file() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let mut state = TargetState::default();
+        state.skills.insert(
+            "voice".to_string(),
+            InstalledSkill {
+                version: Some("1.2.0".to_string()),
+                hash: "0123456789abcdef".to_string(),
+            },
+        );
+
+        // When
+        write(temp.path(), &state).unwrap();
+        let read_back = read(temp.path()).unwrap();
+
+        // Then
+        assert_eq!(read_back.skills, state.skills);
+        assert!(read(&temp.path().join("missing"))
+            .unwrap()
+            .skills
+            .is_empty());
+    }
+
+    #[test]
+    fn should_change_content_hash_when_any_file_changes() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let skill_dir = temp.path().join("voice");
+        fs::create_dir_all(skill_dir.join("references")).unwrap();
+        fs::write(skill_dir.join("SKILL.md"), "body").unwrap();
+        fs::write(skill_dir.join("references/guide.md"), "guide").unwrap();
+        let before = content_hash(&skill_dir).unwrap();
+        assert_eq!(before, content_hash(&skill_dir).unwrap());
+
+        // When
+        fs::write(skill_dir.join("references/guide.md"), "guide v2").unwrap();
+        let after = content_hash(&skill_dir).unwrap();
+
+        // Then
+        assert_eq!(before.len(), 64);
+        assert_ne!(before, after);
+    }
+
+    #[test]
+    fn should_ignore_hidden_files_in_content_hash() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let skill_dir = temp.path().join("voice");
+        fs::create_dir_all(&skill_dir).unwrap();
+        fs::write(skill_dir.join("SKILL.md"), "body").unwrap();
+        let before = content_hash(&skill_dir).unwrap();
+
+        // When
+        fs::write(skill_dir.join(".DS_Store"), "finder").unwrap();
+        fs::write(skill_dir.join(".SKILL.md.swp"), "swap").unwrap();
+        fs::create_dir_all(skill_dir.join(".git")).unwrap();
+        fs::write(skill_dir.join(".git/HEAD"), "ref: refs/heads/main").unwrap();
+        let after = content_hash(&skill_dir).unwrap();
+
+        // Then
+        assert_eq!(before, after);
+    }
+}
@@ -1,6 +1,8 @@
-use anyhow::Result;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
 use clap::{Parser, Subcommand};
-use loadout::{commands, config};
+use loadout::{commands, config, paths};
 
 /// Loadout: Skill lifecycle management for AI agents
 #[derive(Parser, Debug)]
@@ -18,12 +20,18 @@ enum Commands {
         /// Show what would happen without making changes
         #[arg(long)]
         dry_run: bool,
+        /// Only global scope and the project enclosing the current directory
+        #[arg(long)]
+        here: bool,
     },
     /// Remove all managed symlinks from target directories
     Clean {
         /// Show what would happen without making changes
         #[arg(long)]
         dry_run: bool,
+        /// Only global scope and the project enclosing the current directory
+        #[arg(long)]
+        here: bool,
     },
     /// Check skill system health and report diagnostics
     Check {
@@ -33,7 +41,15 @@ enum Commands {
         /// Show suppressed findings alongside active ones
         #[arg(long)]
         verbose: bool,
+        /// Ignore cross-references scored below this confidence (0.0 to 1.0)
+        #[arg(long, value_parser = parse_confidence)]
+        min_confidence: Option<f32>,
+        /// Rewrite dangling references that have exactly one close match
+        #[arg(long)]
+        fix: bool,
     },
+    /// Estimate the tokens each target adds to an agent's context
+    Budget,
     /// Visualize skill dependency graph
     #[cfg(feature = "graph")]
     Graph {
@@ -46,6 +62,12 @@ enum Commands {
         /// Filter to skills with a specific tag
         #[arg(long)]
         tag: Option<String>,
+        /// Ignore cross-references scored below this confidence (0.0 to 1.0)
+        #[arg(long, value_parser = parse_confidence)]
+        min_confidence: Option<f32>,
+        /// Include the skills in this project's own sources
+        #[arg(long)]
+        project: Option<PathBuf>,
     },
     /// List enabled skills per scope
     List {
@@ -70,12 +92,81 @@ enum Commands {
         /// Show a specific pipeline in stage order
         #[arg(long)]
         pipeline: Option<String>,
+        /// Group skills by lifecycle status (stable, draft, deprecated)
+        #[arg(long)]
+        status: bool,
+        /// Show installed skills whose source version has changed
+        #[arg(long)]
+        outdated: bool,
+        /// Show the skills that apply in the current directory, and why
+        #[arg(long)]
+        active: bool,
+        /// Only global scope and the project enclosing the current directory
+        #[arg(long)]
+        here: bool,
     },
     /// Validate SKILL.md files
     Validate {
         /// Skill name or directory path (validates all if not specified)
         target: Option<String>,
     },
+    /// Switch the active profile and reinstall, or list profiles
+    Use {
+        /// Profile to activate, from [profiles.<name>]
+        profile: Option<String>,
+        /// Go back to the top-level settings
+        #[arg(long, conflicts_with = "profile")]
+        clear: bool,
+    },
+    /// Review a project's .loadout.toml and allow install to apply it
+    Trust {
+        /// Project root (default: the project enclosing the current directory)
+        path: Option<PathBuf>,
+        /// Trust without showing the file and asking
+        #[arg(long, short)]
+        yes: bool,
+        /// Stop trusting the project's file
+        #[arg(long, conflicts_with = "yes")]
+        revoke: bool,
+    },
+    /// Work with pipelines declared in skill frontmatter
+    Pipeline {
+        #[command(subcommand)]
+        command: PipelineCommand,
+    },
+    /// Check loadout.toml or print its schema
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommand,
+    },
+    /// Rename a skill and rewrite every reference to it
+    Rename {
+        /// Current skill name
+        old: String,
+        /// New skill name (lowercase-with-hyphens)
+        new: String,
+        /// Show the changes without writing them
+        #[arg(long)]
+        dry_run: bool,
+        /// Leave a stub skill at the old name that points to the new one
+        #[arg(long)]
+        redirect: bool,
+    },
+    /// Rank enabled skills against a prompt to see which one it would trigger
+    Route {
+        /// Sample request to route
+        #[arg(required_unless_present = "suite")]
+        prompt: Option<String>,
+        /// Rank the skills enabled for this project instead of global scope
+        #[arg(long)]
+        project: Option<PathBuf>,
+        /// Number of ranked skills to show
+        #[arg(long, default_value_t = 5)]
+        top: usize,
+        /// Check the expected top skills for each prompt in a TOML suite file
+        #[arg(long, conflicts_with = "prompt")]
+        suite: Option<PathBuf>,
+    },
     /// Create a new skill from template
     New {
         /// Skill name (lowercase-with-hyphens)
@@ -83,22 +174,158 @@ enum Commands {
         /// Skill description
         #[arg(short, long)]
         description: Option<String>,
+        /// Template to render, from [sources] templates (default: "default")
+        #[arg(long)]
+        template: Option<String>,
+        /// Skill source to create in: a 1-based index or a configured path
+        #[arg(long)]
+        source: Option<String>,
+        /// Tag to add (repeatable)
+        #[arg(long = "tag")]
+        tags: Vec<String>,
+        /// Pipeline to place the skill in
+        #[arg(long, requires_all = ["stage", "order"])]
+        pipeline: Option<String>,
+        /// Stage label within the pipeline
+        #[arg(long, requires = "pipeline")]
+        stage: Option<String>,
+        /// Position within the pipeline
+        #[arg(long, requires = "pipeline")]
+        order: Option<u32>,
+        /// Skill this one runs after; it gets a matching `before` (repeatable)
+        #[arg(long, requires = "pipeline")]
+        after: Vec<String>,
+        /// Add the skill to loadout.toml and install it
+        #[arg(long)]
+        enable: bool,
+        /// Enable in this project instead of global scope
+        #[arg(long, requires = "enable")]
+        project: Option<PathBuf>,
     },
 }
 
+#[derive(Subcommand, Debug)]
+enum PipelineCommand {
+    /// Print a pipeline's stages in order as a runnable playbook
+    Export {
+        /// Pipeline name
+        name: String,
+        /// Output format: markdown, json, yaml
+        #[arg(long, default_value = "markdown")]
+        format: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum ConfigCommand {
+    /// Report every problem in loadout.toml with its line and column
+    Validate,
+    /// Print a JSON Schema for loadout.toml, for editor completion
+    Schema,
+}
+
+fn parse_confidence(value: &str) -> Result<f32, String> {
+    let confidence: f32 = value
+        .parse()
+        .map_err(|_| format!("'{}' is not a number", value))?;
+    if !(0.0..=1.0).contains(&confidence) {
+        return Err(format!("{} is not between 0.0 and 1.0", confidence));
+    }
+    Ok(confidence)
+}
+
+/// Narrow the config to global scope and the project enclosing the
+/// current directory
+fn scope_here(
+    config: &config::Config,
+    untrusted: &mut Vec<PathBuf>,
+) -> Result<paths::ProjectScope> {
+    let root = project_here(config)?;
+    let mut scoped = paths::scope_to_project(config, &root);
+    include_project_file(&mut scoped.config, &root, untrusted)?;
+    if !scoped.config.projects.contains_key(&root) {
+        eprintln!(
+            "Note: {} has no [projects] entry; only global skills apply",
+            root.display()
+        );
+    }
+    Ok(scoped)
+}
+
+/// Root of the project enclosing the current directory
+fn project_here(config: &config::Config) -> Result<PathBuf> {
+    let cwd = std::env::current_dir().context("Failed to resolve current working directory")?;
+    paths::detect_project(config, &cwd)
+        .context("Not inside a configured project or a git repository")
+}
+
+/// Apply the `.loadout.toml` of a project missing from `[projects]`;
+/// configured projects had theirs applied at load
+fn include_project_file(
+    config: &mut config::Config,
+    root: &std::path::Path,
+    untrusted: &mut Vec<PathBuf>,
+) -> Result<()> {
+    if config.projects.contains_key(root) {
+        return Ok(());
+    }
+    let status = config::load_project_file(config, root, &config::config_path()?)?;
+    if status == config::local::ProjectFileStatus::Untrusted {
+        untrusted.push(root.to_path_buf());
+    }
+    Ok(())
+}
+
 fn main() -> Result<()> {
     let cli = Cli::parse();
 
-    let config = config::load()?;
+    // Switching profiles edits the file directly, so it works even when the
+    // active profile no longer loads
+    if let Commands::Use { profile, clear } = &cli.command {
+        if profile.is_some() || *clear {
+            let config_path = config::config_path()?;
+            return commands::use_profile(&config_path, profile.as_deref());
+        }
+    }
+
+    // These inspect the config file itself, so they run even when it doesn't load
+    if let Commands::Config { command } = &cli.command {
+        return match command {
+            ConfigCommand::Validate => commands::validate_config(&config::config_path()?),
+            ConfigCommand::Schema => commands::print_schema(),
+        };
+    }
+
+    let config::Loaded {
+        config,
+        mut untrusted,
+    } = config::load()?;
 
     match cli.command {
-        Commands::Install { dry_run } => {
-            commands::install(&config, dry_run)?;
+        Commands::Install { dry_run, here } => {
+            if here {
+                let scoped = scope_here(&config, &mut untrusted)?;
+                commands::trust::warn_untrusted(&untrusted);
+                commands::install::install_scoped(&scoped.config, &scoped.out_of_scope, dry_run)?;
+            } else {
+                commands::trust::warn_untrusted(&untrusted);
+                commands::install(&config, dry_run)?;
+            }
         }
-        Commands::Clean { dry_run } => {
-            commands::clean(&config, dry_run)?;
+        Commands::Clean { dry_run, here } => {
+            if here {
+                let scoped = scope_here(&config, &mut untrusted)?;
+                commands::clean::clean_scoped(&scoped.config, &scoped.out_of_scope, dry_run)?;
+            } else {
+                commands::clean(&config, dry_run)?;
+            }
         }
-        Commands::Check { severity, verbose } => {
+        Commands::Check {
+            severity,
+            verbose,
+            min_confidence,
+            fix,
+        } => {
             let filter = severity
                 .as_deref()
                 .map(|s| match s.to_lowercase().as_str() {
@@ -114,15 +341,25 @@ fn main() -> Result<()> {
                     }
                 });
 
-            let findings = commands::check(&config, filter, verbose)?;
+            if fix {
+                let fixes = commands::check::fix_dangling_references(&config, min_confidence)?;
+                commands::check::print_fixes(&fixes);
+            }
+
+            let findings = commands::check(&config, filter, verbose, min_confidence)?;
             commands::print_check_findings(&findings);
             std::process::exit(commands::check_exit_code(&findings));
         }
+        Commands::Budget => {
+            commands::budget(&config)?;
+        }
         #[cfg(feature = "graph")]
         Commands::Graph {
             format,
             pipeline,
             tag,
+            min_confidence,
+            project,
         } => {
             let output_format = commands::graph::OutputFormat::parse_format(&format)
                 .unwrap_or_else(|| {
@@ -141,7 +378,13 @@ fn main() -> Result<()> {
                 commands::graph::GraphFilter::None
             };
 
-            commands::graph(&config, output_format, filter)?;
+            commands::graph(
+                &config,
+                output_format,
+                filter,
+                min_confidence,
+                project.as_deref(),
+            )?;
         }
         Commands::List {
             groups,
@@ -151,8 +394,24 @@ fn main() -> Result<()> {
             tag,
             pipelines,
             pipeline,
+            status,
+            outdated,
+            active,
+            here,
         } => {
-            let mode = if groups {
+            let mut config = if here {
+                scope_here(&config, &mut untrusted)?.config
+            } else {
+                config
+            };
+            if active {
+                if let Ok(root) = project_here(&config) {
+                    include_project_file(&mut config, &root, &mut untrusted)?;
+                }
+            }
+            let mode = if active {
+                commands::list::ListMode::Active
+            } else if groups {
                 commands::list::ListMode::Groups
             } else if let Some(skill_name) = refs {
                 commands::list::ListMode::Refs(skill_name)
@@ -166,17 +425,102 @@ fn main() -> Result<()> {
                 commands::list::ListMode::Pipelines
             } else if let Some(pipeline_name) = pipeline {
                 commands::list::ListMode::Pipeline(pipeline_name)
+            } else if status {
+                commands::list::ListMode::Status
+            } else if outdated {
+                commands::list::ListMode::Outdated
             } else {
                 commands::list::ListMode::Default
             };
 
+            if matches!(mode, commands::list::ListMode::Default) {
+                commands::trust::warn_untrusted(&untrusted);
+            }
             commands::list(&config, mode)?;
         }
         Commands::Validate { target } => {
             commands::validate(&config, target)?;
         }
-        Commands::New { name, description } => {
-            commands::new(&config, name, description)?;
+        Commands::Use { .. } => {
+            commands::list_profiles(&config)?;
+        }
+        Commands::Trust { path, yes, revoke } => {
+            let root = match path {
+                Some(path) => std::env::current_dir()
+                    .context("Failed to resolve current working directory")?
+                    .join(path),
+                None => project_here(&config)?,
+            };
+            let config_path = config::config_path()?;
+            if revoke {
+                commands::trust::revoke(&config_path, &root)?;
+            } else {
+                commands::trust(&config_path, &root, yes)?;
+            }
+        }
+        Commands::Config { .. } => unreachable!("handled before loading the config"),
+        Commands::Pipeline { command } => match command {
+            PipelineCommand::Export { name, format } => {
+                let export_format = commands::pipeline::ExportFormat::parse_format(&format)
+                    .unwrap_or_else(|| {
+                        eprintln!(
+                            "Invalid format: {}. Valid values: markdown, json, yaml",
+                            format
+                        );
+                        std::process::exit(1);
+                    });
+                commands::pipeline::export(&config, &name, export_format)?;
+            }
+        },
+        Commands::Rename {
+            old,
+            new,
+            dry_run,
+            redirect,
+        } => {
+            let config_path = config::config_path()?;
+            commands::rename(&config, &config_path, &old, &new, dry_run, redirect)?;
+        }
+        Commands::Route {
+            prompt,
+            project,
+            top,
+            suite,
+        } => match (suite, prompt) {
+            (Some(suite), _) => commands::route_suite(&config, &suite, project.as_deref())?,
+            (None, Some(prompt)) => commands::route(&config, &prompt, project.as_deref(), top)?,
+            (None, None) => unreachable!("clap requires a prompt unless --suite is given"),
+        },
+        Commands::New {
+            name,
+            description,
+            template,
+            source,
+            tags,
+            pipeline,
+            stage,
+            order,
+            after,
+            enable,
+            project,
+        } => {
+            let pipeline = pipeline.map(|name| commands::new::PipelinePlacement {
+                name,
+                stage: stage.unwrap_or_default(),
+                order: order.unwrap_or_default(),
+                after,
+            });
+            let options = commands::new::NewOptions {
+                description,
+                template,
+                source,
+                tags,
+                pipeline,
+                enable,
+                project,
+            };
+            let config_path = config::config_path()?;
+            commands::new(&config, &config_path, &name, options)?;
         }
     }
 
@@ -1,5 +1,6 @@
 //! Shared path policies and helpers.
 
+use std::fs;
 use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
@@ -39,27 +40,95 @@ pub fn project_targets(
             )
         })?;
 
-        let target = if paths.project.is_relative() {
-            project_path.join(&paths.project)
-        } else {
-            paths.project.clone()
-        };
-
-        resolved.push(target);
+        resolved.push(project_target(project_path, &paths.project));
     }
 
     Ok(resolved)
 }
 
+/// A project-relative target path resolved against the project root
+pub fn project_target(project_path: &Path, target: &Path) -> PathBuf {
+    if target.is_relative() {
+        project_path.join(target)
+    } else {
+        target.to_path_buf()
+    }
+}
+
+/// Find the project enclosing `dir`
+///
+/// The nearest ancestor that is a `[projects]` key wins; failing that, the
+/// nearest git root. A configured project is preferred over a closer git
+/// root so a key covering several repositories still applies inside each.
+/// Returns the path as written in `config.projects` when configured.
+pub fn detect_project(config: &Config, dir: &Path) -> Option<PathBuf> {
+    let canonical = |path: &Path| fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    let dir = canonical(dir);
+
+    let configured = dir.ancestors().find_map(|ancestor| {
+        config
+            .projects
+            .keys()
+            .find(|key| canonical(key) == ancestor)
+            .cloned()
+    });
+
+    configured.or_else(|| {
+        dir.ancestors()
+            .find(|ancestor| ancestor.join(".git").exists())
+            .map(Path::to_path_buf)
+    })
+}
+
+/// A config narrowed to one project by `--here`
+#[derive(Debug)]
+pub struct ProjectScope {
+    pub config: Config,
+    /// Targets that a removed project also resolves to. Reconciling them
+    /// with this project's skills alone would prune the other project's
+    /// links, so `install` and `clean` leave them untouched.
+    pub out_of_scope: Vec<PathBuf>,
+}
+
+/// A copy of `config` with every project except `root` removed
+///
+/// Commands run on the result touch global targets and that project's
+/// targets only.
+pub fn scope_to_project(config: &Config, root: &Path) -> ProjectScope {
+    let mut scoped = config.clone();
+    scoped.projects.retain(|path, _| path == root);
+    let mut out_of_scope: Vec<PathBuf> = config
+        .projects
+        .keys()
+        .filter(|path| *path != root)
+        .flat_map(|path| {
+            config
+                .target_aliases
+                .values()
+                .map(move |paths| project_target(path, &paths.project))
+        })
+        .collect();
+    out_of_scope.sort();
+    out_of_scope.dedup();
+    ProjectScope {
+        config: scoped,
+        out_of_scope,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::config::{CheckConfig, Global, Sources, TargetAliasPaths};
     use std::collections::HashMap;
+    use tempfile::TempDir;
 
     fn test_config() -> Config {
         Config {
-            sources: Sources { skills: vec![] },
+            sources: Sources {
+                skills: vec![],
+                templates: vec![],
+            },
             global: Global {
                 targets: vec![
                     "claude_code".to_string(),
@@ -67,6 +136,8 @@ mod tests {
                     "codex".to_string(),
                 ],
                 skills: vec![],
+                exclude: vec![],
+                allow_drafts: false,
             },
             target_aliases: HashMap::from([
                 (
@@ -93,6 +164,10 @@ mod tests {
             ]),
             projects: HashMap::new(),
             check: CheckConfig::default(),
+            crossref: Default::default(),
+            budget: Default::default(),
+            profile: None,
+            profiles: Default::default(),
         }
     }
 
@@ -121,6 +196,8 @@ mod tests {
         let project_path = PathBuf::from("/repo");
         let project = Project {
             skills: vec![],
+            exclude: vec![],
+            sources: vec![],
             inherit: true,
             targets: None,
         };
@@ -142,6 +219,8 @@ mod tests {
         let project_path = PathBuf::from("/repo");
         let project = Project {
             skills: vec![],
+            exclude: vec![],
+            sources: vec![],
             inherit: true,
             targets: Some(vec!["claude_code".to_string(), "codex".to_string()]),
         };
@@ -162,6 +241,8 @@ mod tests {
         let project_path = PathBuf::from("/repo");
         let project = Project {
             skills: vec![],
+            exclude: vec![],
+            sources: vec![],
             inherit: true,
             targets: Some(vec![]),
         };
@@ -187,6 +268,8 @@ mod tests {
         let project_path = PathBuf::from("/repo");
         let project = Project {
             skills: vec![],
+            exclude: vec![],
+            sources: vec![],
             inherit: true,
             targets: Some(vec!["custom".to_string()]),
         };
@@ -197,4 +280,56 @@ mod tests {
         // Then
         assert_eq!(targets, vec![PathBuf::from("/absolute/project/custom")]);
     }
+
+    #[test]
+    fn should_detect_configured_project_before_nearer_git_root() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let workspace = temp.path().join("work");
+        let repo = workspace.join("repo");
+        let other = temp.path().join("other");
+        fs::create_dir_all(repo.join(".git")).unwrap();
+        fs::create_dir_all(repo.join("src")).unwrap();
+        fs::create_dir_all(other.join(".git")).unwrap();
+        fs::create_dir_all(other.join("docs")).unwrap();
+        let mut config = test_config();
+        config.projects.insert(
+            workspace.clone(),
+            Project {
+                skills: vec![],
+                exclude: vec![],
+                sources: vec![],
+                inherit: true,
+                targets: None,
+            },
+        );
+        config.projects.insert(
+            temp.path().join("elsewhere"),
+            Project {
+                skills: vec![],
+                exclude: vec![],
+                sources: vec![],
+                inherit: true,
+                targets: None,
+            },
+        );
+
+        // When
+        let in_workspace = detect_project(&config, &repo.join("src"));
+        let in_git_root = detect_project(&config, &other.join("docs"));
+        let outside = detect_project(&config, Path::new("/"));
+        let scoped = scope_to_project(&config, &workspace);
+
+        // Then
+        assert_eq!(in_workspace, Some(workspace.clone()));
+        assert_eq!(
+            in_git_root.map(|p| fs::canonicalize(p).unwrap()),
+            Some(fs::canonicalize(&other).unwrap())
+        );
+        assert_eq!(outside, None);
+        assert_eq!(
+            scoped.config.projects.keys().collect::<Vec<_>>(),
+            vec![&workspace]
+        );
+    }
 }
@@ -0,0 +1,793 @@
+//! Install plan: which skills land in which target directory
+//!
+//! Skill lists in `loadout.toml` hold skill names and selectors:
+//! `pipeline:<name>` for every skill in a pipeline, `tag:<name>` for every
+//! skill with a tag, and glob patterns such as `design-*`. Selectors are
+//! expanded against the discovered skills when the plan is built, so a new
+//! pipeline stage is picked up without editing the config. `exclude` lists
+//! take the same entries and subtract from what was selected.
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+use crate::config::{Config, Project};
+use crate::paths;
+use crate::skill::{self, Skill};
+
+/// Skill list prefix selecting every skill in a pipeline
+pub const PIPELINE_SELECTOR: &str = "pipeline:";
+
+/// Skill list prefix selecting every skill with a tag
+pub const TAG_SELECTOR: &str = "tag:";
+
+/// Skills destined for one target directory
+#[derive(Debug)]
+pub struct TargetPlan {
+    pub target: PathBuf,
+    pub skills: Vec<String>,
+    /// Selector that brought in each skill not listed by name
+    pub via: BTreeMap<String, String>,
+    /// Planned skills found in a project's own sources, which take
+    /// precedence over global sources for this target
+    pub local_skills: BTreeMap<String, Skill>,
+}
+
+/// Every target directory with the skills it should hold
+#[derive(Debug)]
+pub struct InstallPlan {
+    pub targets: Vec<TargetPlan>,
+    /// Targets left out because they are in `out_of_scope`
+    pub skipped: Vec<PathBuf>,
+    /// Selectors that matched no discovered skill
+    pub unmatched: Vec<UnmatchedSelector>,
+}
+
+/// A selector in a skill list that matched nothing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnmatchedSelector {
+    /// "global" or the project path
+    pub scope: String,
+    pub selector: String,
+}
+
+/// A skill chosen by a skill list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectedSkill {
+    pub name: String,
+    /// Selector or pattern that matched it, or `None` when listed by name
+    pub via: Option<String>,
+    /// Whether it came from the global list through `inherit`
+    pub inherited: bool,
+}
+
+/// A skill an `exclude` rule removed from a skill list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExcludedSkill {
+    pub name: String,
+    /// The `exclude` entry that matched it
+    pub rule: String,
+    /// Whether the rule is in `[global] exclude` rather than the project's
+    pub global: bool,
+    /// Whether the list named the skill itself, rather than inheriting it
+    /// or matching it with a selector
+    pub listed: bool,
+}
+
+/// A skill list with its selectors expanded
+#[derive(Debug, Default)]
+pub struct Selection {
+    /// Selected skills by name, without duplicates
+    pub skills: Vec<SelectedSkill>,
+    /// Selectors that matched no skill
+    pub unmatched: Vec<String>,
+    /// Skills selected but then removed by an `exclude` rule
+    pub excluded: Vec<ExcludedSkill>,
+}
+
+impl Selection {
+    /// Names of the selected skills
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.skills.iter().map(|s| s.name.as_str())
+    }
+
+    /// Move skills matching one of `rules` into `excluded`
+    fn exclude(&mut self, rules: &[String], skills: &[Skill], global: bool) {
+        if rules.is_empty() {
+            return;
+        }
+        let matched: Vec<(&String, Option<Vec<&str>>)> = rules
+            .iter()
+            .map(|rule| (rule, matching(rule, skills)))
+            .collect();
+
+        self.skills.retain(|selected| {
+            let rule = matched.iter().find(|(rule, names)| match names {
+                Some(names) => names.contains(&selected.name.as_str()),
+                None => **rule == selected.name,
+            });
+            match rule {
+                Some((rule, _)) => {
+                    self.excluded.push(ExcludedSkill {
+                        name: selected.name.clone(),
+                        rule: rule.to_string(),
+                        global,
+                        listed: selected.via.is_none() && !selected.inherited,
+                    });
+                    false
+                }
+                None => true,
+            }
+        });
+    }
+}
+
+/// Whether a skill list entry is a selector or pattern rather than a skill name
+pub fn is_selector(entry: &str) -> bool {
+    entry.starts_with(PIPELINE_SELECTOR) || entry.starts_with(TAG_SELECTOR) || is_glob(entry)
+}
+
+/// Whether a skill list entry is a glob pattern such as `design-*`
+pub fn is_glob(entry: &str) -> bool {
+    entry.contains(['*', '?'])
+}
+
+/// Match a name against a glob pattern, where `*` matches any run of
+/// characters and `?` exactly one
+pub fn glob_matches(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    // Position after the last `*`, and the name position it was tried at
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                p += 1;
+                backtrack = Some((p, n));
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star_p, star_n)) => {
+                    p = star_p;
+                    n = star_n + 1;
+                    backtrack = Some((star_p, star_n + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Names of the skills a selector or pattern matches, or `None` for a
+/// plain skill name
+fn matching<'a>(entry: &str, skills: &'a [Skill]) -> Option<Vec<&'a str>> {
+    let mut matched: Vec<&str> = if let Some(pipeline) = entry.strip_prefix(PIPELINE_SELECTOR) {
+        skills
+            .iter()
+            .filter(|s| {
+                s.frontmatter
+                    .pipeline
+                    .as_ref()
+                    .is_some_and(|p| p.contains_key(pipeline))
+            })
+            .map(|s| s.name.as_str())
+            .collect()
+    } else if let Some(tag) = entry.strip_prefix(TAG_SELECTOR) {
+        skills
+            .iter()
+            .filter(|s| {
+                s.frontmatter
+                    .tags
+                    .as_ref()
+                    .is_some_and(|tags| tags.iter().any(|t| t == tag))
+            })
+            .map(|s| s.name.as_str())
+            .collect()
+    } else if is_glob(entry) {
+        skills
+            .iter()
+            .filter(|s| glob_matches(entry, &s.name))
+            .map(|s| s.name.as_str())
+            .collect()
+    } else {
+        return None;
+    };
+    matched.sort();
+    matched.dedup();
+    Some(matched)
+}
+
+/// Expand the selectors in a skill list against the discovered skills
+///
+/// Names pass through untouched, even if no such skill exists; install and
+/// check report those. A name listed directly wins over a selector.
+pub fn select<'a>(entries: impl IntoIterator<Item = &'a String>, skills: &[Skill]) -> Selection {
+    let mut selection = Selection::default();
+    let mut seen: BTreeMap<String, usize> = BTreeMap::new();
+
+    for entry in entries {
+        let Some(matched) = matching(entry, skills) else {
+            match seen.get(entry.as_str()) {
+                Some(&i) => selection.skills[i].via = None,
+                None => {
+                    seen.insert(entry.clone(), selection.skills.len());
+                    selection.skills.push(SelectedSkill {
+                        name: entry.clone(),
+                        via: None,
+                        inherited: false,
+                    });
+                }
+            }
+            continue;
+        };
+
+        if matched.is_empty() {
+            selection.unmatched.push(entry.clone());
+        }
+        for name in matched {
+            if !seen.contains_key(name) {
+                seen.insert(name.to_string(), selection.skills.len());
+                selection.skills.push(SelectedSkill {
+                    name: name.to_string(),
+                    via: Some(entry.clone()),
+                    inherited: false,
+                });
+            }
+        }
+    }
+
+    selection
+}
+
+/// Skills enabled in global scope, after `[global] exclude`
+pub fn global_selection(config: &Config, skills: &[Skill]) -> Selection {
+    let mut selection = select(&config.global.skills, skills);
+    selection.exclude(&config.global.exclude, skills, true);
+    selection
+}
+
+/// Skills enabled in a project, including inherited global ones
+///
+/// `[global] exclude` applies to every scope; the project's own `exclude`
+/// then subtracts from both its own list and what it inherits. Only the
+/// project's own unmatched selectors are reported.
+pub fn project_selection(config: &Config, project: &Project, skills: &[Skill]) -> Selection {
+    let mut selection = select(&project.skills, skills);
+    if project.inherit {
+        let own: HashSet<String> = selection.names().map(String::from).collect();
+        let inherited = select(&config.global.skills, skills);
+        selection.skills.extend(
+            inherited
+                .skills
+                .into_iter()
+                .filter(|selected| !own.contains(&selected.name))
+                .map(|selected| SelectedSkill {
+                    inherited: true,
+                    ..selected
+                }),
+        );
+    }
+    selection.exclude(&config.global.exclude, skills, true);
+    selection.exclude(&project.exclude, skills, false);
+    selection
+}
+
+/// Skills a project sees: its own sources first, then the global ones
+///
+/// A skill in a project source shadows a global skill of the same name,
+/// for that project only.
+pub fn project_skills(project: &Project, global: &[Skill]) -> Result<Vec<Skill>> {
+    if project.sources.is_empty() {
+        return Ok(global.to_vec());
+    }
+
+    let mut skills = skill::discover_all(&project.sources)?;
+    let local: HashSet<String> = skills.iter().map(|s| s.name.clone()).collect();
+    skills.extend(global.iter().filter(|s| !local.contains(&s.name)).cloned());
+    Ok(skills)
+}
+
+/// Find the configured project at `path`, relative to the working directory
+pub fn find_project<'a>(config: &'a Config, path: &Path) -> Result<(&'a PathBuf, &'a Project)> {
+    let absolute = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        env::current_dir()
+            .context("Failed to resolve current working directory")?
+            .join(path)
+    };
+    let canonical = fs::canonicalize(&absolute).ok();
+
+    config
+        .projects
+        .iter()
+        .find(|(key, _)| {
+            **key == absolute || (canonical.is_some() && fs::canonicalize(key).ok() == canonical)
+        })
+        .with_context(|| {
+            format!(
+                "No project configured at {} (see [projects] in loadout.toml)",
+                absolute.display()
+            )
+        })
+}
+
+/// Resolve which skills land in which target directory
+///
+/// Targets are consolidated by path, so aliases or projects that share a
+/// directory get the union of their skills. Every configured target is
+/// included, even when it ends up with no skills, so stale links can be pruned.
+/// Targets in `out_of_scope` are listed in `skipped` instead.
+pub fn build(config: &Config, skills: &[Skill], out_of_scope: &[PathBuf]) -> Result<InstallPlan> {
+    let mut aliases: Vec<_> = config.target_aliases.keys().cloned().collect();
+    aliases.sort();
+    validate_global_aliases(config)?;
+
+    let selected_global: HashSet<_> = config.global.targets.iter().cloned().collect();
+    let mut consolidated: BTreeMap<PathBuf, BTreeSet<String>> = BTreeMap::new();
+    let mut via: BTreeMap<PathBuf, BTreeMap<String, String>> = BTreeMap::new();
+    let mut local_skills: BTreeMap<PathBuf, BTreeMap<String, Skill>> = BTreeMap::new();
+    let mut unmatched = Vec::new();
+
+    let global = global_selection(config, skills);
+    unmatched.extend(global.unmatched.iter().map(|selector| UnmatchedSelector {
+        scope: "global".to_string(),
+        selector: selector.clone(),
+    }));
+
+    for alias in &aliases {
+        let alias_paths = config
+            .target_aliases
+            .get(alias)
+            .context(format!("Unknown target alias '{alias}' in global.targets"))?;
+
+        consolidated.entry(alias_paths.global.clone()).or_default();
+        if selected_global.contains(alias) {
+            add_selection(&mut consolidated, &mut via, &alias_paths.global, &global);
+        }
+    }
+
+    let mut project_entries: Vec<_> = config.projects.iter().collect();
+    project_entries.sort_by_key(|(path, _)| *path);
+
+    for (project_path, project_config) in project_entries {
+        validate_project_aliases(config, project_path, project_config)?;
+
+        let pool = project_skills(project_config, skills)?;
+        let selection = project_selection(config, project_config, &pool);
+        let sources = &project_config.sources;
+        let local: Vec<&Skill> = pool
+            .iter()
+            .filter(|s| sources.iter().any(|source| s.path.starts_with(source)))
+            .filter(|s| selection.names().any(|name| name == s.name))
+            .collect();
+        unmatched.extend(
+            selection
+                .unmatched
+                .iter()
+                .map(|selector| UnmatchedSelector {
+                    scope: project_path.display().to_string(),
+                    selector: selector.clone(),
+                }),
+        );
+
+        let selected_aliases: HashSet<String> = project_config
+            .targets
+            .as_ref()
+            .unwrap_or(&config.global.targets)
+            .iter()
+            .cloned()
+            .collect();
+
+        for alias in &aliases {
+            let alias_paths = config.target_aliases.get(alias).context(format!(
+                "Unknown target alias '{alias}' in projects.\"{}\".targets",
+                project_path.display()
+            ))?;
+
+            let target = paths::project_target(project_path, &alias_paths.project);
+
+            consolidated.entry(target.clone()).or_default();
+            if selected_aliases.contains(alias) {
+                add_selection(&mut consolidated, &mut via, &target, &selection);
+                let target_local = local_skills.entry(target.clone()).or_default();
+                for skill in &local {
+                    target_local.insert(skill.name.clone(), (*skill).clone());
+                }
+            }
+        }
+    }
+
+    let (skipped, consolidated): (Vec<_>, Vec<_>) = consolidated
+        .into_iter()
+        .partition(|(target, _)| out_of_scope.contains(target));
+    let skipped = skipped.into_iter().map(|(target, _)| target).collect();
+
+    let targets = consolidated
+        .into_iter()
+        .map(|(target, skills)| TargetPlan {
+            via: via.remove(&target).unwrap_or_default(),
+            local_skills: local_skills.remove(&target).unwrap_or_default(),
+            target,
+            skills: skills.into_iter().collect(),
+        })
+        .collect();
+
+    Ok(InstallPlan {
+        targets,
+        skipped,
+        unmatched,
+    })
+}
+
+/// Add selected skills to a target, keeping the first reason each was added
+///
+/// A skill listed by name anywhere for the target isn't shown as selected.
+fn add_selection(
+    consolidated: &mut BTreeMap<PathBuf, BTreeSet<String>>,
+    via: &mut BTreeMap<PathBuf, BTreeMap<String, String>>,
+    target: &Path,
+    selection: &Selection,
+) {
+    let skills = consolidated.entry(target.to_path_buf()).or_default();
+    let reasons = via.entry(target.to_path_buf()).or_default();
+    for selected in &selection.skills {
+        let is_new = skills.insert(selected.name.clone());
+        match &selected.via {
+            Some(selector) if is_new => {
+                reasons.insert(selected.name.clone(), selector.clone());
+            }
+            Some(_) => {}
+            None => {
+                reasons.remove(&selected.name);
+            }
+        }
+    }
+}
+
+fn validate_global_aliases(config: &Config) -> Result<()> {
+    for alias in &config.global.targets {
+        if !config.target_aliases.contains_key(alias) {
+            anyhow::bail!("Unknown target alias '{alias}' in global.targets");
+        }
+    }
+    Ok(())
+}
+
+fn validate_project_aliases(
+    config: &Config,
+    project_path: &Path,
+    project_config: &crate::config::Project,
+) -> Result<()> {
+    let aliases = project_config
+        .targets
+        .as_ref()
+        .unwrap_or(&config.global.targets);
+    for alias in aliases {
+        if !config.target_aliases.contains_key(alias) {
+            anyhow::bail!(
+                "Unknown target alias '{alias}' in projects.\"{}\".targets",
+                project_path.display()
+            );
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Global, Sources, TargetAliasPaths};
+    use crate::skill::testing::{write_skill, write_skills};
+    use std::collections::HashMap;
+    use tempfile::TempDir;
+
+    fn setup(temp: &TempDir) -> Vec<Skill> {
+        write_skills(
+            &temp.path().join("skills"),
+            &[
+                (
+                    "outline",
+                    "tags: [writing]\npipeline:\n  blog:\n    stage: outline\n    order: 1\n",
+                ),
+                (
+                    "draft",
+                    "pipeline:\n  blog:\n    stage: write\n    order: 2\n",
+                ),
+                ("proofread", "tags: [writing]\n"),
+                ("loose", ""),
+            ],
+        )
+    }
+
+    fn entries(items: &[&str]) -> Vec<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn should_expand_selectors_and_prefer_names() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let skills = setup(&temp);
+        let list = entries(&["pipeline:blog", "tag:writing", "draft", "tag:missing"]);
+
+        // When
+        let selection = select(&list, &skills);
+
+        // Then
+        assert_eq!(
+            selection.skills,
+            vec![
+                SelectedSkill {
+                    name: "draft".to_string(),
+                    via: None,
+                    inherited: false,
+                },
+                SelectedSkill {
+                    name: "outline".to_string(),
+                    via: Some("pipeline:blog".to_string()),
+                    inherited: false,
+                },
+                SelectedSkill {
+                    name: "proofread".to_string(),
+                    via: Some("tag:writing".to_string()),
+                    inherited: false,
+                },
+            ]
+        );
+        assert_eq!(selection.unmatched, vec!["tag:missing"]);
+    }
+
+    #[test]
+    fn should_record_selectors_and_unmatched_in_plan() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let skills = setup(&temp);
+        let project = temp.path().join("project");
+        let mut target_aliases = HashMap::new();
+        target_aliases.insert(
+            "claude".to_string(),
+            TargetAliasPaths {
+                global: temp.path().join("global-target"),
+                project: PathBuf::from(".claude/skills"),
+            },
+        );
+        let mut projects = HashMap::new();
+        projects.insert(
+            project.clone(),
+            Project {
+                skills: entries(&["tag:writing", "pipeline:vlog"]),
+                exclude: vec![],
+                sources: vec![],
+                inherit: true,
+                targets: None,
+            },
+        );
+        let config = Config {
+            sources: Sources {
+                skills: vec![],
+                templates: vec![],
+            },
+            global: Global {
+                targets: vec!["claude".to_string()],
+                skills: entries(&["pipeline:blog", "tag:none"]),
+                exclude: vec![],
+                allow_drafts: false,
+            },
+            target_aliases,
+            projects,
+            check: Default::default(),
+            crossref: Default::default(),
+            budget: Default::default(),
+            profile: None,
+            profiles: Default::default(),
+        };
+
+        // When
+        let plan = build(&config, &skills, &[]).unwrap();
+
+        // Then
+        let project_target = plan
+            .targets
+            .iter()
+            .find(|t| t.target == project.join(".claude/skills"))
+            .unwrap();
+        assert_eq!(project_target.skills, vec!["draft", "outline", "proofread"]);
+        assert_eq!(project_target.via["outline"], "tag:writing");
+        assert_eq!(project_target.via["draft"], "pipeline:blog");
+        assert_eq!(project_target.via["proofread"], "tag:writing");
+        assert_eq!(
+            plan.unmatched,
+            vec![
+                UnmatchedSelector {
+                    scope: "global".to_string(),
+                    selector: "tag:none".to_string(),
+                },
+                UnmatchedSelector {
+                    scope: project.display().to_string(),
+                    selector: "pipeline:vlog".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn should_match_glob_patterns() {
+        assert!(glob_matches("design-*", "design-review"));
+        assert!(glob_matches("*-review", "design-review"));
+        assert!(glob_matches("d?sign-*w", "design-review"));
+        assert!(glob_matches("*", "anything"));
+        assert!(!glob_matches("design-*", "redesign-review"));
+        assert!(!glob_matches("design-?", "design-review"));
+    }
+
+    #[test]
+    fn should_exclude_from_inherited_and_global_skills() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let skills = setup(&temp);
+        let mut config = crate::config::Config {
+            sources: Sources {
+                skills: vec![],
+                templates: vec![],
+            },
+            global: Global {
+                targets: vec![],
+                skills: entries(&["*", "missing"]),
+                exclude: entries(&["loose"]),
+                allow_drafts: false,
+            },
+            target_aliases: HashMap::new(),
+            projects: HashMap::new(),
+            check: Default::default(),
+            crossref: Default::default(),
+            budget: Default::default(),
+            profile: None,
+            profiles: Default::default(),
+        };
+        let project = Project {
+            skills: entries(&["loose", "draft"]),
+            exclude: entries(&["tag:writing"]),
+            sources: vec![],
+            inherit: true,
+            targets: None,
+        };
+
+        // When
+        let global = global_selection(&config, &skills);
+        let selection = project_selection(&config, &project, &skills);
+        config.global.exclude.clear();
+        let unexcluded = global_selection(&config, &skills);
+
+        // Then
+        assert_eq!(
+            global.names().collect::<Vec<_>>(),
+            vec!["draft", "outline", "proofread", "missing"]
+        );
+        assert_eq!(
+            selection
+                .skills
+                .iter()
+                .map(|s| (s.name.as_str(), s.inherited))
+                .collect::<Vec<_>>(),
+            vec![("draft", false), ("missing", true)]
+        );
+        assert_eq!(
+            selection.excluded,
+            vec![
+                ExcludedSkill {
+                    name: "loose".to_string(),
+                    rule: "loose".to_string(),
+                    global: true,
+                    listed: true,
+                },
+                ExcludedSkill {
+                    name: "outline".to_string(),
+                    rule: "tag:writing".to_string(),
+                    global: false,
+                    listed: false,
+                },
+                ExcludedSkill {
+                    name: "proofread".to_string(),
+                    rule: "tag:writing".to_string(),
+                    global: false,
+                    listed: false,
+                },
+            ]
+        );
+        assert!(unexcluded.names().any(|name| name == "loose"));
+    }
+
+    #[test]
+    fn should_resolve_project_sources_for_that_project_only() {
+        // Given
+        let temp = TempDir::new().unwrap();
+        let skills = setup(&temp);
+        let app = temp.path().join("app");
+        let other = temp.path().join("other");
+        write_skill(&app.join(".skills"), "deploy", "");
+        write_skill(&app.join(".skills"), "draft", "tags: [local]\n");
+        let mut target_aliases = HashMap::new();
+        target_aliases.insert(
+            "claude".to_string(),
+            TargetAliasPaths {
+                global: temp.path().join("global-target"),
+                project: PathBuf::from(".claude/skills"),
+            },
+        );
+        let mut projects = HashMap::new();
+        let app_sources = vec![app.join(".skills")];
+        for (root, skills, sources) in [
+            (&app, entries(&["deploy", "tag:local"]), app_sources),
+            (&other, entries(&["tag:local"]), vec![]),
+        ] {
+            projects.insert(
+                root.clone(),
+                Project {
+                    skills,
+                    exclude: vec![],
+                    sources,
+                    inherit: true,
+                    targets: None,
+                },
+            );
+        }
+        let config = Config {
+            sources: Sources {
+                skills: vec![],
+                templates: vec![],
+            },
+            global: Global {
+                targets: vec!["claude".to_string()],
+                skills: entries(&["draft", "outline"]),
+                exclude: vec![],
+                allow_drafts: false,
+            },
+            target_aliases,
+            projects,
+            check: Default::default(),
+            crossref: Default::default(),
+            budget: Default::default(),
+            profile: None,
+            profiles: Default::default(),
+        };
+
+        // When
+        let plan = build(&config, &skills, &[]).unwrap();
+
+        // Then
+        let target = |root: &Path| {
+            plan.targets
+                .iter()
+                .find(|t| t.target == root.join(".claude/skills"))
+                .unwrap()
+        };
+        let app_target = target(&app);
+        assert_eq!(app_target.skills, vec!["deploy", "draft", "outline"]);
+        assert_eq!(
+            app_target.local_skills.keys().collect::<Vec<_>>(),
+            vec!["deploy", "draft"]
+        );
+        assert!(app_target.local_skills["draft"].path.starts_with(&app));
+
+        let other_target = target(&other);
+        assert_eq!(other_target.skills, vec!["draft", "outline"]);
+        assert!(other_target.local_skills.is_empty());
+        assert_eq!(
+            plan.unmatched,
+            vec![UnmatchedSelector {
+                scope: other.display().to_string(),
+                selector: "tag:local".to_string(),
+            }]
+        );
+    }
+}
@@ -1,15 +1,30 @@
 use regex::Regex;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+
+use anyhow::{bail, Context, Result};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::links::{self, LinkTarget};
+use super::markdown::{self, MarkdownLine};
+use super::Skill;
 
 /// A cross-reference to another skill found in SKILL.md body content
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CrossRef {
     /// The name of the referenced skill
     pub target: String,
     /// The line number where the reference was found (1-indexed)
     pub line: usize,
+    /// The character column where the skill name starts (1-indexed)
+    pub column: usize,
+    /// Text of the heading the reference appears under, if any
+    pub heading: Option<String>,
     /// How the reference was detected
     pub method: DetectionMethod,
+    /// How likely the match is a real reference (0.0 to 1.0)
+    pub confidence: f32,
 }
 
 /// Detection method for skill references
@@ -23,6 +38,175 @@ pub enum DetectionMethod {
     RelatedTable,
     /// Natural language pattern (e.g., "invoke the X skill", "load X first")
     NaturalLanguage,
+    /// Markdown link into another skill's directory (e.g., `[voice](../voice/SKILL.md)`)
+    MarkdownLink,
+    /// Wiki-style link (e.g., `[[voice]]` or `[[voice|the voice skill]]`)
+    WikiLink,
+    /// Matched a custom pattern from `[crossref]` config (holds the pattern name)
+    Custom(String),
+}
+
+impl DetectionMethod {
+    /// Config keys for the built-in methods, as used in `[crossref.methods]`
+    pub const BUILTIN: [&'static str; 6] = [
+        "xml_crossref",
+        "markdown_link",
+        "wiki_link",
+        "backtick_context",
+        "related_table",
+        "natural_language",
+    ];
+
+    /// Whether the reference was written as an explicit link rather than inferred
+    pub fn is_link(&self) -> bool {
+        matches!(self, Self::MarkdownLink | Self::WikiLink)
+    }
+
+    /// Config key for this method (custom patterns use their own name)
+    pub fn key(&self) -> &str {
+        match self {
+            Self::XmlCrossref => "xml_crossref",
+            Self::MarkdownLink => "markdown_link",
+            Self::WikiLink => "wiki_link",
+            Self::BacktickContext => "backtick_context",
+            Self::RelatedTable => "related_table",
+            Self::NaturalLanguage => "natural_language",
+            Self::Custom(name) => name,
+        }
+    }
+
+    /// Default confidence for matches of this method
+    ///
+    /// Explicit syntax scores highest; heuristics that read prose score lowest.
+    pub fn confidence(&self) -> f32 {
+        match self {
+            Self::XmlCrossref | Self::MarkdownLink => 1.0,
+            Self::WikiLink => 0.95,
+            Self::RelatedTable => 0.9,
+            Self::BacktickContext => 0.6,
+            Self::NaturalLanguage | Self::Custom(_) => 0.5,
+        }
+    }
+}
+
+/// `[crossref]` settings from loadout.toml, compiled into `ExtractOptions`
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct CrossrefConfig {
+    /// Built-in detection methods to enable or disable, keyed by method name
+    /// e.g., `natural_language = false`
+    #[serde(default)]
+    pub methods: HashMap<String, bool>,
+
+    /// Additional patterns; each regex must have a `(?P<skill>...)` capture
+    #[serde(default)]
+    pub patterns: Vec<CrossrefPattern>,
+
+    /// Drop references scored below this confidence (0.0 to 1.0)
+    #[serde(default)]
+    pub min_confidence: f32,
+}
+
+/// A custom cross-reference pattern
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CrossrefPattern {
+    /// Name reported as the detection method
+    pub name: String,
+
+    /// Regular expression with a named `skill` capture group
+    pub regex: String,
+
+    /// Confidence assigned to matches (default: 0.5)
+    #[serde(default = "default_pattern_confidence")]
+    pub confidence: f32,
+}
+
+fn default_pattern_confidence() -> f32 {
+    0.5
+}
+
+/// Options controlling which parts of a document are scanned for references
+#[derive(Debug, Clone, Default)]
+pub struct ExtractOptions {
+    /// Also scan fenced and indented code blocks (skipped by default)
+    pub include_code: bool,
+    /// Built-in methods to skip, by config key
+    pub disabled: HashSet<String>,
+    /// Custom patterns run in addition to the built-in methods
+    pub patterns: Vec<CustomPattern>,
+    /// Drop references scored below this confidence
+    pub min_confidence: f32,
+}
+
+/// A compiled custom detection pattern
+#[derive(Debug, Clone)]
+pub struct CustomPattern {
+    /// Name reported as the detection method
+    pub name: String,
+    /// Compiled regex with a named `skill` capture
+    pub regex: Regex,
+    /// Confidence assigned to matches
+    pub confidence: f32,
+}
+
+impl ExtractOptions {
+    /// Build options from the `[crossref]` config section
+    ///
+    /// Fails on unknown method names, invalid regexes, patterns without a
+    /// `skill` capture, and confidences outside 0.0 to 1.0.
+    pub fn from_config(config: &CrossrefConfig) -> Result<Self> {
+        let mut disabled = HashSet::new();
+        for (method, enabled) in &config.methods {
+            if !DetectionMethod::BUILTIN.contains(&method.as_str()) {
+                bail!(
+                    "Unknown detection method '{}' in crossref.methods. Valid methods: {}",
+                    method,
+                    DetectionMethod::BUILTIN.join(", ")
+                );
+            }
+            if !enabled {
+                disabled.insert(method.clone());
+            }
+        }
+
+        check_confidence(config.min_confidence, "crossref.min_confidence")?;
+
+        let mut patterns = Vec::new();
+        for pattern in &config.patterns {
+            let field = format!("crossref.patterns '{}'", pattern.name);
+            let regex =
+                Regex::new(&pattern.regex).context(format!("Invalid regex in {}", field))?;
+            if !regex.capture_names().any(|n| n == Some("skill")) {
+                bail!(
+                    "Regex in {} needs a named capture for the skill name, e.g. (?P<skill>[a-z0-9-]+)",
+                    field
+                );
+            }
+            check_confidence(pattern.confidence, &format!("{} confidence", field))?;
+            patterns.push(CustomPattern {
+                name: pattern.name.clone(),
+                regex,
+                confidence: pattern.confidence,
+            });
+        }
+
+        Ok(Self {
+            include_code: false,
+            disabled,
+            patterns,
+            min_confidence: config.min_confidence,
+        })
+    }
+
+    fn enabled(&self, method: &str) -> bool {
+        !self.disabled.contains(method)
+    }
+}
+
+fn check_confidence(value: f32, field: &str) -> Result<()> {
+    if !(0.0..=1.0).contains(&value) {
+        bail!("{} must be between 0.0 and 1.0, got {}", field, value);
+    }
+    Ok(())
 }
 
 /// Extract all skill references from SKILL.md body content
@@ -40,19 +224,100 @@ pub fn extract_references_with_filter(
     skill_name: &str,
     known_skills: Option<&std::collections::HashSet<String>>,
 ) -> Vec<CrossRef> {
+    extract_references_with_options(
+        content,
+        skill_name,
+        known_skills,
+        &ExtractOptions::default(),
+    )
+}
+
+/// Extract references with explicit scanning options
+///
+/// Code blocks are skipped unless `options.include_code` is set, so
+/// examples such as `use `foo` skill` inside a fence don't create edges.
+pub fn extract_references_with_options(
+    content: &str,
+    skill_name: &str,
+    known_skills: Option<&std::collections::HashSet<String>>,
+    options: &ExtractOptions,
+) -> Vec<CrossRef> {
+    let lines: Vec<MarkdownLine<'_>> = markdown::scan(content)
+        .into_iter()
+        .filter(|l| options.include_code || !l.code)
+        .collect();
+
     let mut refs = Vec::new();
 
-    refs.extend(extract_xml_crossrefs(content));
-    refs.extend(extract_backtick_context(content, known_skills));
-    refs.extend(extract_related_tables(content));
-    refs.extend(extract_natural_language(content, known_skills));
+    if options.enabled("xml_crossref") {
+        refs.extend(extract_xml_crossrefs(&lines));
+    }
+    if options.enabled("markdown_link") {
+        refs.extend(extract_markdown_links(&lines, known_skills));
+    }
+    if options.enabled("wiki_link") {
+        refs.extend(extract_wiki_links(&lines));
+    }
+    if options.enabled("backtick_context") {
+        refs.extend(extract_backtick_context(&lines, known_skills));
+    }
+    if options.enabled("related_table") {
+        refs.extend(extract_related_tables(&lines));
+    }
+    if options.enabled("natural_language") {
+        refs.extend(extract_natural_language(&lines, known_skills));
+    }
+    for pattern in &options.patterns {
+        refs.extend(extract_custom_pattern(&lines, pattern, known_skills));
+    }
 
-    // Filter out self-references
+    // Filter out self-references and low-confidence matches
     refs.into_iter()
         .filter(|r| r.target != skill_name)
+        .filter(|r| r.confidence >= options.min_confidence)
         .collect()
 }
 
+/// Read a skill's SKILL.md and extract its references
+///
+/// Honors the skill's `crossref.include-code` frontmatter opt-in on top of
+/// the given options.
+pub fn references_for_skill(
+    skill: &Skill,
+    known_skills: Option<&HashSet<String>>,
+    options: &ExtractOptions,
+) -> Result<Vec<CrossRef>> {
+    let content = fs::read_to_string(&skill.skill_file)
+        .context(format!("Failed to read {}", skill.skill_file.display()))?;
+
+    let include_code = skill
+        .frontmatter
+        .crossref
+        .as_ref()
+        .map(|c| c.include_code)
+        .unwrap_or(false);
+
+    if include_code && !options.include_code {
+        let options = ExtractOptions {
+            include_code,
+            ..options.clone()
+        };
+        return Ok(extract_references_with_options(
+            &content,
+            &skill.name,
+            known_skills,
+            &options,
+        ));
+    }
+
+    Ok(extract_references_with_options(
+        &content,
+        &skill.name,
+        known_skills,
+        options,
+    ))
+}
+
 /// Build a cross-reference map from skill name to set of referenced skill names
 pub fn build_reference_map(
     skills: &[(String, Vec<CrossRef>)],
@@ -68,17 +333,103 @@ pub fn build_reference_map(
 
 // --- Detection heuristics ---
 
-fn extract_xml_crossrefs(content: &str) -> Vec<CrossRef> {
+fn crossref(line: &MarkdownLine<'_>, name: regex::Match<'_>, method: DetectionMethod) -> CrossRef {
+    CrossRef {
+        target: name.as_str().to_string(),
+        line: line.number,
+        column: markdown::column_at(line.text, name.start()),
+        heading: line.heading.clone(),
+        confidence: method.confidence(),
+        method,
+    }
+}
+
+fn extract_xml_crossrefs(lines: &[MarkdownLine<'_>]) -> Vec<CrossRef> {
     let mut refs = Vec::new();
     let re = Regex::new(r#"<see\s+ref="([a-z0-9]+(?:-[a-z0-9]+)*)">"#).unwrap();
 
-    for (line_num, line) in content.lines().enumerate() {
-        for cap in re.captures_iter(line) {
+    for line in lines {
+        for cap in re.captures_iter(line.text) {
             if let Some(skill_name) = cap.get(1) {
+                refs.push(crossref(line, skill_name, DetectionMethod::XmlCrossref));
+            }
+        }
+    }
+
+    refs
+}
+
+fn extract_markdown_links(
+    lines: &[MarkdownLine<'_>],
+    known_skills: Option<&HashSet<String>>,
+) -> Vec<CrossRef> {
+    let mut refs = Vec::new();
+
+    for link in links::links_in_lines(lines) {
+        let LinkTarget::Escaping(path) = links::classify(&link.target) else {
+            continue;
+        };
+
+        // `../voice/...` names the skill directly; deeper source layouts
+        // (`../../writing/voice/SKILL.md`) are recognized by the SKILL.md file
+        let name = match links::sibling_skill(&path) {
+            Some((name, _)) => Some(name),
+            None if path.file_name().is_some_and(|f| f == "SKILL.md") => path
+                .parent()
+                .and_then(|p| p.file_name())
+                .and_then(|n| n.to_str())
+                .map(String::from),
+            None => None,
+        };
+        let Some(name) = name else {
+            continue;
+        };
+
+        let is_skill = match known_skills {
+            Some(known) => known.contains(&name),
+            None => is_skill_name(&name),
+        };
+        if !is_skill {
+            continue;
+        }
+
+        // Point at the skill name inside the destination, not the `../` prefix
+        let offset = link
+            .target
+            .find(&format!("{}/", name))
+            .or_else(|| link.target.rfind(&name))
+            .unwrap_or(0);
+        let line = lines.iter().find(|l| l.number == link.line);
+        refs.push(CrossRef {
+            column: link.column + link.target[..offset].chars().count(),
+            line: link.line,
+            heading: line.and_then(|l| l.heading.clone()),
+            target: name,
+            method: DetectionMethod::MarkdownLink,
+            confidence: DetectionMethod::MarkdownLink.confidence(),
+        });
+    }
+
+    refs
+}
+
+fn extract_wiki_links(lines: &[MarkdownLine<'_>]) -> Vec<CrossRef> {
+    let mut refs = Vec::new();
+    let re =
+        Regex::new(r"\[\[\s*([a-z0-9]+(?:-[a-z0-9]+)*)\s*(?:#[^\]|]*)?(?:\|[^\]]*)?\]\]").unwrap();
+
+    for line in lines {
+        // `[[name]]` inside an inline code span is showing the syntax, not using it
+        let masked = links::mask_code_spans(line.text);
+        for cap in re.captures_iter(&masked) {
+            if let Some(name) = cap.get(1) {
                 refs.push(CrossRef {
-                    target: skill_name.as_str().to_string(),
-                    line: line_num + 1,
-                    method: DetectionMethod::XmlCrossref,
+                    target: name.as_str().to_string(),
+                    line: line.number,
+                    column: markdown::column_at(&masked, name.start()),
+                    heading: line.heading.clone(),
+                    method: DetectionMethod::WikiLink,
+                    confidence: DetectionMethod::WikiLink.confidence(),
                 });
             }
         }
@@ -87,8 +438,39 @@ fn extract_xml_crossrefs(content: &str) -> Vec<CrossRef> {
     refs
 }
 
+fn extract_custom_pattern(
+    lines: &[MarkdownLine<'_>],
+    pattern: &CustomPattern,
+    known_skills: Option<&HashSet<String>>,
+) -> Vec<CrossRef> {
+    let mut refs = Vec::new();
+
+    for line in lines {
+        for cap in pattern.regex.captures_iter(line.text) {
+            let Some(name) = cap.name("skill") else {
+                continue;
+            };
+            if let Some(known) = known_skills {
+                if !known.contains(name.as_str()) {
+                    continue;
+                }
+            }
+            let mut r = crossref(line, name, DetectionMethod::Custom(pattern.name.clone()));
+            r.confidence = pattern.confidence;
+            refs.push(r);
+        }
+    }
+
+    refs
+}
+
+fn is_skill_name(name: &str) -> bool {
+    let re = Regex::new(r"^[a-z0-9]+(?:-[a-z0-9]+)*$").unwrap();
+    re.is_match(name)
+}
+
 fn extract_backtick_context(
-    content: &str,
+    lines: &[MarkdownLine<'_>],
     known_skills: Option<&std::collections::HashSet<String>>,
 ) -> Vec<CrossRef> {
     let mut refs = Vec::new();
@@ -99,8 +481,8 @@ fn extract_backtick_context(
         r"(?i)\b(skill|invoke|load|use)\b[^\n`]*`([a-z0-9]+(?:-[a-z0-9]+)*)`|`([a-z0-9]+(?:-[a-z0-9]+)*)`[^\n`]*\b(skill|invoke|load|use)\b"
     ).unwrap();
 
-    for (line_num, line) in content.lines().enumerate() {
-        for cap in re.captures_iter(line) {
+    for line in lines {
+        for cap in re.captures_iter(line.text) {
             // Either group 2 (context before) or group 3 (context after) will match
             let skill_name = cap.get(2).or_else(|| cap.get(3));
             if let Some(name) = skill_name {
@@ -114,11 +496,7 @@ fn extract_backtick_context(
                     }
                 }
 
-                refs.push(CrossRef {
-                    target: name_str.to_string(),
-                    line: line_num + 1,
-                    method: DetectionMethod::BacktickContext,
-                });
+                refs.push(crossref(line, name, DetectionMethod::BacktickContext));
             }
         }
     }
@@ -126,13 +504,13 @@ fn extract_backtick_context(
     refs
 }
 
-fn extract_related_tables(content: &str) -> Vec<CrossRef> {
+fn extract_related_tables(lines: &[MarkdownLine<'_>]) -> Vec<CrossRef> {
     let mut refs = Vec::new();
     let mut in_related_section = false;
     let skill_pattern = Regex::new(r"`([a-z0-9]+(?:-[a-z0-9]+)*)`").unwrap();
 
-    for (line_num, line) in content.lines().enumerate() {
-        let line_lower = line.to_lowercase();
+    for line in lines {
+        let line_lower = line.text.to_lowercase();
 
         // Detect section headers
         if line_lower.contains("related skill") || line_lower.contains("integration") {
@@ -141,19 +519,15 @@ fn extract_related_tables(content: &str) -> Vec<CrossRef> {
         }
 
         // Exit section on next header
-        if line.starts_with('#') && in_related_section {
+        if line.text.starts_with('#') && in_related_section {
             in_related_section = false;
         }
 
         // Extract skill names from table rows in related sections
-        if in_related_section && line.contains('|') {
-            for cap in skill_pattern.captures_iter(line) {
+        if in_related_section && line.text.contains('|') {
+            for cap in skill_pattern.captures_iter(line.text) {
                 if let Some(name) = cap.get(1) {
-                    refs.push(CrossRef {
-                        target: name.as_str().to_string(),
-                        line: line_num + 1,
-                        method: DetectionMethod::RelatedTable,
-                    });
+                    refs.push(crossref(line, name, DetectionMethod::RelatedTable));
                 }
             }
         }
@@ -163,7 +537,7 @@ fn extract_related_tables(content: &str) -> Vec<CrossRef> {
 }
 
 fn extract_natural_language(
-    content: &str,
+    lines: &[MarkdownLine<'_>],
     known_skills: Option<&std::collections::HashSet<String>>,
 ) -> Vec<CrossRef> {
     let mut refs = Vec::new();
@@ -179,8 +553,8 @@ fn extract_natural_language(
 
     for pattern in &patterns {
         let re = Regex::new(pattern).unwrap();
-        for (line_num, line) in content.lines().enumerate() {
-            for cap in re.captures_iter(line) {
+        for line in lines {
+            for cap in re.captures_iter(line.text) {
                 if let Some(name) = cap.get(1) {
                     let name_str = name.as_str();
 
@@ -191,11 +565,7 @@ fn extract_natural_language(
                         }
                     }
 
-                    refs.push(CrossRef {
-                        target: name_str.to_string(),
-                        line: line_num + 1,
-                        method: DetectionMethod::NaturalLanguage,
-                    });
+                    refs.push(crossref(line, name, DetectionMethod::NaturalLanguage));
                 }
             }
         }
@@ -219,7 +589,7 @@ mod tests {
 "#;
 
         // When
-        let refs = extract_xml_crossrefs(content);
+        let refs = extract_xml_crossrefs(&markdown::scan(content));
 
         // Then
         assert_eq!(refs.len(), 2);
@@ -234,7 +604,7 @@ mod tests {
         let content = "invoke `skill-review` on the result";
 
         // When
-        let refs = extract_backtick_context(content, None);
+        let refs = extract_backtick_context(&markdown::scan(content), None);
 
         // Then
         assert_eq!(refs.len(), 1);
@@ -250,7 +620,7 @@ mod tests {
         known.insert("voice".to_string());
 
         // When
-        let refs = extract_backtick_context(content, Some(&known));
+        let refs = extract_backtick_context(&markdown::scan(content), Some(&known));
 
         // Then
         assert_eq!(refs.len(), 1);
@@ -271,7 +641,7 @@ mod tests {
 "#;
 
         // When
-        let refs = extract_related_tables(content);
+        let refs = extract_related_tables(&markdown::scan(content));
 
         // Then
         assert_eq!(refs.len(), 2);
@@ -285,7 +655,7 @@ mod tests {
         let content = "You should invoke the skill-review skill to verify quality";
 
         // When
-        let refs = extract_natural_language(content, None);
+        let refs = extract_natural_language(&markdown::scan(content), None);
 
         // Then
         assert_eq!(refs.len(), 1);
@@ -301,7 +671,7 @@ mod tests {
         known.insert("voice".to_string());
 
         // When
-        let refs = extract_natural_language(content, Some(&known));
+        let refs = extract_natural_language(&markdown::scan(content), Some(&known));
 
         // Then
         assert_eq!(refs.len(), 1);
@@ -336,12 +706,18 @@ mod tests {
                     CrossRef {
                         target: "skill-b".to_string(),
                         line: 1,
+                        column: 1,
+                        heading: None,
                         method: DetectionMethod::XmlCrossref,
+                        confidence: 1.0,
                     },
                     CrossRef {
                         target: "skill-c".to_string(),
                         line: 2,
+                        column: 1,
+                        heading: None,
                         method: DetectionMethod::XmlCrossref,
+                        confidence: 1.0,
                     },
                 ],
             ),
@@ -359,13 +735,147 @@ mod tests {
         assert_eq!(map.get("skill-b").unwrap().len(), 0);
     }
 
+    #[test]
+    fn should_skip_references_in_code_blocks_by_default() {
+        // Given
+        let content = "Use the `voice` skill.\n\n```xml\n<see ref=\"example\">x</see>\nuse `fake-skill` skill\n```\n\n    invoke the indented skill\n";
+
+        // When
+        let refs = extract_references(content, "self");
+
+        // Then
+        assert_eq!(refs.len(), 1);
+        assert_eq!(refs[0].target, "voice");
+    }
+
+    #[test]
+    fn should_scan_code_blocks_when_opted_in() {
+        // Given
+        let content = "```\n<see ref=\"example\">x</see>\n```";
+        let options = ExtractOptions {
+            include_code: true,
+            ..Default::default()
+        };
+
+        // When
+        let refs = extract_references_with_options(content, "self", None, &options);
+
+        // Then
+        assert_eq!(refs.len(), 1);
+        assert_eq!(refs[0].target, "example");
+    }
+
+    #[test]
+    fn should_record_column_and_heading() {
+        // Given
+        let content = "# Usage\n\n## Workflow\nFirst, invoke `skill-review` here";
+
+        // When
+        let refs = extract_references(content, "self");
+
+        // Then
+        assert_eq!(refs.len(), 1);
+        assert_eq!(refs[0].line, 4);
+        assert_eq!(refs[0].column, 16);
+        assert_eq!(refs[0].heading.as_deref(), Some("Workflow"));
+    }
+
+    #[test]
+    fn should_extract_markdown_links_to_known_skills() {
+        // Given
+        let content = "## See also\nTone lives in [voice](../voice/SKILL.md#tone).\nAlso [notes](../shared/notes.md) and [guide](references/guide.md).\nNested: [style](../../writing/style/SKILL.md)";
+        let known: HashSet<String> = ["voice", "style"].iter().map(|s| s.to_string()).collect();
+
+        // When
+        let refs = extract_markdown_links(&markdown::scan(content), Some(&known));
+
+        // Then
+        assert_eq!(refs.len(), 2);
+        assert_eq!(refs[0].target, "voice");
+        assert_eq!(refs[0].method, DetectionMethod::MarkdownLink);
+        assert_eq!(refs[0].line, 2);
+        assert_eq!(refs[0].column, 26);
+        assert_eq!(refs[0].heading.as_deref(), Some("See also"));
+        assert_eq!(refs[1].target, "style");
+    }
+
+    #[test]
+    fn should_extract_wiki_links() {
+        // Given
+        let content = "Load [[voice]] first, then [[skill-review|the reviewer]].\n`[[not-a-link]]` is [[Not Valid]]";
+
+        // When
+        let refs = extract_wiki_links(&markdown::scan(content));
+
+        // Then
+        assert_eq!(refs.len(), 2);
+        assert_eq!(refs[0].target, "voice");
+        assert_eq!(refs[0].column, 8);
+        assert_eq!(refs[1].target, "skill-review");
+        assert!(refs.iter().all(|r| r.method == DetectionMethod::WikiLink));
+    }
+
+    #[test]
+    fn should_apply_crossref_config() {
+        // Given
+        let config: CrossrefConfig = toml::from_str(
+            r#"
+            methods = { natural_language = false }
+            min_confidence = 0.55
+
+            [[patterns]]
+            name = "hand-off"
+            regex = "(?i)hand off to `?(?P<skill>[a-z0-9-]+)"
+            confidence = 0.8
+            "#,
+        )
+        .unwrap();
+        let options = ExtractOptions::from_config(&config).unwrap();
+        let content = "Hand off to review when done.\nInvoke the voice skill.\nLoad `tone` skill too.\n<see ref=\"style\">x</see>";
+
+        // When
+        let refs = extract_references_with_options(content, "self", None, &options);
+
+        // Then: natural language is off and nothing below 0.55 survives
+        let found: Vec<(&str, f32)> = refs
+            .iter()
+            .map(|r| (r.target.as_str(), r.confidence))
+            .collect();
+        assert_eq!(found, vec![("style", 1.0), ("tone", 0.6), ("review", 0.8)]);
+        assert_eq!(
+            refs[2].method,
+            DetectionMethod::Custom("hand-off".to_string())
+        );
+    }
+
+    #[test]
+    fn should_reject_invalid_crossref_config() {
+        // Given
+        let unknown_method: CrossrefConfig =
+            toml::from_str("methods = { telepathy = true }").unwrap();
+        let bad_confidence: CrossrefConfig = toml::from_str(
+            "[[patterns]]\nname = \"x\"\nregex = \"(?P<skill>a)\"\nconfidence = 2.0",
+        )
+        .unwrap();
+
+        // When/Then
+        assert!(ExtractOptions::from_config(&unknown_method)
+            .unwrap_err()
+            .to_string()
+            .contains("Unknown detection method 'telepathy'"));
+        assert!(ExtractOptions::from_config(&bad_confidence)
+            .unwrap_err()
+            .to_string()
+            .contains("between 0.0 and 1.0"));
+    }
+
     #[test]
     fn should_record_line_numbers() {
         // Given
         let content = "Line 1\nLine 2 with invoke `my-skill` here\nLine 3";
 
         // When
-        let refs = extract_backtick_context(content, None);
+        let refs = extract_backtick_context(&markdown::scan(content), None);
 
         // Then
         assert_eq!(refs.len(), 1);
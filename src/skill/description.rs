@@ -0,0 +1,275 @@
+//! Description quality lint
+//!
+//! Runners choose a skill from its `description` alone, so a description
+//! has to say what the skill does and when to reach for it, in the third
+//! person, and fit before the runner truncates it.
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{bail, Result};
+use regex::Regex;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::frontmatter::MAX_DESCRIPTION_LENGTH;
+use super::text;
+
+/// Rule names accepted in `[check.description] rules`
+pub const RULES: &[&str] = &[
+    "when-clause",
+    "restates-name",
+    "first-person",
+    "too-short",
+    "near-limit",
+];
+
+/// Phrases that tell the runner when a skill applies
+const WHEN_PATTERN: &str =
+    r"(?i)\b(when|whenever|if|before|after|during|while|once)\b|\buse (it |this )?(for|to|on)\b";
+
+/// First-person words, which read as the author talking rather than the skill.
+/// `I` must be capitalized, and words joined by `/` (as in "I/O") don't count.
+const FIRST_PERSON_PATTERN: &str =
+    r"(?:^|[^\w/])(I|I'm|I'll|I've|(?i:me|my|mine|we|we're|we'll|we've|our|ours))(?:$|[^\w/'])";
+
+/// Words that add nothing when a description restates the name
+const FILLER_TERMS: &[&str] = &["skill", "skills", "helper", "tool"];
+
+/// `[check.description]` settings from loadout.toml, compiled into `LintOptions`
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DescriptionLintConfig {
+    /// Lint rules to enable or disable, keyed by rule name
+    /// e.g., `first-person = false`
+    #[serde(default)]
+    pub rules: HashMap<String, bool>,
+
+    /// Fewest words a description needs to disambiguate (default: 6)
+    #[serde(default = "default_min_description_words")]
+    pub min_words: usize,
+
+    /// Warn above this many characters, before runners truncate (default: 900)
+    #[serde(default = "default_max_description_chars")]
+    pub max_length: usize,
+}
+
+impl Default for DescriptionLintConfig {
+    fn default() -> Self {
+        Self {
+            rules: HashMap::new(),
+            min_words: default_min_description_words(),
+            max_length: default_max_description_chars(),
+        }
+    }
+}
+
+fn default_min_description_words() -> usize {
+    6
+}
+
+fn default_max_description_chars() -> usize {
+    900
+}
+
+/// Compiled description lint settings
+#[derive(Debug, Clone)]
+pub struct LintOptions {
+    disabled: HashSet<String>,
+    min_words: usize,
+    max_length: usize,
+}
+
+/// One problem with a description
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DescriptionIssue {
+    pub rule: &'static str,
+    pub message: String,
+    pub fix: String,
+}
+
+impl Default for LintOptions {
+    fn default() -> Self {
+        Self::from_config(&DescriptionLintConfig::default())
+            .expect("default description lint config is valid")
+    }
+}
+
+impl LintOptions {
+    /// Build options from the `[check.description]` config section
+    ///
+    /// Fails on unknown rule names and a `max_length` above the hard limit.
+    pub fn from_config(config: &DescriptionLintConfig) -> Result<Self> {
+        let mut disabled = HashSet::new();
+        for (rule, enabled) in &config.rules {
+            if !RULES.contains(&rule.as_str()) {
+                bail!(
+                    "Unknown description rule '{}' in check.description.rules. Valid rules: {}",
+                    rule,
+                    RULES.join(", ")
+                );
+            }
+            if !enabled {
+                disabled.insert(rule.clone());
+            }
+        }
+
+        if config.max_length > MAX_DESCRIPTION_LENGTH {
+            bail!(
+                "check.description.max_length must be at most {}, got {}",
+                MAX_DESCRIPTION_LENGTH,
+                config.max_length
+            );
+        }
+
+        Ok(Self {
+            disabled,
+            min_words: config.min_words,
+            max_length: config.max_length,
+        })
+    }
+
+    fn enabled(&self, rule: &str) -> bool {
+        !self.disabled.contains(rule)
+    }
+}
+
+/// Lint a skill's description, returning one issue per failed rule
+pub fn lint(name: &str, description: &str, options: &LintOptions) -> Vec<DescriptionIssue> {
+    let mut issues = Vec::new();
+    let description = description.trim();
+    let words = description.split_whitespace().count();
+    let chars = description.chars().count();
+
+    if options.enabled("too-short") && words < options.min_words {
+        issues.push(DescriptionIssue {
+            rule: "too-short",
+            message: format!(
+                "is too short to tell it apart from other skills ({} words, minimum {})",
+                words, options.min_words
+            ),
+            fix: "Say what the skill does and which requests it handles".to_string(),
+        });
+    }
+
+    if options.enabled("restates-name") && restates_name(name, description) {
+        issues.push(DescriptionIssue {
+            rule: "restates-name",
+            message: "only restates the skill name".to_string(),
+            fix: "Describe what the skill does, not what it is called".to_string(),
+        });
+    }
+
+    if options.enabled("when-clause") && !Regex::new(WHEN_PATTERN).unwrap().is_match(description) {
+        issues.push(DescriptionIssue {
+            rule: "when-clause",
+            message: "doesn't say when to use the skill".to_string(),
+            fix: "Add a trigger, e.g. \"Use when ...\"".to_string(),
+        });
+    }
+
+    if options.enabled("first-person") {
+        if let Some(word) = Regex::new(FIRST_PERSON_PATTERN)
+            .unwrap()
+            .captures(description)
+            .and_then(|captures| captures.get(1))
+        {
+            issues.push(DescriptionIssue {
+                rule: "first-person",
+                message: format!("uses first-person phrasing ('{}')", word.as_str()),
+                fix: "Write in the third person, e.g. \"Reviews ...\" instead of \"I review ...\""
+                    .to_string(),
+            });
+        }
+    }
+
+    if options.enabled("near-limit") && chars > options.max_length {
+        issues.push(DescriptionIssue {
+            rule: "near-limit",
+            message: format!(
+                "is {} chars, near the {}-char limit where runners truncate",
+                chars, MAX_DESCRIPTION_LENGTH
+            ),
+            fix: format!(
+                "Trim it below {} chars and move detail into the body",
+                options.max_length
+            ),
+        });
+    }
+
+    issues
+}
+
+/// Whether every content word of the description is already in the name
+fn restates_name(name: &str, description: &str) -> bool {
+    let name_terms: HashSet<String> = text::terms(name).into_iter().collect();
+    let terms: Vec<String> = text::terms(description)
+        .into_iter()
+        .filter(|t| !FILLER_TERMS.contains(&t.as_str()))
+        .collect();
+
+    !terms.is_empty() && terms.iter().all(|t| name_terms.contains(t))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules(name: &str, description: &str) -> Vec<&'static str> {
+        lint(name, description, &LintOptions::default())
+            .into_iter()
+            .map(|issue| issue.rule)
+            .collect()
+    }
+
+    #[test]
+    fn should_flag_each_description_rule() {
+        // Given/When/Then
+        assert!(rules(
+            "code-review",
+            "Reviews pull requests for correctness. Use when a PR is ready for review."
+        )
+        .is_empty());
+        assert_eq!(
+            rules("code-review", "Code review skill"),
+            vec!["too-short", "restates-name", "when-clause"]
+        );
+        assert_eq!(
+            rules(
+                "deploy",
+                "I deploy the staging environment after the build passes on main"
+            ),
+            vec!["first-person"]
+        );
+        assert_eq!(
+            rules(
+                "deploy",
+                "We'll deploy the staging environment after the build passes on main"
+            ),
+            vec!["first-person"]
+        );
+        assert!(rules(
+            "file-io",
+            "Handles file I/O and buffered streams. Use when reading large files."
+        )
+        .is_empty());
+        let long = format!("Use when drafting posts. {}", "word ".repeat(200));
+        assert_eq!(rules("blog", &long), vec!["near-limit"]);
+    }
+
+    #[test]
+    fn should_disable_rules_and_reject_unknown_ones() {
+        // Given
+        let mut config = DescriptionLintConfig::default();
+        config.rules.insert("when-clause".to_string(), false);
+        config.rules.insert("too-short".to_string(), false);
+        let options = LintOptions::from_config(&config).unwrap();
+
+        // When
+        let issues = lint("voice", "Writes copy in the house style", &options);
+
+        // Then
+        assert!(issues.is_empty());
+        config.rules.insert("spelling".to_string(), true);
+        let error = LintOptions::from_config(&config).unwrap_err().to_string();
+        assert!(error.contains("Unknown description rule 'spelling'"));
+    }
+}
@@ -13,7 +13,7 @@ const NAME_PATTERN: &str = r"^[a-z0-9]+(-[a-z0-9]+)*$";
 const MIN_NAME_LENGTH: usize = 1;
 const MAX_NAME_LENGTH: usize = 64;
 const MIN_DESCRIPTION_LENGTH: usize = 1;
-const MAX_DESCRIPTION_LENGTH: usize = 1024;
+pub const MAX_DESCRIPTION_LENGTH: usize = 1024;
 
 /// Errors that can occur during frontmatter parsing and validation
 #[derive(Error, Debug)]
@@ -50,6 +50,37 @@ pub enum FrontmatterError {
 
     #[error("Pipeline '{pipeline}' has order 0: order must be >= 1")]
     InvalidPipelineOrder { pipeline: String },
+
+    #[error("Invalid replaced-by '{0}': must match pattern {NAME_PATTERN}")]
+    InvalidReplacement(String),
+
+    #[error("Invalid version '{version}': {reason}")]
+    InvalidVersion { version: String, reason: String },
+}
+
+/// Lifecycle status of a skill
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum SkillStatus {
+    /// Work in progress; kept out of global scope by default
+    Draft,
+    /// Ready for use (the default when `status` is omitted)
+    #[default]
+    Stable,
+    /// Superseded; see `replaced-by`
+    Deprecated,
+}
+
+impl SkillStatus {
+    pub fn label(&self) -> &str {
+        match self {
+            Self::Draft => "draft",
+            Self::Stable => "stable",
+            Self::Deprecated => "deprecated",
+        }
+    }
 }
 
 /// A skill's role within a named pipeline/workflow
@@ -70,6 +101,68 @@ pub struct PipelineStage {
     pub before: Option<Vec<String>>,
 }
 
+impl PipelineStage {
+    /// Names of the skills in `after`, without version constraints
+    pub fn after_skills(&self) -> impl Iterator<Item = &str> {
+        self.after
+            .iter()
+            .flatten()
+            .map(|entry| requirement_name(entry))
+    }
+
+    /// Names of the skills in `before`, without version constraints
+    pub fn before_skills(&self) -> impl Iterator<Item = &str> {
+        self.before
+            .iter()
+            .flatten()
+            .map(|entry| requirement_name(entry))
+    }
+}
+
+/// A pipeline `after`/`before` entry: `story-spine` or `story-spine@^2`
+#[derive(Debug, Clone, PartialEq)]
+pub struct SkillRequirement {
+    pub name: String,
+    pub constraint: Option<semver::VersionReq>,
+}
+
+impl SkillRequirement {
+    /// Parse an entry, splitting off the constraint after `@`
+    pub fn parse(entry: &str) -> std::result::Result<Self, String> {
+        let (name, constraint) = match entry.split_once('@') {
+            Some((name, constraint)) => (name, Some(constraint)),
+            None => (entry, None),
+        };
+
+        let re = Regex::new(NAME_PATTERN).unwrap();
+        if !re.is_match(name) {
+            return Err(format!("skill name must match pattern {NAME_PATTERN}"));
+        }
+
+        let constraint = constraint
+            .map(|c| semver::VersionReq::parse(c).map_err(|e| format!("constraint '{c}': {e}")))
+            .transpose()?;
+
+        Ok(Self {
+            name: name.to_string(),
+            constraint,
+        })
+    }
+}
+
+/// Skill name of a pipeline entry, with any `@constraint` removed
+pub fn requirement_name(entry: &str) -> &str {
+    entry.split_once('@').map_or(entry, |(name, _)| name)
+}
+
+/// Per-skill cross-reference extraction settings
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CrossrefSettings {
+    /// Also scan fenced and indented code blocks for references
+    #[serde(rename = "include-code", default)]
+    pub include_code: bool,
+}
+
 /// SKILL.md frontmatter
 ///
 /// This struct represents the union of all supported frontmatter fields
@@ -128,6 +221,23 @@ pub struct Frontmatter {
     /// Pipeline/workflow participation with stage ordering
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pipeline: Option<HashMap<String, PipelineStage>>,
+
+    /// Cross-reference extraction settings for this skill's body
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crossref: Option<CrossrefSettings>,
+
+    /// Lifecycle status: draft, stable (default), or deprecated
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<SkillStatus>,
+
+    /// Skill that supersedes this one
+    #[serde(rename = "replaced-by")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replaced_by: Option<String>,
+
+    /// Semantic version of the skill content, bumped when it changes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
 }
 
 impl Frontmatter {
@@ -157,9 +267,16 @@ impl Frontmatter {
         self.validate_description()?;
         self.validate_tags()?;
         self.validate_pipeline()?;
+        self.validate_replacement()?;
+        self.validate_version()?;
         Ok(())
     }
 
+    /// Lifecycle status, defaulting to stable when omitted
+    pub fn status(&self) -> SkillStatus {
+        self.status.unwrap_or_default()
+    }
+
     /// Validate the skill name
     fn validate_name(&self) -> Result<()> {
         let name_len = self.name.len();
@@ -217,6 +334,28 @@ impl Frontmatter {
         Ok(())
     }
 
+    /// Validate replaced-by if present
+    fn validate_replacement(&self) -> Result<()> {
+        if let Some(replacement) = &self.replaced_by {
+            let re = Regex::new(NAME_PATTERN).unwrap();
+            if !re.is_match(replacement) {
+                return Err(FrontmatterError::InvalidReplacement(replacement.clone()).into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate version if present
+    fn validate_version(&self) -> Result<()> {
+        if let Some(version) = &self.version {
+            semver::Version::parse(version).map_err(|e| FrontmatterError::InvalidVersion {
+                version: version.clone(),
+                reason: e.to_string(),
+            })?;
+        }
+        Ok(())
+    }
+
     /// Validate the description
     fn validate_description(&self) -> Result<()> {
         let desc_len = self.description.trim().len();
@@ -652,6 +791,82 @@ description: A test skill
         assert!(frontmatter.pipeline.is_none());
     }
 
+    #[test]
+    fn should_parse_crossref_include_code_setting() {
+        // Given
+        let content =
+            "---\nname: my-skill\ndescription: A test skill\ncrossref:\n  include-code: true\n---";
+
+        // When
+        let frontmatter = Frontmatter::parse(content).unwrap();
+
+        // Then
+        assert!(frontmatter.crossref.unwrap().include_code);
+    }
+
+    #[test]
+    fn should_parse_status_and_replacement() {
+        // Given
+        let deprecated = "---\nname: old-voice\ndescription: A test skill\nstatus: deprecated\nreplaced-by: voice\n---";
+        let plain = "---\nname: voice\ndescription: A test skill\n---";
+        let invalid = "---\nname: old\ndescription: A test skill\nstatus: retired\n---";
+
+        // When
+        let deprecated = Frontmatter::parse(deprecated).unwrap();
+        let plain = Frontmatter::parse(plain).unwrap();
+
+        // Then
+        assert_eq!(deprecated.status(), SkillStatus::Deprecated);
+        assert_eq!(deprecated.replaced_by.as_deref(), Some("voice"));
+        assert_eq!(plain.status(), SkillStatus::Stable);
+        assert!(Frontmatter::parse(invalid).is_err());
+    }
+
+    #[test]
+    fn should_validate_semver_version() {
+        // Given
+        let valid = "---\nname: voice\ndescription: A test skill\nversion: 1.2.0-beta.1\n---";
+        let invalid = "---\nname: voice\ndescription: A test skill\nversion: \"1.2\"\n---";
+
+        // When
+        let valid = Frontmatter::parse(valid).unwrap();
+        let result = Frontmatter::parse(invalid);
+
+        // Then
+        assert_eq!(valid.version.as_deref(), Some("1.2.0-beta.1"));
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid version '1.2'"));
+    }
+
+    #[test]
+    fn should_parse_version_constraints_in_pipeline_entries() {
+        // Given
+        let valid = "---\nname: blog-draft\ndescription: A test skill\npipeline:\n  blog:\n    stage: draft\n    order: 2\n    after: [story-spine@^2]\n    before: [blog-edit]\n---";
+        let malformed = "---\nname: blog-draft\ndescription: A test skill\npipeline:\n  blog:\n    stage: draft\n    order: 2\n    after: [story-spine@two]\n---";
+
+        // When
+        let frontmatter = Frontmatter::parse(valid).unwrap();
+        let lenient = Frontmatter::parse(malformed);
+
+        // Then
+        let stage = &frontmatter.pipeline.unwrap()["blog"];
+        assert_eq!(
+            stage.after_skills().collect::<Vec<_>>(),
+            vec!["story-spine"]
+        );
+        assert_eq!(stage.before_skills().collect::<Vec<_>>(), vec!["blog-edit"]);
+        let requirement = SkillRequirement::parse("story-spine@^2").unwrap();
+        assert!(requirement
+            .constraint
+            .unwrap()
+            .matches(&semver::Version::new(2, 3, 0)));
+        // Malformed entries load and are reported by `loadout check`
+        assert!(lenient.is_ok());
+        assert!(SkillRequirement::parse("story-spine@two").is_err());
+    }
+
     #[test]
     fn should_reject_invalid_pipeline_name() {
         // Given
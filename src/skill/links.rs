@@ -0,0 +1,253 @@
+//! Markdown link extraction and resolution for SKILL.md bodies
+
+use std::path::{Component, Path, PathBuf};
+
+use regex::Regex;
+
+use super::markdown::{self, MarkdownLine};
+
+/// A markdown link or image reference found in SKILL.md content
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Link {
+    /// The raw link destination as written
+    pub target: String,
+    /// The line number where the link was found (1-indexed)
+    pub line: usize,
+    /// The character column where the destination starts (1-indexed)
+    pub column: usize,
+    /// Whether this is an image reference (`![alt](src)`)
+    pub image: bool,
+}
+
+/// Where a link destination points, relative to the skill directory
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkTarget {
+    /// URL with a scheme (`https:`, `mailto:`) or protocol-relative URL
+    External,
+    /// In-page anchor (`#section`)
+    Anchor,
+    /// Path inside the skill directory (normalized, relative to the skill root)
+    Local(PathBuf),
+    /// Path that leaves the skill directory (normalized, may start with `..`)
+    Escaping(PathBuf),
+}
+
+/// Extract inline links, images, and reference definitions from markdown
+///
+/// Links inside fenced or indented code blocks and inline code spans are ignored.
+pub fn extract_links(content: &str) -> Vec<Link> {
+    let lines: Vec<MarkdownLine<'_>> = markdown::scan(content)
+        .into_iter()
+        .filter(|l| !l.code)
+        .collect();
+    links_in_lines(&lines)
+}
+
+/// Extract links from pre-scanned lines, without filtering code blocks
+///
+/// Inline code spans are still ignored.
+pub fn links_in_lines(lines: &[MarkdownLine<'_>]) -> Vec<Link> {
+    let inline = Regex::new(r#"(!?)\[[^\]]*\]\(\s*<?([^)\s>]+)>?(?:\s+["'(][^)]*)?\)"#).unwrap();
+    let definition = Regex::new(r"^\s{0,3}\[[^\]]+\]:\s*<?([^\s>]+)>?").unwrap();
+
+    let mut links = Vec::new();
+
+    for md_line in lines {
+        // Masking keeps one char per char, so columns still match the original
+        let line = mask_code_spans(md_line.text);
+
+        if let Some(cap) = definition.captures(&line) {
+            let target = cap.get(1).unwrap();
+            links.push(Link {
+                target: target.as_str().to_string(),
+                line: md_line.number,
+                column: markdown::column_at(&line, target.start()),
+                image: false,
+            });
+            continue;
+        }
+
+        for cap in inline.captures_iter(&line) {
+            let target = cap.get(2).unwrap();
+            links.push(Link {
+                target: target.as_str().to_string(),
+                line: md_line.number,
+                column: markdown::column_at(&line, target.start()),
+                image: &cap[1] == "!",
+            });
+        }
+    }
+
+    links
+}
+
+/// Classify a link destination relative to the skill directory root
+pub fn classify(target: &str) -> LinkTarget {
+    if target.starts_with('#') {
+        return LinkTarget::Anchor;
+    }
+    if target.starts_with("//") || has_scheme(target) {
+        return LinkTarget::External;
+    }
+
+    // Drop fragment and query; they don't affect which file is targeted
+    let path = target.split(['#', '?']).next().unwrap_or_default();
+    let path = path.replace("%20", " ");
+
+    if Path::new(&path).is_absolute() {
+        return LinkTarget::Escaping(PathBuf::from(path));
+    }
+
+    let normalized = normalize(Path::new(&path));
+    if normalized.starts_with("..") {
+        LinkTarget::Escaping(normalized)
+    } else {
+        LinkTarget::Local(normalized)
+    }
+}
+
+/// Lexically normalize a relative path, resolving `.` and `..` components
+pub fn normalize(path: &Path) -> PathBuf {
+    let mut parts: Vec<Component<'_>> = Vec::new();
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => match parts.last() {
+                Some(Component::Normal(_)) => {
+                    parts.pop();
+                }
+                _ => parts.push(component),
+            },
+            other => parts.push(other),
+        }
+    }
+
+    parts.iter().collect()
+}
+
+/// Split an escaping path of the form `../<name>/<rest>` into its parts
+///
+/// This is the only shape of cross-skill link that still resolves once
+/// skills are symlinked side by side into a target directory.
+pub fn sibling_skill(path: &Path) -> Option<(String, PathBuf)> {
+    let mut components = path.components();
+    if components.next() != Some(Component::ParentDir) {
+        return None;
+    }
+    let name = match components.next() {
+        Some(Component::Normal(name)) => name.to_str()?.to_string(),
+        _ => return None,
+    };
+    Some((name, components.as_path().to_path_buf()))
+}
+
+fn has_scheme(target: &str) -> bool {
+    let re = Regex::new(r"^[A-Za-z][A-Za-z0-9+.-]*:").unwrap();
+    re.is_match(target)
+}
+
+/// Replace the contents of inline code spans with spaces so they can't match
+pub(crate) fn mask_code_spans(line: &str) -> String {
+    let mut masked = String::with_capacity(line.len());
+    let mut in_span = false;
+
+    for c in line.chars() {
+        if c == '`' {
+            in_span = !in_span;
+            masked.push(c);
+        } else if in_span {
+            masked.push(' ');
+        } else {
+            masked.push(c);
+        }
+    }
+
+    masked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_extract_inline_links_and_images() {
+        // Given
+        let content = "See [the guide](references/guide.md) and ![diagram](assets/flow.png).";
+
+        // When
+        let links = extract_links(content);
+
+        // Then
+        assert_eq!(links.len(), 2);
+        assert_eq!(links[0].target, "references/guide.md");
+        assert_eq!(links[0].column, 17);
+        assert!(!links[0].image);
+        assert_eq!(links[1].target, "assets/flow.png");
+        assert!(links[1].image);
+    }
+
+    #[test]
+    fn should_extract_reference_definitions() {
+        // Given
+        let content = "Read [the guide][guide].\n\n[guide]: references/guide.md \"Guide\"";
+
+        // When
+        let links = extract_links(content);
+
+        // Then
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].target, "references/guide.md");
+        assert_eq!(links[0].line, 3);
+    }
+
+    #[test]
+    fn should_ignore_links_in_code() {
+        // Given
+        let content = "```\n[fake](missing.md)\n```\nUse `[also](fake.md)` syntax";
+
+        // When
+        let links = extract_links(content);
+
+        // Then
+        assert!(links.is_empty());
+    }
+
+    #[test]
+    fn should_classify_link_targets() {
+        // Given/When/Then
+        assert_eq!(classify("https://example.com"), LinkTarget::External);
+        assert_eq!(classify("mailto:me@example.com"), LinkTarget::External);
+        assert_eq!(classify("#usage"), LinkTarget::Anchor);
+        assert_eq!(
+            classify("./scripts/run.sh#L10"),
+            LinkTarget::Local(PathBuf::from("scripts/run.sh"))
+        );
+        assert_eq!(
+            classify("references/../assets/a.png"),
+            LinkTarget::Local(PathBuf::from("assets/a.png"))
+        );
+        assert_eq!(
+            classify("../other-skill/SKILL.md"),
+            LinkTarget::Escaping(PathBuf::from("../other-skill/SKILL.md"))
+        );
+    }
+
+    #[test]
+    fn should_split_sibling_skill_paths() {
+        // Given/When/Then
+        assert_eq!(
+            sibling_skill(Path::new("../voice/SKILL.md")),
+            Some(("voice".to_string(), PathBuf::from("SKILL.md")))
+        );
+        assert_eq!(
+            sibling_skill(Path::new("../voice")),
+            Some(("voice".to_string(), PathBuf::new()))
+        );
+        assert_eq!(
+            sibling_skill(Path::new("../../writing/voice/SKILL.md")),
+            None
+        );
+        assert_eq!(sibling_skill(Path::new("references/a.md")), None);
+    }
+}
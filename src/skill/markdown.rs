@@ -0,0 +1,264 @@
+//! Line-oriented markdown structure scanning
+//!
+//! This is not a full CommonMark parser. It tracks just enough block
+//! structure (fenced code, indented code, ATX headings, lists) for the
+//! detectors that scan SKILL.md bodies line by line.
+
+use regex::Regex;
+
+/// A single line of markdown with its block context
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkdownLine<'a> {
+    /// The line number (1-indexed)
+    pub number: usize,
+    /// The raw line text
+    pub text: &'a str,
+    /// Whether the line is part of a fenced or indented code block
+    pub code: bool,
+    /// Text of the nearest heading above this line, if any
+    pub heading: Option<String>,
+}
+
+/// Scan markdown content into lines annotated with code and heading context
+pub fn scan(content: &str) -> Vec<MarkdownLine<'_>> {
+    let heading_re = Regex::new(r"^ {0,3}#{1,6}(?:\s+(.*?))?\s*#*\s*$").unwrap();
+    let list_re = Regex::new(r"^\s*(?:[-*+]|\d+[.)])\s").unwrap();
+
+    let mut lines = Vec::new();
+    let mut heading: Option<String> = None;
+    let mut fence: Option<(char, usize)> = None;
+    let mut prev_blank = true;
+    let mut prev_indented_code = false;
+    let mut in_list = false;
+
+    for (index, text) in content.lines().enumerate() {
+        let number = index + 1;
+        let blank = text.trim().is_empty();
+
+        // Inside a fence: everything is code until the matching close
+        if let Some((marker, len)) = fence {
+            if is_fence_close(text, marker, len) {
+                fence = None;
+            }
+            lines.push(MarkdownLine {
+                number,
+                text,
+                code: true,
+                heading: heading.clone(),
+            });
+            prev_blank = false;
+            continue;
+        }
+
+        if let Some(open) = fence_open(text) {
+            fence = Some(open);
+            lines.push(MarkdownLine {
+                number,
+                text,
+                code: true,
+                heading: heading.clone(),
+            });
+            prev_blank = false;
+            prev_indented_code = false;
+            continue;
+        }
+
+        let indented = text.starts_with("    ") || text.starts_with('\t');
+        let indented_code = !blank && indented && !in_list && (prev_blank || prev_indented_code);
+
+        if indented_code {
+            lines.push(MarkdownLine {
+                number,
+                text,
+                code: true,
+                heading: heading.clone(),
+            });
+            prev_blank = false;
+            prev_indented_code = true;
+            continue;
+        }
+
+        if !blank {
+            if let Some(cap) = heading_re.captures(text) {
+                heading = Some(
+                    cap.get(1)
+                        .map(|m| m.as_str().trim().to_string())
+                        .unwrap_or_default(),
+                );
+                in_list = false;
+            } else if list_re.is_match(text) {
+                in_list = true;
+            } else if !indented && prev_blank {
+                in_list = false;
+            }
+        }
+
+        lines.push(MarkdownLine {
+            number,
+            text,
+            code: false,
+            heading: heading.clone(),
+        });
+        prev_blank = blank;
+        if !blank {
+            prev_indented_code = false;
+        }
+    }
+
+    lines
+}
+
+/// Convert a byte offset within a line to a 1-indexed character column
+pub fn column_at(line: &str, byte_offset: usize) -> usize {
+    line[..byte_offset].chars().count() + 1
+}
+
+/// Replace `from` with `to` at a 1-indexed line and character column
+///
+/// Returns `None` if the text at that position isn't `from`.
+pub fn replace_at(
+    content: &str,
+    line: usize,
+    column: usize,
+    from: &str,
+    to: &str,
+) -> Option<String> {
+    let mut offset = 0;
+    for (index, text) in content.split_inclusive('\n').enumerate() {
+        if index + 1 == line {
+            let start = offset + text.char_indices().nth(column.checked_sub(1)?)?.0;
+            if !content[start..].starts_with(from) {
+                return None;
+            }
+            let mut updated = String::with_capacity(content.len() + to.len());
+            updated.push_str(&content[..start]);
+            updated.push_str(to);
+            updated.push_str(&content[start + from.len()..]);
+            return Some(updated);
+        }
+        offset += text.len();
+    }
+    None
+}
+
+fn fence_open(text: &str) -> Option<(char, usize)> {
+    let indent = text.len() - text.trim_start_matches(' ').len();
+    if indent > 3 {
+        return None;
+    }
+    let rest = &text[indent..];
+    let marker = rest.chars().next()?;
+    if marker != '`' && marker != '~' {
+        return None;
+    }
+    let len = rest.chars().take_while(|&c| c == marker).count();
+    if len < 3 {
+        return None;
+    }
+    // Backtick fences can't have backticks in their info string
+    if marker == '`' && rest[len..].contains('`') {
+        return None;
+    }
+    Some((marker, len))
+}
+
+fn is_fence_close(text: &str, marker: char, open_len: usize) -> bool {
+    let trimmed = text.trim_start_matches(' ');
+    if text.len() - trimmed.len() > 3 {
+        return false;
+    }
+    let len = trimmed.chars().take_while(|&c| c == marker).count();
+    len >= open_len && trimmed[len..].trim().is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn code_lines(content: &str) -> Vec<usize> {
+        scan(content)
+            .into_iter()
+            .filter(|l| l.code)
+            .map(|l| l.number)
+            .collect()
+    }
+
+    #[test]
+    fn should_mark_fenced_code_blocks() {
+        // Given
+        let content = "Intro\n```xml\n<skill>\n```\nAfter\n~~~~\n```\nstill code\n~~~~\n";
+
+        // When
+        let code = code_lines(content);
+
+        // Then
+        assert_eq!(code, vec![2, 3, 4, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn should_mark_indented_code_after_blank_line() {
+        // Given
+        let content =
+            "Paragraph\n    not code (continuation)\n\n    code line\n    more code\nText";
+
+        // When
+        let code = code_lines(content);
+
+        // Then
+        assert_eq!(code, vec![4, 5]);
+    }
+
+    #[test]
+    fn should_not_treat_nested_list_items_as_code() {
+        // Given
+        let content = "- item\n\n    - nested item\n    continuation\n";
+
+        // When
+        let code = code_lines(content);
+
+        // Then
+        assert!(code.is_empty());
+    }
+
+    #[test]
+    fn should_track_enclosing_heading() {
+        // Given
+        let content = "# Title\nIntro\n## Related skills ##\n| `voice` |";
+
+        // When
+        let lines = scan(content);
+
+        // Then
+        assert_eq!(lines[1].heading.as_deref(), Some("Title"));
+        assert_eq!(lines[3].heading.as_deref(), Some("Related skills"));
+    }
+
+    #[test]
+    fn should_replace_text_at_line_and_column() {
+        // Given
+        let content = "# Title\nUse é `voice` and `voice`\n";
+
+        // When
+        let replaced = replace_at(content, 2, 20, "voice", "tone");
+        let mismatched = replace_at(content, 2, 1, "voice", "tone");
+
+        // Then
+        assert_eq!(
+            replaced.as_deref(),
+            Some("# Title\nUse é `voice` and `tone`\n")
+        );
+        assert_eq!(mismatched, None);
+    }
+
+    #[test]
+    fn should_compute_character_columns() {
+        // Given
+        let line = "é `voice`";
+
+        // When
+        let column = column_at(line, line.find("voice").unwrap());
+
+        // Then
+        assert_eq!(column, 4);
+    }
+}
@@ -1,7 +1,16 @@
 //! Skill discovery, resolution, and frontmatter validation
 
 pub mod crossref;
+pub mod description;
 pub mod frontmatter;
+pub mod links;
+pub mod markdown;
+pub mod pipeline;
+pub mod suggest;
+#[cfg(test)]
+pub(crate) mod testing;
+pub mod text;
+pub mod tokens;
 
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
@@ -11,18 +20,28 @@ use thiserror::Error;
 use walkdir::{DirEntry, WalkDir};
 
 pub use crossref::{
-    build_reference_map, extract_references, extract_references_with_filter, CrossRef,
-    DetectionMethod,
+    build_reference_map, extract_references, extract_references_with_filter,
+    extract_references_with_options, references_for_skill, CrossRef, CustomPattern,
+    DetectionMethod, ExtractOptions,
+};
+pub use frontmatter::{
+    CrossrefSettings, Frontmatter, PipelineStage, SkillRequirement, SkillStatus,
 };
-pub use frontmatter::{Frontmatter, PipelineStage};
+pub use links::{extract_links, Link, LinkTarget};
 
 const SKILL_FILE_NAME: &str = "SKILL.md";
 
+/// Directory in a skill source holding that source's default template
+pub const SOURCE_TEMPLATE_DIR: &str = "_template";
+
 /// Errors that can occur during skill resolution
 #[derive(Error, Debug)]
 pub enum SkillError {
-    #[error("Skill '{0}' not found in any source directory")]
-    NotFound(String),
+    #[error("Skill '{name}' not found in any source directory{}", suggest::did_you_mean(.suggestions))]
+    NotFound {
+        name: String,
+        suggestions: Vec<String>,
+    },
 
     #[error("No SKILL.md found in skill directory: {0}")]
     MissingSkillFile(PathBuf),
@@ -34,6 +53,16 @@ pub enum SkillError {
     },
 }
 
+impl SkillError {
+    /// Build a `NotFound` error with suggestions drawn from the known names
+    pub fn not_found<'a>(name: &str, known: impl IntoIterator<Item = &'a str>) -> Self {
+        SkillError::NotFound {
+            name: name.to_string(),
+            suggestions: suggest::suggestions(name, known),
+        }
+    }
+}
+
 /// A discovered skill with its metadata
 #[derive(Debug, Clone)]
 pub struct Skill {
@@ -73,6 +102,37 @@ impl Skill {
             frontmatter,
         })
     }
+
+    /// List files shipped alongside SKILL.md (e.g. `scripts/`, `references/`)
+    ///
+    /// Returns paths relative to the skill directory, sorted. Hidden entries
+    /// and nested skill directories are skipped.
+    pub fn supporting_files(&self) -> Result<Vec<PathBuf>> {
+        let mut files = Vec::new();
+
+        let walker = WalkDir::new(&self.path)
+            .min_depth(1)
+            .into_iter()
+            .filter_entry(|e| is_not_hidden(e) && !is_nested_skill_dir(e));
+
+        for entry in walker {
+            let entry = entry.map_err(|e| SkillError::WalkError {
+                path: self.path.clone(),
+                source: e,
+            })?;
+
+            if !entry.file_type().is_file() || entry.path() == self.skill_file {
+                continue;
+            }
+
+            if let Ok(relative) = entry.path().strip_prefix(&self.path) {
+                files.push(relative.to_path_buf());
+            }
+        }
+
+        files.sort();
+        Ok(files)
+    }
 }
 
 /// Walk source directories to discover all skills
@@ -102,7 +162,7 @@ pub fn discover_in_directory(source: &Path) -> Result<Vec<Skill>> {
     let walker = WalkDir::new(source)
         .follow_links(true)
         .into_iter()
-        .filter_entry(is_not_hidden);
+        .filter_entry(|e| is_not_hidden(e) && is_not_template(e));
 
     for entry in walker {
         let entry = entry.map_err(|e| SkillError::WalkError {
@@ -140,7 +200,9 @@ pub fn resolve(sources: &[PathBuf], name: &str) -> Result<Skill> {
         }
     }
 
-    Err(SkillError::NotFound(name.to_string()).into())
+    // Only pay for a full discovery once we know the name is wrong
+    let known = discover_all(sources)?;
+    Err(SkillError::not_found(name, known.iter().map(|s| s.name.as_str())).into())
 }
 
 /// Find a skill by name within a single source directory
@@ -152,7 +214,7 @@ fn find_in_directory(source: &Path, name: &str) -> Result<Option<Skill>> {
     let walker = WalkDir::new(source)
         .follow_links(true)
         .into_iter()
-        .filter_entry(is_not_hidden);
+        .filter_entry(|e| is_not_hidden(e) && is_not_template(e));
 
     for entry in walker {
         let entry = entry.map_err(|e| SkillError::WalkError {
@@ -189,13 +251,25 @@ fn is_skill_file(entry: &DirEntry) -> bool {
             .unwrap_or(false)
 }
 
-/// Filter out hidden directories (starting with .)
-fn is_not_hidden(entry: &DirEntry) -> bool {
-    entry
-        .file_name()
-        .to_str()
-        .map(|s| !s.starts_with('.'))
-        .unwrap_or(false)
+/// Filter out hidden entries (starting with .) below the walk root
+pub(crate) fn is_not_hidden(entry: &DirEntry) -> bool {
+    entry.depth() == 0
+        || entry
+            .file_name()
+            .to_str()
+            .map(|s| !s.starts_with('.'))
+            .unwrap_or(false)
+}
+
+/// Filter out the source's `_template` directory, which `loadout new`
+/// renders rather than links
+fn is_not_template(entry: &DirEntry) -> bool {
+    entry.depth() != 1 || entry.file_name() != SOURCE_TEMPLATE_DIR
+}
+
+/// Check if a directory entry is a directory containing its own SKILL.md
+fn is_nested_skill_dir(entry: &DirEntry) -> bool {
+    entry.depth() > 0 && entry.file_type().is_dir() && entry.path().join(SKILL_FILE_NAME).exists()
 }
 
 #[cfg(test)]
@@ -262,6 +336,54 @@ mod tests {
         assert!(nested.path.ends_with("category/nested-skill"));
     }
 
+    #[test]
+    fn should_discover_skills_in_hidden_source_but_skip_hidden_subdirectories() {
+        // Given
+        let temp = tempfile::TempDir::new().unwrap();
+        let source = temp.path().join(".skills");
+        for dir in ["visible", ".hidden"] {
+            let skill_dir = source.join(dir);
+            std::fs::create_dir_all(&skill_dir).unwrap();
+            std::fs::write(
+                skill_dir.join("SKILL.md"),
+                format!("---\nname: {dir}\ndescription: Test skill\n---\n"),
+            )
+            .unwrap();
+        }
+
+        // When
+        let skills = discover_in_directory(&source).unwrap();
+
+        // Then
+        let names: Vec<&str> = skills.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["visible"]);
+    }
+
+    #[test]
+    fn should_skip_source_template_but_not_other_underscore_directories() {
+        // Given
+        let temp = tempfile::TempDir::new().unwrap();
+        let source = temp.path().join("skills");
+        for (dir, name) in [("_template", "template"), ("_shared/foo", "foo")] {
+            let skill_dir = source.join(dir);
+            std::fs::create_dir_all(&skill_dir).unwrap();
+            std::fs::write(
+                skill_dir.join("SKILL.md"),
+                format!("---\nname: {name}\ndescription: Test skill\n---\n"),
+            )
+            .unwrap();
+        }
+
+        // When
+        let skills = discover_in_directory(&source).unwrap();
+        let found = find_in_directory(&source, "template").unwrap();
+
+        // Then
+        let names: Vec<&str> = skills.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["foo"]);
+        assert!(found.is_none());
+    }
+
     #[test]
     fn should_resolve_skill_from_first_matching_source() {
         // Given
@@ -293,6 +415,21 @@ mod tests {
         assert!(err.to_string().contains("nonexistent-skill"));
     }
 
+    #[test]
+    fn should_suggest_similar_names_when_skill_not_found() {
+        // Given
+        let sources = vec![PathBuf::from("tests/fixtures/skills")];
+
+        // When
+        let result = resolve(&sources, "test-skil");
+
+        // Then
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Skill 'test-skil' not found in any source directory. Did you mean 'test-skill'?"
+        );
+    }
+
     #[test]
     fn should_handle_nonexistent_source_directory() {
         // Given
@@ -350,6 +487,29 @@ mod tests {
         assert_eq!(skill.name, "nested-skill");
     }
 
+    #[test]
+    fn should_list_supporting_files_relative_to_skill_dir() {
+        // Given
+        let temp = tempfile::TempDir::new().unwrap();
+        let skill_dir = temp.path().join("my-skill");
+        std::fs::create_dir_all(skill_dir.join("scripts")).unwrap();
+        std::fs::create_dir_all(skill_dir.join(".cache")).unwrap();
+        std::fs::write(
+            skill_dir.join("SKILL.md"),
+            "---\nname: my-skill\ndescription: Test\n---\n",
+        )
+        .unwrap();
+        std::fs::write(skill_dir.join("scripts/run.sh"), "echo").unwrap();
+        std::fs::write(skill_dir.join(".cache/tmp"), "").unwrap();
+        let skill = Skill::from_directory(&skill_dir).unwrap();
+
+        // When
+        let files = skill.supporting_files().unwrap();
+
+        // Then
+        assert_eq!(files, vec![PathBuf::from("scripts/run.sh")]);
+    }
+
     #[test]
     fn should_return_none_when_skill_not_in_directory() {
         // Given
@@ -0,0 +1,361 @@
+//! Pipeline ordering analysis
+//!
+//! A pipeline is declared twice: each stage's numeric `order`, and the
+//! `after`/`before` edges between stages. A topological sort over the edges
+//! derives the execution order they imply, which can then be compared with
+//! the declared one.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+
+use super::PipelineStage;
+
+/// An edge whose stages are declared in the opposite (or the same) order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderContradiction {
+    /// Stage the edge says runs first
+    pub first: String,
+    pub first_order: u32,
+    /// Stage the edge says runs after `first`
+    pub then: String,
+    pub then_order: u32,
+}
+
+/// Result of checking one pipeline's `order` values against its edges
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PipelineAnalysis {
+    /// Every stage in an order satisfying the edges, ties broken by
+    /// declared order then name. Stages in a cycle are kept together in
+    /// declared order.
+    pub execution_order: Vec<String>,
+    /// Each loop of edges, starting and ending at the same stage
+    pub cycles: Vec<Vec<String>>,
+    pub contradictions: Vec<OrderContradiction>,
+    /// Stages no edge leads to from the pipeline's first declared order
+    pub unreachable: Vec<String>,
+}
+
+/// Analyze the stages of one pipeline, keyed by skill name
+///
+/// Edges to skills outside the pipeline are ignored; `check` reports
+/// missing skills separately.
+pub fn analyze<'a>(
+    stages: impl IntoIterator<Item = (&'a str, &'a PipelineStage)>,
+) -> PipelineAnalysis {
+    let stages: BTreeMap<&str, &PipelineStage> = stages.into_iter().collect();
+
+    let mut edges: BTreeSet<(&str, &str)> = BTreeSet::new();
+    for (&name, stage) in &stages {
+        for dep in stage.after_skills() {
+            if let Some((&dep, _)) = stages.get_key_value(dep) {
+                edges.insert((dep, name));
+            }
+        }
+        for next in stage.before_skills() {
+            if let Some((&next, _)) = stages.get_key_value(next) {
+                edges.insert((name, next));
+            }
+        }
+    }
+
+    let mut successors: BTreeMap<&str, Vec<&str>> =
+        stages.keys().map(|&name| (name, Vec::new())).collect();
+    for &(from, to) in &edges {
+        successors.entry(from).or_default().push(to);
+    }
+
+    let contradictions = edges
+        .iter()
+        .filter(|(from, to)| stages[from].order >= stages[to].order)
+        .map(|&(from, to)| OrderContradiction {
+            first: from.to_string(),
+            first_order: stages[from].order,
+            then: to.to_string(),
+            then_order: stages[to].order,
+        })
+        .collect();
+
+    let components = strongly_connected(&successors);
+
+    PipelineAnalysis {
+        execution_order: execution_order(&stages, &edges, &components),
+        cycles: cycles(&successors, &components, &edges),
+        contradictions,
+        unreachable: unreachable(&stages, &successors, edges.is_empty()),
+    }
+}
+
+/// Kahn's algorithm over the stages with each cycle collapsed into one
+/// node, picking the earliest declared among those ready
+fn execution_order<'a>(
+    stages: &BTreeMap<&'a str, &PipelineStage>,
+    edges: &BTreeSet<(&'a str, &'a str)>,
+    components: &[Vec<&'a str>],
+) -> Vec<String> {
+    let key = |name: &'a str| (stages[name].order, name);
+    let component_of: HashMap<&str, usize> = components
+        .iter()
+        .enumerate()
+        .flat_map(|(i, members)| members.iter().map(move |&name| (name, i)))
+        .collect();
+
+    let mut incoming = vec![0usize; components.len()];
+    let mut successors: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); components.len()];
+    for &(from, to) in edges {
+        let (from, to) = (component_of[from], component_of[to]);
+        if from != to && successors[from].insert(to) {
+            incoming[to] += 1;
+        }
+    }
+
+    let component_key = |i: usize| {
+        let first = components[i].iter().map(|&name| key(name)).min();
+        (first.expect("components are not empty"), i)
+    };
+    let mut ready: BTreeSet<((u32, &str), usize)> = (0..components.len())
+        .filter(|&i| incoming[i] == 0)
+        .map(component_key)
+        .collect();
+    let mut order = Vec::new();
+
+    while let Some(next) = ready.pop_first() {
+        let component = next.1;
+        let mut members = components[component].clone();
+        members.sort_by_key(|&name| key(name));
+        order.extend(members.iter().map(|name| name.to_string()));
+
+        for &to in &successors[component] {
+            incoming[to] -= 1;
+            if incoming[to] == 0 {
+                ready.insert(component_key(to));
+            }
+        }
+    }
+
+    order
+}
+
+/// One loop through each cycle of edges
+fn cycles(
+    successors: &BTreeMap<&str, Vec<&str>>,
+    components: &[Vec<&str>],
+    edges: &BTreeSet<(&str, &str)>,
+) -> Vec<Vec<String>> {
+    let mut loops: Vec<Vec<String>> = components
+        .iter()
+        .filter(|members| members.len() > 1 || edges.contains(&(members[0], members[0])))
+        .filter_map(|component| {
+            let members: BTreeSet<&str> = component.iter().copied().collect();
+            let start = *members.iter().next()?;
+            shortest_loop(successors, &members, start)
+        })
+        .collect();
+    loops.sort();
+    loops
+}
+
+/// Strongly connected components, via Tarjan's algorithm
+fn strongly_connected<'a>(successors: &BTreeMap<&'a str, Vec<&'a str>>) -> Vec<Vec<&'a str>> {
+    struct Tarjan<'a, 'b> {
+        successors: &'b BTreeMap<&'a str, Vec<&'a str>>,
+        index: HashMap<&'a str, usize>,
+        low: HashMap<&'a str, usize>,
+        stack: Vec<&'a str>,
+        on_stack: BTreeSet<&'a str>,
+        components: Vec<Vec<&'a str>>,
+    }
+
+    impl<'a> Tarjan<'a, '_> {
+        fn visit(&mut self, node: &'a str) {
+            let index = self.index.len();
+            self.index.insert(node, index);
+            self.low.insert(node, index);
+            self.stack.push(node);
+            self.on_stack.insert(node);
+
+            for &next in &self.successors[node] {
+                if !self.index.contains_key(next) {
+                    self.visit(next);
+                    let low = self.low[node].min(self.low[next]);
+                    self.low.insert(node, low);
+                } else if self.on_stack.contains(next) {
+                    let low = self.low[node].min(self.index[next]);
+                    self.low.insert(node, low);
+                }
+            }
+
+            if self.low[node] == self.index[node] {
+                let mut component = Vec::new();
+                while let Some(member) = self.stack.pop() {
+                    self.on_stack.remove(member);
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                self.components.push(component);
+            }
+        }
+    }
+
+    let mut tarjan = Tarjan {
+        successors,
+        index: HashMap::new(),
+        low: HashMap::new(),
+        stack: Vec::new(),
+        on_stack: BTreeSet::new(),
+        components: Vec::new(),
+    };
+    for &node in successors.keys() {
+        if !tarjan.index.contains_key(node) {
+            tarjan.visit(node);
+        }
+    }
+    tarjan.components
+}
+
+/// Shortest path from `start` back to itself within `members`
+fn shortest_loop(
+    successors: &BTreeMap<&str, Vec<&str>>,
+    members: &BTreeSet<&str>,
+    start: &str,
+) -> Option<Vec<String>> {
+    let mut previous: HashMap<&str, &str> = HashMap::new();
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        for &next in &successors[node] {
+            if !members.contains(next) {
+                continue;
+            }
+            if next == start {
+                let mut path = vec![start.to_string()];
+                let mut current = node;
+                while current != start {
+                    path.push(current.to_string());
+                    current = previous[current];
+                }
+                path.push(start.to_string());
+                path.reverse();
+                return Some(path);
+            }
+            if !previous.contains_key(next) {
+                previous.insert(next, node);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Stages not reachable along edges from the stages with the lowest order
+///
+/// Pipelines that declare no edges at all rely on `order` alone and have
+/// nothing to report.
+fn unreachable(
+    stages: &BTreeMap<&str, &PipelineStage>,
+    successors: &BTreeMap<&str, Vec<&str>>,
+    no_edges: bool,
+) -> Vec<String> {
+    let Some(first_order) = stages.values().map(|s| s.order).min() else {
+        return Vec::new();
+    };
+    if no_edges {
+        return Vec::new();
+    }
+
+    let mut reached: BTreeSet<&str> = stages
+        .iter()
+        .filter(|(_, stage)| stage.order == first_order)
+        .map(|(&name, _)| name)
+        .collect();
+    let mut queue: VecDeque<&str> = reached.iter().copied().collect();
+    while let Some(node) = queue.pop_front() {
+        for &next in &successors[node] {
+            if reached.insert(next) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    stages
+        .keys()
+        .filter(|name| !reached.contains(*name))
+        .map(|name| name.to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stage(order: u32, after: &[&str], before: &[&str]) -> PipelineStage {
+        let list = |names: &[&str]| {
+            (!names.is_empty()).then(|| names.iter().map(|n| n.to_string()).collect())
+        };
+        PipelineStage {
+            stage: "stage".to_string(),
+            order,
+            after: list(after),
+            before: list(before),
+        }
+    }
+
+    #[test]
+    fn should_derive_execution_order_from_edges() {
+        // Given
+        let stages = [
+            ("draft", stage(1, &[], &["edit"])),
+            ("edit", stage(2, &["draft@^1.0"], &[])),
+            ("publish", stage(3, &["edit", "elsewhere"], &[])),
+            ("review", stage(2, &["draft"], &["publish"])),
+        ];
+
+        // When
+        let analysis = analyze(stages.iter().map(|(name, s)| (*name, s)));
+
+        // Then
+        assert_eq!(
+            analysis.execution_order,
+            vec!["draft", "edit", "review", "publish"]
+        );
+        assert!(analysis.cycles.is_empty());
+        assert!(analysis.contradictions.is_empty());
+        assert!(analysis.unreachable.is_empty());
+    }
+
+    #[test]
+    fn should_report_cycles_contradictions_and_unreachable_stages() {
+        // Given
+        let stages = [
+            ("outline", stage(1, &[], &[])),
+            ("draft", stage(2, &["outline", "polish"], &[])),
+            ("edit", stage(3, &["draft"], &[])),
+            ("polish", stage(4, &["edit"], &[])),
+            ("archive", stage(5, &[], &[])),
+        ];
+
+        // When
+        let analysis = analyze(stages.iter().map(|(name, s)| (*name, s)));
+
+        // Then
+        assert_eq!(
+            analysis.cycles,
+            vec![vec!["draft", "edit", "polish", "draft"]]
+        );
+        assert_eq!(
+            analysis.contradictions,
+            vec![OrderContradiction {
+                first: "polish".to_string(),
+                first_order: 4,
+                then: "draft".to_string(),
+                then_order: 2,
+            }]
+        );
+        assert_eq!(analysis.unreachable, vec!["archive"]);
+        assert_eq!(
+            analysis.execution_order,
+            vec!["outline", "draft", "edit", "polish", "archive"]
+        );
+    }
+}
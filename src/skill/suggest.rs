@@ -0,0 +1,179 @@
+//! "Did you mean" suggestions for unknown skill names
+//!
+//! Candidates are scored by edit distance and by overlap of their
+//! hyphen-separated tokens, so both `voise` → `voice` and
+//! `review-code` → `code-review` are caught.
+
+use std::collections::HashSet;
+
+/// Minimum score for a candidate to be suggested at all
+const SUGGEST_THRESHOLD: f64 = 0.7;
+
+/// Minimum score for a candidate to be applied automatically
+const STRONG_THRESHOLD: f64 = 0.8;
+
+/// Maximum number of suggestions returned
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Suggest known skill names close to `name`, best match first
+pub fn suggestions<'a>(name: &str, candidates: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    ranked(name, candidates)
+        .into_iter()
+        .filter(|(score, _)| *score >= SUGGEST_THRESHOLD)
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, candidate)| candidate)
+        .collect()
+}
+
+/// Return the single strong candidate for `name`, if there is exactly one
+///
+/// Used to decide whether a reference can be rewritten without asking.
+pub fn strong_candidate<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<String> {
+    let strong: Vec<String> = ranked(name, candidates)
+        .into_iter()
+        .filter(|(score, _)| *score >= STRONG_THRESHOLD)
+        .map(|(_, candidate)| candidate)
+        .collect();
+
+    match strong.as_slice() {
+        [only] => Some(only.clone()),
+        _ => None,
+    }
+}
+
+/// Format suggestions as a sentence to append to an error message
+///
+/// Returns an empty string when there is nothing to suggest.
+pub fn did_you_mean(suggestions: &[String]) -> String {
+    match suggestions {
+        [] => String::new(),
+        [only] => format!(". Did you mean '{}'?", only),
+        many => format!(
+            ". Did you mean one of: {}?",
+            many.iter()
+                .map(|s| format!("'{}'", s))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+/// Similarity between two names in the range 0.0 to 1.0
+pub fn similarity(a: &str, b: &str) -> f64 {
+    edit_similarity(a, b).max(token_similarity(a, b))
+}
+
+fn ranked<'a>(name: &str, candidates: impl IntoIterator<Item = &'a str>) -> Vec<(f64, String)> {
+    let mut scored: Vec<(f64, String)> = candidates
+        .into_iter()
+        .filter(|candidate| *candidate != name)
+        .map(|candidate| (similarity(name, candidate), candidate.to_string()))
+        .collect();
+
+    scored.sort_by(|a, b| b.0.total_cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+    scored.dedup_by(|a, b| a.1 == b.1);
+    scored
+}
+
+fn edit_similarity(a: &str, b: &str) -> f64 {
+    let longest = a.chars().count().max(b.chars().count());
+    if longest == 0 {
+        return 1.0;
+    }
+    1.0 - levenshtein(a, b) as f64 / longest as f64
+}
+
+/// Jaccard overlap of hyphen-separated tokens, each fuzzily matched
+fn token_similarity(a: &str, b: &str) -> f64 {
+    let a_tokens: HashSet<&str> = a.split('-').filter(|t| !t.is_empty()).collect();
+    let b_tokens: HashSet<&str> = b.split('-').filter(|t| !t.is_empty()).collect();
+    if a_tokens.is_empty() || b_tokens.is_empty() {
+        return 0.0;
+    }
+
+    // A token counts as shared if the other side has one within one edit
+    let shared = a_tokens
+        .iter()
+        .filter(|t| {
+            b_tokens
+                .iter()
+                .any(|u| **t == *u || (t.len() > 3 && levenshtein(t, u) <= 1))
+        })
+        .count();
+    let union = a_tokens.len() + b_tokens.len() - shared;
+
+    shared as f64 / union as f64
+}
+
+/// Levenshtein edit distance over characters
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b_chars.len()).collect();
+    let mut current = vec![0; b_chars.len() + 1];
+
+    for (i, a_char) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, b_char) in b_chars.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b_chars.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SKILLS: [&str; 5] = [
+        "voice",
+        "code-review",
+        "skill-craft",
+        "skill-review",
+        "deploy",
+    ];
+
+    #[test]
+    fn should_compute_levenshtein_distance() {
+        // Given/When/Then
+        assert_eq!(levenshtein("voice", "voice"), 0);
+        assert_eq!(levenshtein("voise", "voice"), 1);
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("", "abc"), 3);
+    }
+
+    #[test]
+    fn should_suggest_typos_and_reordered_tokens() {
+        // Given/When/Then
+        assert_eq!(suggestions("voise", SKILLS), vec!["voice"]);
+        assert_eq!(suggestions("review-code", SKILLS), vec!["code-review"]);
+        assert!(suggestions("kubernetes", SKILLS).is_empty());
+    }
+
+    #[test]
+    fn should_only_return_unambiguous_strong_candidates() {
+        // Given/When/Then
+        assert_eq!(
+            strong_candidate("skill-reviw", SKILLS),
+            Some("skill-review".into())
+        );
+        assert_eq!(strong_candidate("skill", SKILLS), None);
+        assert_eq!(strong_candidate("depoy", SKILLS), Some("deploy".into()));
+    }
+
+    #[test]
+    fn should_format_did_you_mean_hint() {
+        // Given/When/Then
+        assert_eq!(did_you_mean(&[]), "");
+        assert_eq!(did_you_mean(&["voice".into()]), ". Did you mean 'voice'?");
+        assert_eq!(
+            did_you_mean(&["a".into(), "b".into()]),
+            ". Did you mean one of: 'a', 'b'?"
+        );
+    }
+}
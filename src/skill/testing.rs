@@ -0,0 +1,30 @@
+//! Skill fixtures shared by unit tests
+
+use std::fs;
+use std::path::Path;
+
+use super::Skill;
+
+/// Write `dir/<name>/SKILL.md` with `extra` appended to the frontmatter
+pub fn write_skill(dir: &Path, name: &str, extra: &str) {
+    write_skill_with(dir, name, &format!("Run the {name} step"), extra, "");
+}
+
+/// Write `dir/<name>/SKILL.md` with its own description and body
+pub fn write_skill_with(dir: &Path, name: &str, description: &str, extra: &str, body: &str) {
+    let skill_dir = dir.join(name);
+    fs::create_dir_all(&skill_dir).unwrap();
+    fs::write(
+        skill_dir.join("SKILL.md"),
+        format!("---\nname: {name}\ndescription: {description}\n{extra}---\n{body}"),
+    )
+    .unwrap();
+}
+
+/// Write each `(name, extra)` skill into `dir` and discover them
+pub fn write_skills(dir: &Path, skills: &[(&str, &str)]) -> Vec<Skill> {
+    for (name, extra) in skills {
+        write_skill(dir, name, extra);
+    }
+    super::discover_in_directory(dir).unwrap()
+}
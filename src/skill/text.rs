@@ -0,0 +1,227 @@
+//! Term weighting for comparing skill descriptions
+//!
+//! Runners pick a skill by matching the request against its description,
+//! so two descriptions that share most of their distinctive words are
+//! ambiguous triggers. TF-IDF weighs each term by how rare it is across the
+//! whole library, so boilerplate like "use when" counts for little. BM25
+//! ranks skills against a prompt the same way a runner would, roughly.
+
+use std::collections::{HashMap, HashSet};
+
+/// Words too common to distinguish one skill from another
+const STOPWORDS: &[&str] = &[
+    "a", "about", "all", "an", "and", "any", "are", "as", "at", "be", "by", "can", "do", "for",
+    "from", "has", "have", "how", "if", "in", "into", "is", "it", "its", "of", "on", "or", "so",
+    "that", "the", "their", "then", "this", "to", "use", "used", "uses", "using", "via", "was",
+    "what", "when", "which", "while", "with", "you", "your",
+];
+
+/// Lowercased content words of `text`, in order, without stopwords
+pub fn terms(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| word.chars().count() > 1)
+        .map(str::to_lowercase)
+        .filter(|word| !STOPWORDS.contains(&word.as_str()))
+        .collect()
+}
+
+/// TF-IDF vectors for a set of documents
+pub struct TfIdf {
+    vectors: Vec<HashMap<String, f64>>,
+}
+
+impl TfIdf {
+    /// Weigh the terms of each document against the whole set
+    pub fn new<'a>(documents: impl IntoIterator<Item = &'a str>) -> Self {
+        let documents: Vec<Vec<String>> = documents.into_iter().map(terms).collect();
+
+        let mut document_frequency: HashMap<&str, usize> = HashMap::new();
+        for document in &documents {
+            let unique: HashSet<&str> = document.iter().map(String::as_str).collect();
+            for term in unique {
+                *document_frequency.entry(term).or_default() += 1;
+            }
+        }
+
+        // Smoothed IDF, so terms found in every document still count a little
+        let count = documents.len() as f64;
+        let idf = |term: &str| ((1.0 + count) / (1.0 + document_frequency[term] as f64)).ln() + 1.0;
+
+        let vectors = documents
+            .iter()
+            .map(|document| {
+                let mut vector: HashMap<String, f64> = HashMap::new();
+                for term in document {
+                    *vector.entry(term.clone()).or_default() += 1.0;
+                }
+                for (term, weight) in vector.iter_mut() {
+                    *weight *= idf(term);
+                }
+                vector
+            })
+            .collect();
+
+        Self { vectors }
+    }
+
+    /// Cosine similarity of two documents, from 0.0 to 1.0
+    pub fn cosine(&self, a: usize, b: usize) -> f64 {
+        let (a, b) = (&self.vectors[a], &self.vectors[b]);
+        let dot: f64 = a
+            .iter()
+            .filter_map(|(term, weight)| b.get(term).map(|other| weight * other))
+            .sum();
+        let norm = |v: &HashMap<String, f64>| v.values().map(|w| w * w).sum::<f64>().sqrt();
+
+        let denominator = norm(a) * norm(b);
+        if denominator == 0.0 {
+            0.0
+        } else {
+            dot / denominator
+        }
+    }
+
+    /// Terms two documents share, most distinctive first
+    pub fn shared_terms(&self, a: usize, b: usize) -> Vec<String> {
+        let (a, b) = (&self.vectors[a], &self.vectors[b]);
+        let mut shared: Vec<(f64, &String)> = a
+            .iter()
+            .filter_map(|(term, weight)| b.get(term).map(|other| (weight * other, term)))
+            .collect();
+        shared.sort_by(|x, y| y.0.total_cmp(&x.0).then_with(|| x.1.cmp(y.1)));
+        shared.into_iter().map(|(_, term)| term.clone()).collect()
+    }
+}
+
+/// BM25 term saturation
+const BM25_K1: f64 = 1.2;
+
+/// BM25 document length normalization
+const BM25_B: f64 = 0.75;
+
+/// BM25 ranking over a set of documents
+pub struct Bm25 {
+    documents: Vec<HashMap<String, usize>>,
+    lengths: Vec<usize>,
+    document_frequency: HashMap<String, usize>,
+    average_length: f64,
+}
+
+impl Bm25 {
+    /// Index the terms of each document
+    pub fn new<'a>(documents: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut indexed = Vec::new();
+        let mut lengths = Vec::new();
+        let mut document_frequency: HashMap<String, usize> = HashMap::new();
+
+        for document in documents {
+            let document = terms(document);
+            let mut counts: HashMap<String, usize> = HashMap::new();
+            for term in &document {
+                *counts.entry(term.clone()).or_default() += 1;
+            }
+            for term in counts.keys() {
+                *document_frequency.entry(term.clone()).or_default() += 1;
+            }
+            lengths.push(document.len());
+            indexed.push(counts);
+        }
+
+        let average_length = if lengths.is_empty() {
+            0.0
+        } else {
+            lengths.iter().sum::<usize>() as f64 / lengths.len() as f64
+        };
+
+        Self {
+            documents: indexed,
+            lengths,
+            document_frequency,
+            average_length,
+        }
+    }
+
+    /// Score one document against a query, with the query terms it matched
+    pub fn score(&self, query: &str, document: usize) -> (f64, Vec<String>) {
+        let counts = &self.documents[document];
+        let count = self.documents.len() as f64;
+        let length_ratio = if self.average_length == 0.0 {
+            1.0
+        } else {
+            self.lengths[document] as f64 / self.average_length
+        };
+
+        let mut score = 0.0;
+        let mut matched: Vec<String> = Vec::new();
+        let unique: HashSet<String> = terms(query).into_iter().collect();
+        for term in unique {
+            let Some(&frequency) = counts.get(&term) else {
+                continue;
+            };
+            let df = self.document_frequency[&term] as f64;
+            let idf = (1.0 + (count - df + 0.5) / (df + 0.5)).ln();
+            let frequency = frequency as f64;
+            score += idf * frequency * (BM25_K1 + 1.0)
+                / (frequency + BM25_K1 * (1.0 - BM25_B + BM25_B * length_ratio));
+            matched.push(term);
+        }
+
+        matched.sort();
+        (score, matched)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_extract_lowercase_terms_without_stopwords() {
+        // Given/When/Then
+        assert_eq!(
+            terms("Use when reviewing a Pull-Request's diff"),
+            vec!["reviewing", "pull", "request", "diff"]
+        );
+    }
+
+    #[test]
+    fn should_score_near_identical_descriptions_higher() {
+        // Given
+        let tfidf = TfIdf::new([
+            "Review pull requests for correctness and style",
+            "Review pull requests for style and correctness issues",
+            "Deploy the staging environment",
+        ]);
+
+        // When
+        let similar = tfidf.cosine(0, 1);
+        let different = tfidf.cosine(0, 2);
+
+        // Then
+        assert!(similar > 0.8, "{similar}");
+        assert_eq!(different, 0.0);
+        assert_eq!(tfidf.shared_terms(0, 2), Vec::<String>::new());
+        assert_eq!(tfidf.shared_terms(0, 1).len(), 5);
+    }
+
+    #[test]
+    fn should_rank_documents_with_rarer_matching_terms_higher() {
+        // Given
+        let bm25 = Bm25::new([
+            "code review: Review pull requests for correctness",
+            "deploy: Deploy the staging environment after review",
+            "voice: Write in the house voice",
+        ]);
+
+        // When
+        let (review, review_terms) = bm25.score("please review my pull request", 0);
+        let (deploy, deploy_terms) = bm25.score("please review my pull request", 1);
+        let (voice, _) = bm25.score("please review my pull request", 2);
+
+        // Then
+        assert!(review > deploy, "{review} <= {deploy}");
+        assert_eq!(voice, 0.0);
+        assert_eq!(review_terms, vec!["pull", "review"]);
+        assert_eq!(deploy_terms, vec!["review"]);
+    }
+}
@@ -0,0 +1,44 @@
+//! Approximate token counting
+//!
+//! Close enough to BPE tokenizers on English prose and markdown to size a
+//! context budget, without shipping a vocabulary: runs of letters and digits
+//! cost one token per four characters, and every other visible character
+//! costs one token of its own.
+
+/// Characters per token within a word
+const CHARS_PER_TOKEN: usize = 4;
+
+/// Estimate how many tokens `text` occupies in an agent's context
+pub fn estimate_tokens(text: &str) -> usize {
+    let mut tokens = 0;
+    let mut word_len: usize = 0;
+
+    for ch in text.chars() {
+        if ch.is_alphanumeric() {
+            word_len += 1;
+            continue;
+        }
+        tokens += word_len.div_ceil(CHARS_PER_TOKEN);
+        word_len = 0;
+        if !ch.is_whitespace() {
+            tokens += 1;
+        }
+    }
+
+    tokens + word_len.div_ceil(CHARS_PER_TOKEN)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_estimate_tokens_for_words_and_punctuation() {
+        // Given/When/Then
+        assert_eq!(estimate_tokens(""), 0);
+        assert_eq!(estimate_tokens("   \n"), 0);
+        assert_eq!(estimate_tokens("voice"), 2);
+        assert_eq!(estimate_tokens("Write in our voice."), 7);
+        assert_eq!(estimate_tokens("code-review"), 4);
+    }
+}